@@ -325,6 +325,75 @@ pub fn jsfilter() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn jsfilter_arg() -> clap::Arg {
+    clap::Arg::new("jsfilter_arg")
+        .long("jsfilter-arg")
+        .value_name("NAME=VALUE")
+        .help("Define a value of the config object of JavaScript filters")
+        .help_heading("Run options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
+pub fn jsfilter_args_mode() -> clap::Arg {
+    clap::Arg::new("jsfilter_args_mode")
+        .long("jsfilter-args-mode")
+        .value_name("MODE")
+        .value_parser(["positional", "array"])
+        .help("Pass the arguments of JavaScript filters as positional parameters or as an array [default: positional]")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn jsfilter_date_objects() -> clap::Arg {
+    clap::Arg::new("jsfilter_date_objects")
+        .long("jsfilter-date-objects")
+        .help("Pass dates to JavaScript filters as Date objects instead of ISO 8601 strings")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn jsfilter_prefix() -> clap::Arg {
+    clap::Arg::new("jsfilter_prefix")
+        .long("jsfilter-prefix")
+        .value_name("PREFIX")
+        .help("Prefix of the JavaScript filter function names (default filter_)")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn jsfilter_shared_global() -> clap::Arg {
+    clap::Arg::new("jsfilter_shared_global")
+        .long("jsfilter-shared-global")
+        .help("Share the client.global object of JavaScript filters between parallel workers (JavaScript filters are run one at a time)")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn jsfilter_strict() -> clap::Arg {
+    clap::Arg::new("jsfilter_strict")
+        .long("jsfilter-strict")
+        .help("Fail when a JavaScript filter returns a value without Hurl counterpart (function, symbol)")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn jsfilter_tuple_results() -> clap::Arg {
+    clap::Arg::new("jsfilter_tuple_results")
+        .long("jsfilter-tuple-results")
+        .help("Fail when a JavaScript filter returns a [result, error] array with an error message")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn jsfilter_undefined_unchanged() -> clap::Arg {
+    clap::Arg::new("jsfilter_undefined_unchanged")
+        .long("jsfilter-undefined-unchanged")
+        .help("Leave the input unchanged when a JavaScript filter returns undefined")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn limit_rate() -> clap::Arg {
     clap::Arg::new("limit_rate")
         .long("limit-rate")
@@ -335,6 +404,15 @@ pub fn limit_rate() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn list_jsfilters() -> clap::Arg {
+    clap::Arg::new("list_jsfilters")
+        .long("list-jsfilters")
+        .value_name("FILE")
+        .help("List the custom filter functions defined in a JavaScript file and exit")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn max_filesize() -> clap::Arg {
     clap::Arg::new("max_filesize")
         .long("max-filesize")
@@ -616,6 +694,16 @@ pub fn secrets_file() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn seed() -> clap::Arg {
+    clap::Arg::new("seed")
+        .long("seed")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u64))
+        .help("Seed the random generator used by the sample and shuffle filters")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
@@ -624,6 +712,14 @@ pub fn ssl_no_revoke() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn strict_arithmetic() -> clap::Arg {
+    clap::Arg::new("strict_arithmetic")
+        .long("strict-arithmetic")
+        .help("Fail when an arithmetic filter overflows instead of wrapping around")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn test() -> clap::Arg {
     clap::Arg::new("test")
         .long("test")
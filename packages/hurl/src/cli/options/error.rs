@@ -25,6 +25,7 @@ pub enum CliOptionsError {
     NoInput(String),
     Error(String),
     InvalidInputFile(PathBuf),
+    Info(String),
 }
 
 impl CliOptionsError {
@@ -65,6 +66,7 @@ impl fmt::Display for CliOptionsError {
                 "error: Cannot access '{}': No such file or directory",
                 path.display()
             ),
+            CliOptionsError::Info(message) => write!(f, "{message}"),
         }
     }
 }
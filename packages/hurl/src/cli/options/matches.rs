@@ -22,6 +22,7 @@ use std::time::Duration;
 
 use clap::ArgMatches;
 use hurl::pretty::PrettyMode;
+use hurl::runner::js::{ArgsMode, JsRuntime};
 use hurl::runner::Value;
 use hurl_core::input::Input;
 use hurl_core::types::{BytesPerSec, Count, DurationUnit};
@@ -357,6 +358,75 @@ pub fn junit_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> O
         .or(default_value)
 }
 
+pub fn jsfilter_args(
+    arg_matches: &ArgMatches,
+    default_value: HashMap<String, Value>,
+) -> Result<HashMap<String, Value>, CliOptionsError> {
+    let mut args = default_value;
+    if let Some(input) = get_strings(arg_matches, "jsfilter_arg") {
+        for s in input {
+            let (name, value) = variables::parse(&s, TypeKind::Inferred)?;
+            args.insert(name.to_string(), value);
+        }
+    }
+    Ok(args)
+}
+
+pub fn jsfilter_args_mode(arg_matches: &ArgMatches, default_value: ArgsMode) -> ArgsMode {
+    match get::<String>(arg_matches, "jsfilter_args_mode") {
+        Some(args_mode) => match args_mode.as_str() {
+            "array" => ArgsMode::Array,
+            "positional" => ArgsMode::Positional,
+            _ => ArgsMode::Positional,
+        },
+        None => default_value,
+    }
+}
+
+pub fn jsfilter_date_objects(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "jsfilter_date_objects") {
+        true
+    } else {
+        default_value
+    }
+}
+
+pub fn jsfilter_prefix(arg_matches: &ArgMatches, default_value: String) -> String {
+    get::<String>(arg_matches, "jsfilter_prefix").unwrap_or(default_value)
+}
+
+pub fn jsfilter_shared_global(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "jsfilter_shared_global") {
+        true
+    } else {
+        default_value
+    }
+}
+
+pub fn jsfilter_strict(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "jsfilter_strict") {
+        true
+    } else {
+        default_value
+    }
+}
+
+pub fn jsfilter_tuple_results(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "jsfilter_tuple_results") {
+        true
+    } else {
+        default_value
+    }
+}
+
+pub fn jsfilter_undefined_unchanged(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "jsfilter_undefined_unchanged") {
+        true
+    } else {
+        default_value
+    }
+}
+
 pub fn limit_rate(
     arg_matches: &ArgMatches,
     default_value: Option<BytesPerSec>,
@@ -366,6 +436,25 @@ pub fn limit_rate(
         .or(default_value)
 }
 
+/// Returns the custom filters defined in the JavaScript file of `--list-jsfilters`, one per line,
+/// if this option is used.
+pub fn list_jsfilters(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
+    let Some(file) = get::<String>(arg_matches, "list_jsfilters") else {
+        return Ok(None);
+    };
+    let mut runtime = JsRuntime::from_file(Path::new(&file))
+        .map_err(|e| CliOptionsError::Error(e.to_string()))?;
+    if let Some(prefix) = get::<String>(arg_matches, "jsfilter_prefix") {
+        runtime.set_filter_prefix(&prefix);
+    }
+    let listing = runtime
+        .list_filters()
+        .iter()
+        .map(|name| format!("{name}\n"))
+        .collect();
+    Ok(Some(listing))
+}
+
 pub fn max_filesize(arg_matches: &ArgMatches, default_value: Option<u64>) -> Option<u64> {
     get::<u64>(arg_matches, "max_filesize").or(default_value)
 }
@@ -654,6 +743,10 @@ fn add_secret(
     Ok(())
 }
 
+pub fn seed(arg_matches: &ArgMatches, default_value: Option<u64>) -> Option<u64> {
+    get::<u64>(arg_matches, "seed").or(default_value)
+}
+
 pub fn ssl_no_revoke(arg_matches: &ArgMatches, default_value: bool) -> bool {
     if has_flag(arg_matches, "ssl_no_revoke") {
         true
@@ -662,6 +755,14 @@ pub fn ssl_no_revoke(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+pub fn strict_arithmetic(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "strict_arithmetic") {
+        true
+    } else {
+        default_value
+    }
+}
+
 pub fn tap_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_tap")
         .map(PathBuf::from)
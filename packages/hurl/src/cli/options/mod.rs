@@ -35,6 +35,7 @@ use clap::ArgMatches;
 use hurl::http;
 use hurl::http::RequestedHttpVersion;
 use hurl::pretty::PrettyMode;
+use hurl::runner::js::{ArgsMode, DEFAULT_FILTER_PREFIX};
 use hurl::runner::Output;
 use hurl::util::logger;
 use hurl::util::logger::{LoggerOptions, LoggerOptionsBuilder};
@@ -78,6 +79,14 @@ pub struct CliOptions {
     pub ip_resolve: Option<IpResolve>,
     pub jobs: Option<usize>,
     pub jsfilter: Option<PathBuf>,
+    pub jsfilter_args: HashMap<String, Value>,
+    pub jsfilter_args_mode: ArgsMode,
+    pub jsfilter_date_objects: bool,
+    pub jsfilter_prefix: String,
+    pub jsfilter_shared_global: bool,
+    pub jsfilter_strict: bool,
+    pub jsfilter_tuple_results: bool,
+    pub jsfilter_undefined_unchanged: bool,
     pub json_report_dir: Option<PathBuf>,
     pub junit_file: Option<PathBuf>,
     pub limit_rate: Option<BytesPerSec>,
@@ -103,7 +112,9 @@ pub struct CliOptions {
     pub retry: Option<Count>,
     pub retry_interval: Duration,
     pub secrets: HashMap<String, String>,
+    pub seed: Option<u64>,
     pub ssl_no_revoke: bool,
+    pub strict_arithmetic: bool,
     pub tap_file: Option<PathBuf>,
     pub test: bool,
     pub timeout: Duration,
@@ -277,12 +288,23 @@ pub fn parse(context: &RunContext) -> Result<CliOptions, CliOptionsError> {
         .arg(commands::ignore_asserts())
         .arg(commands::jobs())
         .arg(commands::jsfilter())
+        .arg(commands::jsfilter_arg())
+        .arg(commands::jsfilter_args_mode())
+        .arg(commands::jsfilter_date_objects())
+        .arg(commands::jsfilter_prefix())
+        .arg(commands::jsfilter_shared_global())
+        .arg(commands::jsfilter_strict())
+        .arg(commands::jsfilter_tuple_results())
+        .arg(commands::jsfilter_undefined_unchanged())
+        .arg(commands::list_jsfilters())
         .arg(commands::parallel())
         .arg(commands::repeat())
         .arg(commands::retry())
         .arg(commands::retry_interval())
         .arg(commands::secret())
         .arg(commands::secrets_file())
+        .arg(commands::seed())
+        .arg(commands::strict_arithmetic())
         .arg(commands::test())
         .arg(commands::to_entry())
         .arg(commands::variable())
@@ -307,6 +329,11 @@ pub fn parse(context: &RunContext) -> Result<CliOptions, CliOptionsError> {
         Err(error) => return Err(CliOptionsError::from_clap(error, context.is_with_color())),
     };
 
+    // Listing JavaScript filters is a standalone diagnostic, that doesn't need any Hurl file.
+    if let Some(listing) = matches::list_jsfilters(&arg_matches)? {
+        return Err(CliOptionsError::Info(listing));
+    }
+
     // If we've no file input (either from the standard input or from the command line arguments),
     // we just print help and exit.
     if !matches::has_input_files(&arg_matches, context) {
@@ -375,6 +402,21 @@ fn parse_matches(
     let ip_resolve = matches::ip_resolve(arg_matches, default_options.ip_resolve);
     let jobs = matches::jobs(arg_matches, default_options.jobs);
     let jsfilter = matches::jsfilter(arg_matches, default_options.jsfilter)?;
+    let jsfilter_args = matches::jsfilter_args(arg_matches, default_options.jsfilter_args)?;
+    let jsfilter_args_mode =
+        matches::jsfilter_args_mode(arg_matches, default_options.jsfilter_args_mode);
+    let jsfilter_date_objects =
+        matches::jsfilter_date_objects(arg_matches, default_options.jsfilter_date_objects);
+    let jsfilter_prefix = matches::jsfilter_prefix(arg_matches, default_options.jsfilter_prefix);
+    let jsfilter_shared_global =
+        matches::jsfilter_shared_global(arg_matches, default_options.jsfilter_shared_global);
+    let jsfilter_strict = matches::jsfilter_strict(arg_matches, default_options.jsfilter_strict);
+    let jsfilter_tuple_results =
+        matches::jsfilter_tuple_results(arg_matches, default_options.jsfilter_tuple_results);
+    let jsfilter_undefined_unchanged = matches::jsfilter_undefined_unchanged(
+        arg_matches,
+        default_options.jsfilter_undefined_unchanged,
+    );
     let json_report_dir = matches::json_report_dir(arg_matches, default_options.json_report_dir)?;
     let junit_file = matches::junit_file(arg_matches, default_options.junit_file);
     let limit_rate = matches::limit_rate(arg_matches, default_options.limit_rate);
@@ -400,7 +442,10 @@ fn parse_matches(
     let retry = matches::retry(arg_matches, default_options.retry);
     let retry_interval = matches::retry_interval(arg_matches, default_options.retry_interval)?;
     let secrets = matches::secret(arg_matches, context, default_options.secrets)?;
+    let seed = matches::seed(arg_matches, default_options.seed);
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches, default_options.ssl_no_revoke);
+    let strict_arithmetic =
+        matches::strict_arithmetic(arg_matches, default_options.strict_arithmetic);
     let tap_file = matches::tap_file(arg_matches, default_options.tap_file);
     let test = matches::test(arg_matches, default_options.test);
     let timeout = matches::timeout(arg_matches, default_options.timeout)?;
@@ -457,6 +502,14 @@ fn parse_matches(
         ip_resolve,
         json_report_dir,
         jsfilter,
+        jsfilter_args,
+        jsfilter_args_mode,
+        jsfilter_date_objects,
+        jsfilter_prefix,
+        jsfilter_shared_global,
+        jsfilter_strict,
+        jsfilter_tuple_results,
+        jsfilter_undefined_unchanged,
         junit_file,
         limit_rate,
         max_filesize,
@@ -481,7 +534,9 @@ fn parse_matches(
         retry,
         retry_interval,
         secrets,
+        seed,
         ssl_no_revoke,
+        strict_arithmetic,
         tap_file,
         test,
         timeout,
@@ -538,6 +593,14 @@ impl Default for CliOptions {
             jobs: None,
             json_report_dir: None,
             jsfilter: None,
+            jsfilter_args: HashMap::new(),
+            jsfilter_args_mode: ArgsMode::default(),
+            jsfilter_date_objects: false,
+            jsfilter_prefix: DEFAULT_FILTER_PREFIX.to_string(),
+            jsfilter_shared_global: false,
+            jsfilter_strict: false,
+            jsfilter_tuple_results: false,
+            jsfilter_undefined_unchanged: false,
             junit_file: None,
             limit_rate: None,
             max_filesize: None,
@@ -562,7 +625,9 @@ impl Default for CliOptions {
             retry: None,
             retry_interval: Duration::from_millis(1000),
             secrets: HashMap::new(),
+            seed: None,
             ssl_no_revoke: false,
+            strict_arithmetic: false,
             tap_file: None,
             test: false,
             timeout: Duration::from_secs(300),
@@ -629,7 +694,9 @@ impl CliOptions {
         let resolves = self.resolves.clone();
         let retry = self.retry;
         let retry_interval = self.retry_interval;
+        let seed = self.seed;
         let ssl_no_revoke = self.ssl_no_revoke;
+        let strict_arithmetic = self.strict_arithmetic;
         let negotiate = self.negotiate;
         let ntlm = self.ntlm;
         let timeout = self.timeout;
@@ -678,7 +745,9 @@ impl CliOptions {
             .resolves(&resolves)
             .retry(retry)
             .retry_interval(retry_interval)
+            .seed(seed)
             .ssl_no_revoke(ssl_no_revoke)
+            .strict_arithmetic(strict_arithmetic)
             .timeout(timeout)
             .to_entry(to_entry)
             .unix_socket(unix_socket)
@@ -688,6 +757,14 @@ impl CliOptions {
 
         // Set JavaScript filter file path if specified
         builder.jsfilter_path(self.jsfilter.clone());
+        builder.jsfilter_config(self.jsfilter_args.clone());
+        builder.jsfilter_args_mode(self.jsfilter_args_mode);
+        builder.jsfilter_date_objects(self.jsfilter_date_objects);
+        builder.jsfilter_prefix(&self.jsfilter_prefix);
+        builder.jsfilter_shared_global(self.jsfilter_shared_global);
+        builder.jsfilter_strict(self.jsfilter_strict);
+        builder.jsfilter_tuple_results(self.jsfilter_tuple_results);
+        builder.jsfilter_undefined_unchanged(self.jsfilter_undefined_unchanged);
 
         builder.build()
     }
@@ -70,7 +70,9 @@ fn main() -> ExitCode {
     let opts = match cli::options::parse(&ctx) {
         Ok(v) => v,
         Err(e) => match e {
-            CliOptionsError::DisplayHelp(e) | CliOptionsError::DisplayVersion(e) => {
+            CliOptionsError::DisplayHelp(e)
+            | CliOptionsError::DisplayVersion(e)
+            | CliOptionsError::Info(e) => {
                 print!("{e}");
                 return ExitCode::from(EXIT_OK);
             }
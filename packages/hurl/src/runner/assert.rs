@@ -15,8 +15,6 @@
  * limitations under the License.
  *
  */
-use std::path::PathBuf;
-
 use hurl_core::ast::{Assert, SourceInfo};
 use hurl_core::reader::Pos;
 
@@ -26,7 +24,7 @@ use crate::util::path::ContextDir;
 use super::cache::BodyCache;
 use super::diff::diff;
 use super::error::{RunnerError, RunnerErrorKind};
-use super::filter::eval_filters_with_js;
+use super::filter::{eval_filters_with_context, FilterContext};
 use super::predicate::eval_predicate;
 use super::query::eval_query;
 use super::result::AssertResult;
@@ -156,7 +154,7 @@ pub fn eval_explicit_assert(
     http_responses: &[&http::Response],
     cache: &mut BodyCache,
     context_dir: &ContextDir,
-    jsfilter_path: &Option<PathBuf>,
+    filter_context: &FilterContext,
 ) -> AssertResult {
     let query_result = eval_query(&assert.query, variables, http_responses, cache);
 
@@ -176,7 +174,7 @@ pub fn eval_explicit_assert(
             }),
             Some(value) => {
                 let filters = assert.filters.iter().map(|(_, f)| f).collect::<Vec<_>>();
-                match eval_filters_with_js(&filters, &value, variables, jsfilter_path, true) {
+                match eval_filters_with_context(&filters, &value, variables, filter_context, true) {
                     Ok(value) => Ok(value),
                     Err(e) => Err(e),
                 }
@@ -278,7 +276,7 @@ pub mod tests {
                 &[&xml_three_users_http_response()],
                 &mut cache,
                 &context_dir,
-                &None,
+                &FilterContext::default(),
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(3)))),
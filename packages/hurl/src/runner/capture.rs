@@ -15,15 +15,13 @@
  * limitations under the License.
  *
  */
-use std::path::PathBuf;
-
 use hurl_core::ast::{Capture, SourceInfo};
 
 use crate::http;
 
 use super::cache::BodyCache;
 use super::error::{RunnerError, RunnerErrorKind};
-use super::filter::eval_filters_with_js;
+use super::filter::{eval_filters_with_context, FilterContext};
 use super::query::eval_query;
 use super::result::CaptureResult;
 use super::template::eval_template;
@@ -39,14 +37,14 @@ pub fn eval_capture(
     variables: &VariableSet,
     http_responses: &[&http::Response],
     cache: &mut BodyCache,
-    jsfilter_path: &Option<PathBuf>,
+    filter_context: &FilterContext,
 ) -> Result<CaptureResult, RunnerError> {
     let name = eval_template(&capture.name, variables)?;
     let value = eval_query(&capture.query, variables, http_responses, cache)?;
     let value = match value {
         Some(value) => {
             let filters = capture.filters.iter().map(|(_, f)| f).collect::<Vec<_>>();
-            match eval_filters_with_js(&filters, &value, variables, jsfilter_path, false)? {
+            match eval_filters_with_context(&filters, &value, variables, filter_context, false)? {
                 None => {
                     // If we have an error, we can be sure that there is at least one filter.
                     // We don't know which filter in the filter chain firstly returns no value
@@ -194,7 +192,7 @@ pub mod tests {
             &variables,
             &[&http::xml_three_users_http_response()],
             &mut cache,
-            &None,
+            &FilterContext::default(),
         )
         .err()
         .unwrap();
@@ -260,7 +258,7 @@ pub mod tests {
                 &variables,
                 &[&http::xml_three_users_http_response()],
                 &mut cache,
-                &None,
+                &FilterContext::default(),
             )
             .unwrap(),
             CaptureResult {
@@ -275,7 +273,7 @@ pub mod tests {
                 &variables,
                 &[&http::json_http_response()],
                 &mut cache,
-                &None,
+                &FilterContext::default(),
             )
             .unwrap(),
             CaptureResult {
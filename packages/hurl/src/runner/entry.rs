@@ -27,6 +27,7 @@ use crate::util::term::WriteMode;
 
 use super::cache::BodyCache;
 use super::error::{RunnerError, RunnerErrorKind};
+use super::filter::{self, FilterContext};
 use super::request;
 use super::response;
 use super::result::{AssertResult, CaptureResult, EntryResult};
@@ -39,12 +40,14 @@ use super::variable::VariableSet;
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+/// `filter_context` holds the settings and state of the filters shared by the entries of a file.
 pub fn run(
     entry: &Entry,
     entry_index: Index,
     http_client: &mut http::Client,
     variables: &mut VariableSet,
     runner_options: &RunnerOptions,
+    filter_context: &FilterContext,
     logger: &mut Logger,
 ) -> EntryResult {
     let compressed = runner_options.compressed;
@@ -74,6 +77,19 @@ pub fn run(
         }
     }
 
+    // Fails fast if the entry uses undefined JavaScript filters.
+    if let Some(path) = filter_context.jsfilter_path() {
+        if let Err(error) = filter::check_js_filters(entry, variables, path) {
+            return EntryResult {
+                entry_index,
+                source_info,
+                errors: vec![error],
+                compressed,
+                ..Default::default()
+            };
+        }
+    }
+
     // Evaluates our source requests given our set of variables
     let http_request = match request::eval_request(&entry.request, variables, context_dir) {
         Ok(r) => r,
@@ -139,6 +155,23 @@ pub fn run(
     // `transfer_duration` represent the network time of calls, not including assert processing.
     let transfer_duration = calls.iter().map(|call| call.timings.total).sum();
 
+    // JavaScript filters can access the last responses through the `response` and
+    // `client.history` globals.
+    let jsfilter_path = filter_context.jsfilter_path();
+    if let Err(error) = filter::record_js_response(entry, jsfilter_path, http_response) {
+        return EntryResult {
+            entry_index,
+            source_info,
+            calls,
+            captures: vec![],
+            asserts: vec![],
+            errors: vec![error],
+            transfer_duration,
+            compressed,
+            curl_cmd,
+        };
+    }
+
     // We proceed asserts and captures in this order:
     // 1. first, check implicit assert on status and version. If KO, test is failed
     // 2. then, we compute captures, we might need them in asserts
@@ -172,7 +205,9 @@ pub fn run(
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, &responses, &mut cache, variables, &runner_options.jsfilter_path) {
+            let filter_context = filter_context.with_logger(logger);
+            let captures = response::eval_captures(response_spec, &responses, &mut cache, variables, &filter_context);
+            match captures {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -208,7 +243,7 @@ pub fn run(
                 &responses,
                 &mut cache,
                 context_dir,
-                &runner_options.jsfilter_path,
+                &filter_context.with_logger(logger),
             );
             asserts.append(&mut other_asserts);
         }
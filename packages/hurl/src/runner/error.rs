@@ -85,26 +85,74 @@ pub enum RunnerErrorKind {
         path: PathBuf,
         error: String,
     },
+    /// An arithmetic filter overflows in strict arithmetic mode, with the overflowing operation.
+    FilterArithmeticOverflow(String),
     FilterDecode(String),
+    /// A filter divides by zero.
+    FilterDivisionByZero,
     FilterDateParsingError {
         date: String,
         format: String,
     },
+    /// The JavaScript predicate of an `expect` filter is falsy, with the user message.
+    FilterExpectFailed(String),
     FilterInvalidEncoding(String),
     /// Input of the filter is not valid, with a given reason.
     FilterInvalidInput(String),
+    /// Input of the filter named `filter` is not valid, with a given reason.
+    FilterInvalidInputFor {
+        filter: String,
+        reason: String,
+    },
     FilterInvalidFormatSpecifier(String),
+    /// Pattern of the `numberFormat` filter is not valid.
+    FilterInvalidNumberFormat(String),
+    /// A JSON Patch operation can't be applied, with a given reason.
+    FilterJsonPatch(String),
     FilterMissingInput,
     /// jsfilter used but no --jsfilter file specified
     JsFilterNotConfigured,
     /// JavaScript filter function not found
     JsFilterFunctionNotFound {
-        name: String,
+        function: String,
+        available: Vec<String>,
+    },
+    /// JavaScript filter function called with fewer arguments than it declares
+    JsFilterArityMismatch {
+        function: String,
+        expected: usize,
+        got: usize,
     },
     /// JavaScript runtime error
     JsFilterRuntimeError {
         message: String,
     },
+    /// JavaScript filters used in an entry are not defined
+    JsFilterUnknownFilters {
+        names: Vec<String>,
+        available: Vec<String>,
+    },
+    /// Value can't be converted between Hurl and JavaScript
+    JsFilterConversionError {
+        message: String,
+    },
+    /// JavaScript filter file can't be loaded
+    JsFilterFileLoadError {
+        path: String,
+        message: String,
+    },
+    /// JavaScript filter file doesn't exist
+    JsFilterFileNotFound {
+        path: String,
+    },
+    /// JavaScript filter file can't be read with the current permissions
+    JsFilterFilePermissionDenied {
+        path: String,
+    },
+    /// JavaScript filter code can't be parsed
+    JsFilterParseError {
+        message: String,
+    },
     Http(HttpError),
     InvalidJson {
         value: String,
@@ -159,14 +207,31 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::FileReadAccess { .. } => "File read access".to_string(),
             RunnerErrorKind::FileWriteAccess { .. } => "File write access".to_string(),
             RunnerErrorKind::FilterDateParsingError { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterArithmeticOverflow { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterDecode { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterDivisionByZero => "Filter error".to_string(),
+            RunnerErrorKind::FilterExpectFailed { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidInput { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterInvalidInputFor { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidFormatSpecifier { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterInvalidNumberFormat { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterJsonPatch { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterMissingInput => "Filter error".to_string(),
             RunnerErrorKind::JsFilterNotConfigured => "JavaScript filter error".to_string(),
             RunnerErrorKind::JsFilterFunctionNotFound { .. } => "JavaScript filter error".to_string(),
+            RunnerErrorKind::JsFilterArityMismatch { .. } => "JavaScript filter error".to_string(),
             RunnerErrorKind::JsFilterRuntimeError { .. } => "JavaScript filter error".to_string(),
+            RunnerErrorKind::JsFilterUnknownFilters { .. } => "JavaScript filter error".to_string(),
+            RunnerErrorKind::JsFilterConversionError { .. } => {
+                "JavaScript filter error".to_string()
+            }
+            RunnerErrorKind::JsFilterFileLoadError { .. } => "JavaScript filter error".to_string(),
+            RunnerErrorKind::JsFilterFileNotFound { .. } => "JavaScript filter error".to_string(),
+            RunnerErrorKind::JsFilterFilePermissionDenied { .. } => {
+                "JavaScript filter error".to_string()
+            }
+            RunnerErrorKind::JsFilterParseError { .. } => "JavaScript filter error".to_string(),
             RunnerErrorKind::Http(http_error) => http_error.description(),
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
@@ -257,11 +322,25 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterArithmeticOverflow(operation) => {
+                let message = &format!("arithmetic overflow in <{operation}>");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterDecode(encoding) => {
                 let message = &format!("value can not be decoded with <{encoding}> encoding");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterDivisionByZero => {
+                let message = "division by zero";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::FilterExpectFailed(message) => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterInvalidEncoding(encoding) => {
                 let message = &format!("<{encoding}> encoding is not supported");
                 let message = error::add_carets(message, self.source_info, content);
@@ -272,11 +351,26 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterInvalidInputFor { filter, reason } => {
+                let message = &format!("invalid input for filter <{filter}>: {reason}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterInvalidFormatSpecifier(format) => {
                 let message = &format!("date format <{format}> is not supported");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterInvalidNumberFormat(format) => {
+                let message = &format!("number format <{format}> is not supported");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::FilterJsonPatch(reason) => {
+                let message = &format!("JSON patch can not be applied: {reason}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterMissingInput => {
                 let message = "missing value to apply filter";
                 let message = error::add_carets(message, self.source_info, content);
@@ -287,8 +381,25 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
-            RunnerErrorKind::JsFilterFunctionNotFound { name } => {
-                let message = &format!("JavaScript filter function 'filter_{name}' not found");
+            RunnerErrorKind::JsFilterFunctionNotFound {
+                function,
+                available,
+            } => {
+                let message = if available.is_empty() {
+                    format!("JavaScript filter function '{function}' not found")
+                } else {
+                    let available = available.join(", ");
+                    format!("JavaScript filter function '{function}' not found (available filters: {available})")
+                };
+                let message = error::add_carets(&message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::JsFilterArityMismatch {
+                function,
+                expected,
+                got,
+            } => {
+                let message = &format!("JavaScript filter function '{function}' expects {expected} arguments (including input), got {got}");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
@@ -297,6 +408,43 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::JsFilterUnknownFilters { names, available } => {
+                let names = names.join(", ");
+                let message = if available.is_empty() {
+                    format!("unknown JavaScript filters: {names}")
+                } else {
+                    let available = available.join(", ");
+                    format!("unknown JavaScript filters: {names} (available filters: {available})")
+                };
+                let message = error::add_carets(&message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::JsFilterConversionError { message: msg } => {
+                let message = &format!("JavaScript value conversion error: {msg}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::JsFilterFileLoadError { path, message: msg } => {
+                let message = &format!("failed to load JavaScript file '{path}': {msg}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::JsFilterFileNotFound { path } => {
+                let message = &format!("JavaScript file '{path}' not found");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::JsFilterFilePermissionDenied { path } => {
+                let message =
+                    &format!("JavaScript file '{path}' can not be read (permission denied)");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::JsFilterParseError { message: msg } => {
+                let message = &format!("JavaScript parse error: {msg}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::Http(http_error) => {
                 let message = http_error.message();
                 let message = error::add_carets(&message, self.source_info, content);
@@ -76,7 +76,7 @@ mod tests {
 
         let data_chrono = chrono::DateTime::parse_from_rfc2822("Tue, 10 Jan 2023 08:29:52 GMT")
             .unwrap()
-            .into();
+            .with_timezone(&chrono_tz::UTC);
         variables.insert("now".to_string(), Value::Date(data_chrono));
         let expr = Expr {
             kind: ExprKind::Variable(Variable {
@@ -16,77 +16,102 @@
  *
  */
 use hurl_core::ast::{NumberValue, Placeholder, SourceInfo};
+use num_bigint::BigInt;
 
 use crate::runner::{expr, Number, RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Adds a number `addend` to the input `value`.
+///
+/// An integer overflow wraps around the boundary of the type, or raises an error in `strict`
+/// arithmetic mode.
 pub fn eval_add(
     value: &Value,
     addend: &NumberValue,
     variables: &VariableSet,
+    strict: bool,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     let addend = eval_number_value(addend, variables)?;
 
-    match value {
-        Value::Number(n) => {
-            let result = add_numbers(n, &addend);
-            Ok(Some(Value::Number(result)))
-        }
+    let n = match value {
+        Value::Number(n) => n.clone(),
         Value::String(s) => {
             // Try to parse string as number
             if let Ok(i) = s.parse::<i64>() {
-                let n = Number::Integer(i);
-                let result = add_numbers(&n, &addend);
-                Ok(Some(Value::Number(result)))
+                Number::Integer(i)
+            } else if s.parse::<BigInt>().is_ok() {
+                Number::BigInteger(s.clone())
             } else if let Ok(f) = s.parse::<f64>() {
-                let n = Number::Float(f);
-                let result = add_numbers(&n, &addend);
-                Ok(Some(Value::Number(result)))
+                Number::Float(f)
             } else {
                 let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
-                Err(RunnerError::new(source_info, kind, assert))
+                return Err(RunnerError::new(source_info, kind, assert));
             }
         }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    match add_numbers(&n, &addend, strict) {
+        Some(result) => Ok(Some(Value::Number(result))),
+        None => {
+            let kind = RunnerErrorKind::FilterArithmeticOverflow(format!("{n} + {addend}"));
             Err(RunnerError::new(source_info, kind, assert))
         }
     }
 }
 
 /// Adds two numbers, handling type promotion.
-fn add_numbers(a: &Number, b: &Number) -> Number {
-    match (a, b) {
+///
+/// Big integers are added exactly, unless the other operand is a float. Returns `None` if the sum
+/// of two integers overflows in `strict` mode, otherwise the sum wraps.
+fn add_numbers(a: &Number, b: &Number, strict: bool) -> Option<Number> {
+    let sum = match (a, b) {
         (Number::Integer(i1), Number::Integer(i2)) => {
-            // Use wrapping_add for silent overflow
-            Number::Integer(i1.wrapping_add(*i2))
+            if strict {
+                Number::Integer(i1.checked_add(*i2)?)
+            } else {
+                Number::Integer(i1.wrapping_add(*i2))
+            }
         }
         (Number::Integer(i), Number::Float(f)) => Number::Float(*i as f64 + f),
         (Number::Float(f), Number::Integer(i)) => Number::Float(f + *i as f64),
         (Number::Float(f1), Number::Float(f2)) => Number::Float(f1 + f2),
-        // BigInteger is not supported for arithmetic operations
+        (Number::BigInteger(_), Number::Float(_)) | (Number::Float(_), Number::BigInteger(_)) => {
+            Number::Float(a.to_f64() + b.to_f64())
+        }
         (Number::BigInteger(_), _) | (_, Number::BigInteger(_)) => {
-            // Fall back to float conversion for BigInteger
-            let f1 = number_to_f64(a);
-            let f2 = number_to_f64(b);
-            Number::Float(f1 + f2)
+            match (to_big_int(a), to_big_int(b)) {
+                (Some(i1), Some(i2)) => from_big_int(i1 + i2),
+                // Fall back to float conversion for invalid big integers
+                _ => Number::Float(a.to_f64() + b.to_f64()),
+            }
         }
-    }
+    };
+    Some(sum)
 }
 
-/// Converts a Number to f64.
-fn number_to_f64(n: &Number) -> f64 {
+/// Converts an integer or a big integer to a [`BigInt`].
+fn to_big_int(n: &Number) -> Option<BigInt> {
     match n {
-        Number::Integer(i) => *i as f64,
-        Number::Float(f) => *f,
-        Number::BigInteger(s) => s.parse::<f64>().unwrap_or(f64::NAN),
+        Number::Integer(i) => Some(BigInt::from(*i)),
+        Number::BigInteger(s) => s.parse().ok(),
+        Number::Float(_) => None,
+    }
+}
+
+/// Converts a [`BigInt`] to an integer if it fits in 64 bits, or to a big integer otherwise.
+fn from_big_int(n: BigInt) -> Number {
+    match i64::try_from(&n) {
+        Ok(i) => Number::Integer(i),
+        Err(_) => Number::BigInteger(n.to_string()),
     }
 }
 
 /// Evaluates a [`NumberValue`] against a variable set.
-fn eval_number_value(n: &NumberValue, variables: &VariableSet) -> Result<Number, RunnerError> {
+pub fn eval_number_value(n: &NumberValue, variables: &VariableSet) -> Result<Number, RunnerError> {
     match n {
         NumberValue::Literal(number) => Ok(ast_number_to_runner_number(number)),
         NumberValue::Placeholder(Placeholder { expr, .. }) => match expr::eval(expr, variables)? {
@@ -117,7 +142,8 @@ mod tests {
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
 
-    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::{eval_filter, eval_filter_with_context};
     use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
     fn whitespace() -> Whitespace {
@@ -324,6 +350,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eval_filter_add_overflow_strict() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Add {
+                space0: whitespace(),
+                value: NumberValue::Literal(hurl_core::ast::Number::Integer(I64::new(
+                    1,
+                    "1".to_source(),
+                ))),
+            },
+        };
+
+        let context = FilterContext::default().with_strict_arithmetic(true);
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(i64::MAX)),
+            &variables,
+            &context,
+            false,
+        );
+        let ret_below = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(i64::MAX - 1)),
+            &variables,
+            &context,
+            false,
+        );
+
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterArithmeticOverflow("9223372036854775807 + 1".to_string())
+        );
+        assert_eq!(
+            ret_below.unwrap().unwrap(),
+            Value::Number(Number::Integer(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn eval_filter_add_big_integer() {
+        let variables = VariableSet::new();
+        let filter = |value| Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Add {
+                space0: whitespace(),
+                value: NumberValue::Literal(value),
+            },
+        };
+        let add_one = filter(hurl_core::ast::Number::Integer(I64::new(
+            1,
+            "1".to_source(),
+        )));
+        let add_big = filter(hurl_core::ast::Number::BigInteger(
+            "10000000000000000000".to_string(),
+        ));
+        let add_float = filter(hurl_core::ast::Number::Float(hurl_core::ast::Float::new(
+            0.5,
+            "0.5".to_source(),
+        )));
+        let big = Value::Number(Number::BigInteger("9223372036854775808".to_string()));
+
+        let ret = eval_filter(&add_one, &big, &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger("9223372036854775809".to_string()))
+        );
+
+        let ret = eval_filter(&add_big, &big, &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger("19223372036854775808".to_string()))
+        );
+
+        let ret = eval_filter(
+            &add_big,
+            &Value::Number(Number::Integer(i64::MAX)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger("19223372036854775807".to_string()))
+        );
+
+        let ret = eval_filter(
+            &add_one,
+            &Value::String("123456789012345678901234567890".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger(
+                "123456789012345678901234567891".to_string()
+            ))
+        );
+
+        // Back to a 64 bits integer when the sum fits
+        let ret = eval_filter(
+            &add_big,
+            &Value::Number(Number::BigInteger("-10000000000000000001".to_string())),
+            &variables,
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(-1)));
+
+        // Promoted to float with a float operand
+        let ret = eval_filter(&add_float, &big, &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::Float(9223372036854775808.5))
+        );
+    }
+
     #[test]
     fn eval_filter_add_invalid_input() {
         let variables = VariableSet::new();
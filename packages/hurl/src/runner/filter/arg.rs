@@ -0,0 +1,133 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{Template, TemplateElement};
+
+use crate::runner::template::eval_template;
+use crate::runner::{expr, Number, RunnerError, Value, VariableSet};
+
+/// Evaluates a filter argument: a placeholder is evaluated to its value, a quoted template to a
+/// string and an unquoted literal to a number.
+///
+/// All the filters taking free arguments (`coalesce`, `jsfilter`, the `jsonpath` default value,
+/// the `reduce` initial value) evaluate them with this function, so an argument has the same value
+/// whatever the filter.
+pub fn eval_arg(arg: &Template, variables: &VariableSet) -> Result<Value, RunnerError> {
+    if let (None, [TemplateElement::Placeholder(placeholder)]) =
+        (arg.delimiter, arg.elements.as_slice())
+    {
+        return expr::eval(&placeholder.expr, variables);
+    }
+    let s = eval_template(arg, variables)?;
+    if arg.delimiter.is_none() {
+        if let Ok(i) = s.parse::<i64>() {
+            return Ok(Value::Number(Number::Integer(i)));
+        }
+        if let Ok(f) = s.parse::<f64>() {
+            return Ok(Value::Number(Number::Float(f)));
+        }
+    }
+    Ok(Value::String(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Expr, ExprKind, Placeholder, SourceInfo, Variable, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use super::*;
+
+    fn placeholder(delimiter: Option<char>, name: &str) -> Template {
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info,
+        };
+        let placeholder = Placeholder {
+            space0: whitespace.clone(),
+            expr: Expr {
+                kind: ExprKind::Variable(Variable {
+                    name: name.to_string(),
+                    source_info,
+                }),
+                source_info,
+            },
+            space1: whitespace,
+        };
+        Template::new(
+            delimiter,
+            vec![TemplateElement::Placeholder(placeholder)],
+            source_info,
+        )
+    }
+
+    fn literal(delimiter: Option<char>, value: &str) -> Template {
+        Template::new(
+            delimiter,
+            vec![TemplateElement::String {
+                value: value.to_string(),
+                source: value.to_source(),
+            }],
+            SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        )
+    }
+
+    #[test]
+    fn test_eval_arg_literal() {
+        let variables = VariableSet::new();
+        assert_eq!(
+            eval_arg(&literal(None, "42"), &variables).unwrap(),
+            Value::Number(Number::Integer(42))
+        );
+        assert_eq!(
+            eval_arg(&literal(None, "-10"), &variables).unwrap(),
+            Value::Number(Number::Integer(-10))
+        );
+        assert_eq!(
+            eval_arg(&literal(None, "1.5"), &variables).unwrap(),
+            Value::Number(Number::Float(1.5))
+        );
+        assert_eq!(
+            eval_arg(&literal(Some('"'), "42"), &variables).unwrap(),
+            Value::String("42".to_string())
+        );
+        assert_eq!(
+            eval_arg(&literal(Some('"'), "hello"), &variables).unwrap(),
+            Value::String("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_eval_arg_placeholder() {
+        let mut variables = VariableSet::new();
+        variables.insert("n".to_string(), Value::Number(Number::Integer(42)));
+        variables.insert("b".to_string(), Value::Bool(true));
+        assert_eq!(
+            eval_arg(&placeholder(None, "n"), &variables).unwrap(),
+            Value::Number(Number::Integer(42))
+        );
+        assert_eq!(
+            eval_arg(&placeholder(None, "b"), &variables).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval_arg(&placeholder(Some('"'), "n"), &variables).unwrap(),
+            Value::String("42".to_string())
+        );
+    }
+}
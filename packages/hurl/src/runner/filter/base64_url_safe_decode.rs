@@ -15,35 +15,34 @@
  * limitations under the License.
  *
  */
-use base64::prelude::BASE64_URL_SAFE_NO_PAD;
-use base64::DecodeError::InvalidPadding;
+use base64::alphabet::URL_SAFE;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
 use base64::Engine;
 use hurl_core::ast::SourceInfo;
 
 use crate::runner::{RunnerError, RunnerErrorKind, Value};
 
+/// Base 64 URL safe engine accepting both padded and unpadded input.
+const BASE64_URL_SAFE_INDIFFERENT: GeneralPurpose = GeneralPurpose::new(
+    &URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
 /// Decode base 64 URL safe encoded string 'value' into bytes.
+///
+/// The `=` padding of the encoded string is optional.
 pub fn eval_base64_url_safe_decode(
     value: &Value,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match value {
-        Value::String(value) => match BASE64_URL_SAFE_NO_PAD.decode(value) {
+        Value::String(value) => match BASE64_URL_SAFE_INDIFFERENT.decode(value) {
             Ok(decoded) => Ok(Some(Value::Bytes(decoded))),
-            Err(err) => match err {
-                InvalidPadding => {
-                    let kind = RunnerErrorKind::FilterInvalidInput(
-                        "base64 string contains padding".to_string(),
-                    );
-                    Err(RunnerError::new(source_info, kind, assert))
-                }
-                _ => {
-                    let kind =
-                        RunnerErrorKind::FilterInvalidInput("string is not base64".to_string());
-                    Err(RunnerError::new(source_info, kind, assert))
-                }
-            },
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInput("string is not base64".to_string());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
         },
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
@@ -99,12 +98,13 @@ mod tests {
     }
 
     #[test]
-    fn eval_filter_base64_url_safe_decode_ko_contains_padding() {
+    fn eval_filter_base64_url_safe_decode_padding() {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
             value: FilterValue::Base64UrlSafeDecode,
         };
+        let bytes = "шеллы".as_bytes().to_vec();
 
         let ret = eval_filter(
             &filter,
@@ -112,9 +112,25 @@ mod tests {
             &variables,
             false,
         );
+        assert_eq!(ret.unwrap().unwrap(), Value::Bytes(bytes.clone()));
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("0YjQtdC70LvRiw".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Bytes(bytes));
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("0YjQtdC70LvRiw===".to_string()),
+            &variables,
+            false,
+        );
         assert_eq!(
             ret.unwrap_err().kind,
-            RunnerErrorKind::FilterInvalidInput("base64 string contains padding".to_string())
+            RunnerErrorKind::FilterInvalidInput("string is not base64".to_string())
         );
     }
 
@@ -15,20 +15,30 @@
  * limitations under the License.
  *
  */
-use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::prelude::{BASE64_URL_SAFE, BASE64_URL_SAFE_NO_PAD};
 use base64::Engine;
 use hurl_core::ast::SourceInfo;
 
 use crate::runner::{RunnerError, RunnerErrorKind, Value};
 
 /// Encode bytes 'value' into base 64 URL safe encoded string.
+///
+/// The encoded string isn't padded with `=`, unless `pad` is set.
 pub fn eval_base64_url_safe_encode(
     value: &Value,
+    pad: bool,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match value {
-        Value::Bytes(value) => Ok(Some(Value::String(BASE64_URL_SAFE_NO_PAD.encode(value)))),
+        Value::Bytes(value) => {
+            let encoded = if pad {
+                BASE64_URL_SAFE.encode(value)
+            } else {
+                BASE64_URL_SAFE_NO_PAD.encode(value)
+            };
+            Ok(Some(Value::String(encoded)))
+        }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
             Err(RunnerError::new(source_info, kind, assert))
@@ -38,7 +48,7 @@ pub fn eval_base64_url_safe_encode(
 
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
     use hurl_core::reader::Pos;
 
     use super::*;
@@ -50,7 +60,7 @@ mod tests {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
-            value: FilterValue::Base64UrlSafeEncode,
+            value: FilterValue::Base64UrlSafeEncode { pad: None },
         };
         let bytes = vec![0x3c, 0x3c, 0x3f, 0x3f, 0x3f, 0x3e, 0x3e];
 
@@ -61,12 +71,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eval_filter_base64_url_safe_encode_pad() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base64UrlSafeEncode {
+                pad: Some(Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 20), Pos::new(1, 21)),
+                }),
+            },
+        };
+        let bytes = vec![0x3c, 0x3c, 0x3f, 0x3f, 0x3f, 0x3e, 0x3e];
+
+        let ret = eval_filter(&filter, &Value::Bytes(bytes), &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("PDw_Pz8-Pg==".to_string())
+        );
+    }
+
     #[test]
     fn eval_filter_base64_url_safe_encode_ko_invalid_input() {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
-            value: FilterValue::Base64UrlSafeEncode,
+            value: FilterValue::Base64UrlSafeEncode { pad: None },
         };
 
         let ret = eval_filter(
@@ -0,0 +1,171 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::cmp::Ordering;
+
+use hurl_core::ast::{NumberValue, SourceInfo};
+
+use crate::runner::filter::add::eval_number_value;
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns true if the number `value` is within the range [`min`, `max`], false otherwise.
+/// Bounds are excluded from the range if `exclusive` is true. Numeric strings are accepted.
+pub fn eval_between(
+    value: &Value,
+    min: &NumberValue,
+    max: &NumberValue,
+    exclusive: bool,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let min = eval_number_value(min, variables)?;
+    let max = eval_number_value(max, variables)?;
+    if min.cmp_value(&max) == Ordering::Greater {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!(
+            "invalid range, min <{min}> is greater than max <{max}>"
+        ));
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+
+    let n = match value {
+        Value::Number(n) => n.clone(),
+        Value::String(s) => match s.parse::<i64>() {
+            Ok(i) => Number::Integer(i),
+            Err(_) => match s.parse::<f64>() {
+                Ok(f) if !f.is_nan() => Number::Float(f),
+                _ => {
+                    let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+                    return Err(RunnerError::new(source_info, kind, assert));
+                }
+            },
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+
+    let above_min = n.cmp_value(&min);
+    let below_max = n.cmp_value(&max);
+    let within = if exclusive {
+        above_min == Ordering::Greater && below_max == Ordering::Less
+    } else {
+        above_min != Ordering::Less && below_max != Ordering::Greater
+    };
+    Ok(Some(Value::Bool(within)))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, NumberValue, SourceInfo, Whitespace, I64};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
+    fn number_value(n: i64) -> NumberValue {
+        NumberValue::Literal(hurl_core::ast::Number::Integer(I64::new(
+            n,
+            n.to_string().to_source(),
+        )))
+    }
+
+    fn new_filter(min: i64, max: i64, exclusive: bool) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Between {
+                space0: whitespace(),
+                min: Box::new(number_value(min)),
+                space1: whitespace(),
+                max: Box::new(number_value(max)),
+                exclusive: exclusive.then(whitespace),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_between() {
+        let variables = VariableSet::new();
+        let inclusive = new_filter(1, 10, false);
+        let exclusive = new_filter(1, 10, true);
+        let values = [
+            (Value::Number(Number::Integer(0)), false, false),
+            (Value::Number(Number::Integer(1)), true, false),
+            (Value::Number(Number::Float(5.5)), true, true),
+            (Value::String("7".to_string()), true, true),
+            (Value::Number(Number::Integer(10)), true, false),
+            (Value::Number(Number::Float(10.1)), false, false),
+        ];
+        for (value, expected_inclusive, expected_exclusive) in values {
+            assert_eq!(
+                eval_filter(&inclusive, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::Bool(expected_inclusive)
+            );
+            assert_eq!(
+                eval_filter(&exclusive, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::Bool(expected_exclusive)
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_between_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(10, 1, false);
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(5)),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "invalid range, min <10> is greater than max <1>".to_string()
+            )
+        );
+
+        let filter = new_filter(1, 10, false);
+        let err = eval_filter(
+            &filter,
+            &Value::String("abc".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("string <abc>".to_string())
+        );
+    }
+}
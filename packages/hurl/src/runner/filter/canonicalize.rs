@@ -0,0 +1,153 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, Value};
+
+/// Returns a canonical form of `value`, where the keys of every object are recursively sorted.
+/// The order of list items is preserved.
+pub fn eval_canonicalize(
+    value: &Value,
+    _source_info: SourceInfo,
+    _assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    Ok(Some(canonicalize(value)))
+}
+
+fn canonicalize(value: &Value) -> Value {
+    match value {
+        Value::Object(fields) => {
+            let mut fields = fields
+                .iter()
+                .map(|(key, value)| (key.clone(), canonicalize(value)))
+                .collect::<Vec<_>>();
+            fields.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
+            Value::Object(fields)
+        }
+        Value::List(values) => Value::List(values.iter().map(canonicalize).collect()),
+        v => v.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, Value, VariableSet};
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn canonicalize(value: &Value) -> Value {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Canonicalize,
+        };
+        eval_filter(&filter, value, &variables, false)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn eval_filter_canonicalize_nested() {
+        let value = object(&[
+            ("b", Value::Number(Number::Integer(1))),
+            (
+                "a",
+                object(&[
+                    ("z", Value::Bool(true)),
+                    (
+                        "y",
+                        Value::List(vec![
+                            object(&[("d", Value::Null), ("c", Value::Null)]),
+                            Value::Number(Number::Integer(2)),
+                            Value::Number(Number::Integer(1)),
+                        ]),
+                    ),
+                ]),
+            ),
+        ]);
+        assert_eq!(
+            canonicalize(&value),
+            object(&[
+                (
+                    "a",
+                    object(&[
+                        (
+                            "y",
+                            Value::List(vec![
+                                object(&[("c", Value::Null), ("d", Value::Null)]),
+                                Value::Number(Number::Integer(2)),
+                                Value::Number(Number::Integer(1)),
+                            ]),
+                        ),
+                        ("z", Value::Bool(true)),
+                    ]),
+                ),
+                ("b", Value::Number(Number::Integer(1))),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_canonicalize_stable() {
+        // Objects with the same content in different orders have the same canonical form.
+        let value1 = object(&[
+            ("name", Value::String("Bob".to_string())),
+            ("id", Value::Number(Number::Integer(1))),
+            (
+                "tags",
+                object(&[("b", Value::Bool(false)), ("a", Value::Bool(true))]),
+            ),
+        ]);
+        let value2 = object(&[
+            (
+                "tags",
+                object(&[("a", Value::Bool(true)), ("b", Value::Bool(false))]),
+            ),
+            ("id", Value::Number(Number::Integer(1))),
+            ("name", Value::String("Bob".to_string())),
+        ]);
+        let Value::Object(fields1) = canonicalize(&value1) else {
+            panic!("object expected");
+        };
+        let Value::Object(fields2) = canonicalize(&value2) else {
+            panic!("object expected");
+        };
+        let keys1 = fields1.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+        let keys2 = fields2.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+        assert_eq!(keys1, vec!["id", "name", "tags"]);
+        assert_eq!(keys1, keys2);
+        assert_eq!(fields1, fields2);
+    }
+
+    #[test]
+    fn eval_filter_canonicalize_scalar() {
+        let value = Value::String("abc".to_string());
+        assert_eq!(canonicalize(&value), value);
+    }
+}
@@ -0,0 +1,216 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts `value` to camel case (`httpServerName`).
+pub fn eval_camel_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_case(value, source_info, assert, |words| {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.clone()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect()
+    })
+}
+
+/// Converts `value` to kebab case (`http-server-name`).
+pub fn eval_kebab_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_case(value, source_info, assert, |words| words.join("-"))
+}
+
+/// Converts `value` to snake case (`http_server_name`).
+pub fn eval_snake_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_case(value, source_info, assert, |words| words.join("_"))
+}
+
+/// Splits a string `value` in lowercase words and joins them back with `join`.
+fn eval_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+    join: impl Fn(&[String]) -> String,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            let words = split_words(value);
+            Ok(Some(Value::String(join(&words))))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Splits an identifier-like string in lowercase words.
+///
+/// Words are separated by any non-alphanumeric character, and by case changes: a lowercase letter
+/// or a digit followed by an uppercase letter starts a new word (`fooBar`), and a run of
+/// uppercase letters is kept as a single word, except for its last letter when it starts a
+/// capitalized word (`HTTPServer` gives `http` and `server`).
+fn split_words(s: &str) -> Vec<String> {
+    let mut words = vec![];
+    for part in s.split(|c: char| !c.is_alphanumeric()) {
+        let chars = part.chars().collect::<Vec<_>>();
+        let mut word = String::new();
+        for (i, c) in chars.iter().enumerate() {
+            if i > 0 && c.is_uppercase() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                if !prev.is_uppercase() || next_is_lower {
+                    words.push(word.to_lowercase());
+                    word = String::new();
+                }
+            }
+            word.push(*c);
+        }
+        if !word.is_empty() {
+            words.push(word.to_lowercase());
+        }
+    }
+    words
+}
+
+/// Uppercases the first character of `word`.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::split_words;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(value: FilterValue) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_split_words() {
+        let tests = [
+            ("", vec![]),
+            ("foo", vec!["foo"]),
+            ("fooBar", vec!["foo", "bar"]),
+            ("FooBar", vec!["foo", "bar"]),
+            ("foo_bar", vec!["foo", "bar"]),
+            ("foo-bar", vec!["foo", "bar"]),
+            ("foo bar", vec!["foo", "bar"]),
+            ("__foo__bar__", vec!["foo", "bar"]),
+            ("HTTPServer", vec!["http", "server"]),
+            ("getHTTPResponse", vec!["get", "http", "response"]),
+            ("userID", vec!["user", "id"]),
+            ("HTTP", vec!["http"]),
+            ("SCREAMING_SNAKE_CASE", vec!["screaming", "snake", "case"]),
+            ("version2Alpha", vec!["version2", "alpha"]),
+            ("utf8", vec!["utf8"]),
+        ];
+        for (input, output) in tests {
+            assert_eq!(split_words(input), output, "{input}");
+        }
+    }
+
+    #[test]
+    fn eval_filter_case() {
+        let variables = VariableSet::new();
+        let camel = new_filter(FilterValue::CamelCase);
+        let kebab = new_filter(FilterValue::KebabCase);
+        let snake = new_filter(FilterValue::SnakeCase);
+
+        // Each input is given in each convention, and converted to each convention.
+        let tests = [
+            ("httpServerName", "http-server-name", "http_server_name"),
+            ("fooBar", "foo-bar", "foo_bar"),
+            ("foo", "foo", "foo"),
+            ("", "", ""),
+        ];
+        for (camel_case, kebab_case, snake_case) in tests {
+            for input in [camel_case, kebab_case, snake_case] {
+                for (filter, output) in [
+                    (&camel, camel_case),
+                    (&kebab, kebab_case),
+                    (&snake, snake_case),
+                ] {
+                    assert_eq!(
+                        eval_filter(filter, &Value::String(input.to_string()), &variables, false)
+                            .unwrap()
+                            .unwrap(),
+                        Value::String(output.to_string()),
+                        "{input}"
+                    );
+                }
+            }
+        }
+
+        // Acronyms are kept as a single word.
+        let input = Value::String("HTTPServer".to_string());
+        for (filter, output) in [
+            (&camel, "httpServer"),
+            (&kebab, "http-server"),
+            (&snake, "http_server"),
+        ] {
+            assert_eq!(
+                eval_filter(filter, &input, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+
+        let ret = eval_filter(
+            &snake,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer".to_string())
+        );
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{IntegerValue, SourceInfo};
+
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Keeps at most the first `max` elements of a list `value`, or the first `max` characters of a
+/// string `value`. Shorter values are returned unchanged.
+pub fn eval_clamp_length(
+    value: &Value,
+    max: &IntegerValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let max = eval_integer_value(max, variables)?;
+    if max < 0 {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!("length <{max}> is negative"));
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    let max = max as usize;
+
+    match value {
+        Value::List(values) => {
+            let values = values.iter().take(max).cloned().collect();
+            Ok(Some(Value::List(values)))
+        }
+        Value::String(s) => {
+            let s = s.chars().take(max).collect();
+            Ok(Some(Value::String(s)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, IntegerValue, SourceInfo, Whitespace, I64};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(max: i64) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ClampLength {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                max: IntegerValue::Literal(I64::new(max, max.to_string().to_source())),
+            },
+        }
+    }
+
+    fn list(values: &[i64]) -> Value {
+        Value::List(
+            values
+                .iter()
+                .map(|v| Value::Number(Number::Integer(*v)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn eval_filter_clamp_length_list() {
+        let variables = VariableSet::new();
+        let filter = new_filter(3);
+        assert_eq!(
+            eval_filter(&filter, &list(&[1, 2, 3, 4, 5]), &variables, false)
+                .unwrap()
+                .unwrap(),
+            list(&[1, 2, 3])
+        );
+        assert_eq!(
+            eval_filter(&filter, &list(&[1, 2]), &variables, false)
+                .unwrap()
+                .unwrap(),
+            list(&[1, 2])
+        );
+    }
+
+    #[test]
+    fn eval_filter_clamp_length_string() {
+        let variables = VariableSet::new();
+        let filter = new_filter(4);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("héllo wörld".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("héll".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("日本".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("日本".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_clamp_length_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(-1);
+        let err = eval_filter(&filter, &list(&[1, 2]), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("length <-1> is negative".to_string())
+        );
+
+        let filter = new_filter(2);
+        let err = eval_filter(&filter, &Value::Bool(true), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean <true>".to_string())
+        );
+    }
+}
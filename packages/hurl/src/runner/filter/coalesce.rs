@@ -0,0 +1,166 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template, Whitespace};
+
+use crate::runner::filter::arg::eval_arg;
+use crate::runner::{RunnerError, Value, VariableSet};
+
+/// Returns `value` if it's not null or empty, else the first argument of `args` that is not null
+/// or empty, else null.
+///
+/// Arguments are evaluated lazily, in order: an argument after the first non-empty one is never
+/// evaluated.
+pub fn eval_coalesce(
+    value: &Value,
+    args: &[(Whitespace, Template)],
+    variables: &VariableSet,
+    _source_info: SourceInfo,
+    _assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    if !is_null_or_empty(value) {
+        return Ok(Some(value.clone()));
+    }
+    for (_, arg) in args {
+        let arg = eval_arg(arg, variables)?;
+        if !is_null_or_empty(&arg) {
+            return Ok(Some(arg));
+        }
+    }
+    Ok(Some(Value::Null))
+}
+
+/// Returns `true` if `value` is null, or an empty string, list, object or bytes.
+fn is_null_or_empty(value: &Value) -> bool {
+    match value {
+        Value::Bytes(v) => v.is_empty(),
+        Value::List(v) => v.is_empty(),
+        Value::Null => true,
+        Value::Object(v) => v.is_empty(),
+        Value::String(v) => v.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Placeholder, SourceInfo, Template, TemplateElement,
+        Variable, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, Value, VariableSet};
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
+    fn placeholder(name: &str) -> Template {
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let expr = Expr {
+            kind: ExprKind::Variable(Variable {
+                name: name.to_string(),
+                source_info,
+            }),
+            source_info,
+        };
+        let placeholder = Placeholder {
+            space0: Whitespace {
+                value: String::new(),
+                source_info,
+            },
+            expr,
+            space1: Whitespace {
+                value: String::new(),
+                source_info,
+            },
+        };
+        Template::new(
+            None,
+            vec![TemplateElement::Placeholder(placeholder)],
+            source_info,
+        )
+    }
+
+    fn literal(delimiter: Option<char>, value: &str) -> Template {
+        Template::new(
+            delimiter,
+            vec![TemplateElement::String {
+                value: value.to_string(),
+                source: value.to_source(),
+            }],
+            SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        )
+    }
+
+    #[test]
+    fn eval_filter_coalesce() {
+        let mut variables = VariableSet::new();
+        variables.insert("a".to_string(), Value::Null);
+        variables.insert("b".to_string(), Value::String("bob".to_string()));
+        variables.insert("c".to_string(), Value::List(vec![]));
+
+        let filter = |args: Vec<Template>| Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Coalesce {
+                args: args.into_iter().map(|arg| (whitespace(), arg)).collect(),
+            },
+        };
+        let coalesce = |value: Value, args: Vec<Template>| {
+            eval_filter(&filter(args), &value, &variables, false)
+                .unwrap()
+                .unwrap()
+        };
+
+        // Input present.
+        assert_eq!(
+            coalesce(Value::String("alice".to_string()), vec![placeholder("b")]),
+            Value::String("alice".to_string())
+        );
+        // First fallback used.
+        assert_eq!(
+            coalesce(Value::Null, vec![placeholder("b"), literal(Some('"'), "x")]),
+            Value::String("bob".to_string())
+        );
+        // Second fallback used.
+        assert_eq!(
+            coalesce(
+                Value::String(String::new()),
+                vec![placeholder("a"), literal(None, "42")]
+            ),
+            Value::Number(Number::Integer(42))
+        );
+        assert_eq!(
+            coalesce(
+                Value::Null,
+                vec![placeholder("c"), literal(Some('"'), "anonymous")]
+            ),
+            Value::String("anonymous".to_string())
+        );
+        // All null.
+        assert_eq!(
+            coalesce(Value::Null, vec![placeholder("a"), placeholder("c")]),
+            Value::Null
+        );
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Context of the filters evaluated during a Hurl file run.
+
+use std::cell::{RefCell, RefMut};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::util::logger::Logger;
+
+/// Settings and state shared by the filters evaluated during a Hurl file run, built from the
+/// runner options of the file.
+pub struct FilterContext<'a> {
+    /// Path of the JavaScript file defining the JavaScript filters, if any.
+    jsfilter_path: Option<PathBuf>,
+    /// Whether an integer overflow in the arithmetic filters is an error.
+    strict_arithmetic: bool,
+    /// Random generator of the non-deterministic filters like `sample` or `shuffle`.
+    rng: Rc<RefCell<StdRng>>,
+    /// Logger of the `debug` filters messages, if any.
+    logger: Option<RefCell<&'a mut Logger>>,
+}
+
+impl FilterContext<'_> {
+    /// Creates a new context, with an optional JavaScript filters file `jsfilter_path`.
+    ///
+    /// The random generator is seeded from the operating system, see [`FilterContext::with_seed`]
+    /// for reproducible runs.
+    pub fn new(jsfilter_path: Option<PathBuf>) -> Self {
+        FilterContext {
+            jsfilter_path,
+            strict_arithmetic: false,
+            rng: Rc::new(RefCell::new(StdRng::from_os_rng())),
+            logger: None,
+        }
+    }
+
+    /// Seeds the random generator of the filters with `seed`.
+    ///
+    /// With `None`, the generator is seeded from the operating system and runs are not reproducible.
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        if let Some(seed) = seed {
+            self.rng = Rc::new(RefCell::new(StdRng::seed_from_u64(seed)));
+        }
+        self
+    }
+
+    /// Sets the arithmetic mode of the filters.
+    ///
+    /// In strict mode, an integer overflow raises an error, otherwise the result silently wraps
+    /// around the boundary of the type.
+    pub fn with_strict_arithmetic(mut self, strict: bool) -> Self {
+        self.strict_arithmetic = strict;
+        self
+    }
+
+    /// Returns a context sharing the settings and the random generator of this context, where the
+    /// `debug` filters write their messages to `logger` as soon as they're evaluated.
+    pub fn with_logger<'b>(&self, logger: &'b mut Logger) -> FilterContext<'b> {
+        FilterContext {
+            jsfilter_path: self.jsfilter_path.clone(),
+            strict_arithmetic: self.strict_arithmetic,
+            rng: Rc::clone(&self.rng),
+            logger: Some(RefCell::new(logger)),
+        }
+    }
+
+    /// Returns the path of the JavaScript file defining the JavaScript filters, if any.
+    pub fn jsfilter_path(&self) -> &Option<PathBuf> {
+        &self.jsfilter_path
+    }
+
+    /// Returns `true` if the arithmetic filters are in strict mode.
+    pub fn strict_arithmetic(&self) -> bool {
+        self.strict_arithmetic
+    }
+
+    /// Returns the random generator of the filters.
+    pub fn rng(&self) -> RefMut<'_, StdRng> {
+        self.rng.borrow_mut()
+    }
+
+    /// Logs the `message` of a `debug` filter, if this context has a logger.
+    pub fn debug(&self, message: &str) {
+        if let Some(logger) = &self.logger {
+            logger.borrow_mut().debug(message);
+        }
+    }
+}
+
+impl Default for FilterContext<'_> {
+    fn default() -> Self {
+        FilterContext::new(None)
+    }
+}
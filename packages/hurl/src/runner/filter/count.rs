@@ -19,7 +19,9 @@ use hurl_core::ast::SourceInfo;
 
 use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
 
-/// Counts the number of items in a collection `value`.
+/// Counts the number of items in a collection `value`: the number of elements of a list, the
+/// number of key/value pairs of an object, the number of chars of a string and the number of bytes
+/// of a bytes buffer.
 pub fn eval_count(
     value: &Value,
     source_info: SourceInfo,
@@ -27,6 +29,11 @@ pub fn eval_count(
 ) -> Result<Option<Value>, RunnerError> {
     match value {
         Value::List(values) => Ok(Some(Value::Number(Number::Integer(values.len() as i64)))),
+        Value::Object(values) => Ok(Some(Value::Number(Number::Integer(values.len() as i64)))),
+        Value::String(s) => {
+            let count = s.chars().count() as i64;
+            Ok(Some(Value::Number(Number::Integer(count))))
+        }
         Value::Bytes(values) => Ok(Some(Value::Number(Number::Integer(values.len() as i64)))),
         Value::Nodeset(size) => Ok(Some(Value::Number(Number::Integer(*size as i64)))),
         v => {
@@ -81,4 +88,34 @@ mod tests {
             RunnerErrorKind::FilterInvalidInput("boolean".to_string())
         );
     }
+    #[test]
+    fn eval_filter_count_collections() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6)),
+            value: FilterValue::Count,
+        };
+        let values = [
+            (
+                Value::Object(vec![
+                    ("id".to_string(), Value::Number(Number::Integer(1))),
+                    ("name".to_string(), Value::String("Bob".to_string())),
+                ]),
+                2,
+            ),
+            (Value::Object(vec![]), 0),
+            (Value::List(vec![]), 0),
+            (Value::String("café".to_string()), 4),
+            (Value::String(String::new()), 0),
+            (Value::Bytes(vec![0xc3, 0xa9, 0x00]), 3),
+        ];
+        for (value, expected) in values {
+            assert_eq!(
+                eval_filter(&filter, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::Number(Number::Integer(expected))
+            );
+        }
+    }
 }
@@ -0,0 +1,292 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::{Datelike, Duration, Months, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// A period of time used to truncate dates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Period {
+    Day,
+    Hour,
+    Minute,
+    Month,
+    Year,
+}
+
+impl Period {
+    fn parse(unit: &str) -> Option<Period> {
+        match unit {
+            "day" => Some(Period::Day),
+            "hour" => Some(Period::Hour),
+            "minute" => Some(Period::Minute),
+            "month" => Some(Period::Month),
+            "year" => Some(Period::Year),
+            _ => None,
+        }
+    }
+
+    /// Returns the start of the period containing the local `date`.
+    fn start(self, date: NaiveDateTime) -> Option<NaiveDateTime> {
+        let day = date.date();
+        match self {
+            Period::Day => Some(day.and_time(NaiveTime::MIN)),
+            Period::Hour => day.and_hms_opt(date.hour(), 0, 0),
+            Period::Minute => day.and_hms_opt(date.hour(), date.minute(), 0),
+            Period::Month => day.with_day(1).map(|d| d.and_time(NaiveTime::MIN)),
+            Period::Year => day.with_ordinal(1).map(|d| d.and_time(NaiveTime::MIN)),
+        }
+    }
+
+    /// Returns the last millisecond of the period containing the local `date`.
+    fn end(self, date: NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = self.start(date)?;
+        let next = match self {
+            Period::Day => start.checked_add_signed(Duration::days(1)),
+            Period::Hour => start.checked_add_signed(Duration::hours(1)),
+            Period::Minute => start.checked_add_signed(Duration::minutes(1)),
+            Period::Month => start.checked_add_months(Months::new(1)),
+            Period::Year => start.checked_add_months(Months::new(12)),
+        }?;
+        next.checked_sub_signed(Duration::milliseconds(1))
+    }
+}
+
+/// Truncates a date `value` to the start of the period `unit` (`day`, `hour`, `minute`, `month`
+/// or `year`), in the timezone of the date.
+pub fn eval_start_of(
+    value: &Value,
+    unit: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_period(value, unit, variables, source_info, assert, Period::start)
+}
+
+/// Moves a date `value` to the last millisecond of the period `unit` (`day`, `hour`, `minute`,
+/// `month` or `year`), in the timezone of the date.
+pub fn eval_end_of(
+    value: &Value,
+    unit: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_period(value, unit, variables, source_info, assert, Period::end)
+}
+
+/// Applies the function `bound` on the local time of a date `value`, given a period `unit`.
+fn eval_period(
+    value: &Value,
+    unit: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+    bound: fn(Period, NaiveDateTime) -> Option<NaiveDateTime>,
+) -> Result<Option<Value>, RunnerError> {
+    let unit = eval_template(unit, variables)?;
+    let Some(period) = Period::parse(&unit) else {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!("unit <{unit}> is unknown"));
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+
+    match value {
+        Value::Date(date) => {
+            let timezone = date.timezone();
+            let bounded = bound(period, date.naive_local())
+                .and_then(|local| timezone.from_local_datetime(&local).earliest());
+            match bounded {
+                Some(bounded) => Ok(Some(Value::Date(bounded))),
+                None => {
+                    let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                        "date <{date}> is out of range"
+                    ));
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+    use chrono_tz::Europe::Paris;
+    use chrono_tz::UTC;
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    fn new_template(value: &str) -> Template {
+        Template::new(
+            None,
+            vec![TemplateElement::String {
+                value: value.to_string(),
+                source: value.to_source(),
+            }],
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        )
+    }
+
+    fn new_space() -> Whitespace {
+        Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        }
+    }
+
+    fn new_start_of_filter(unit: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::StartOf {
+                space0: new_space(),
+                unit: new_template(unit),
+            },
+        }
+    }
+
+    fn new_end_of_filter(unit: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::EndOf {
+                space0: new_space(),
+                unit: new_template(unit),
+            },
+        }
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> Value {
+        Value::Date(
+            UTC.with_ymd_and_hms(year, month, day, hour, min, sec)
+                .unwrap(),
+        )
+    }
+
+    fn utc_end(year: i32, month: u32, day: u32, hour: u32, min: u32) -> Value {
+        Value::Date(
+            UTC.with_ymd_and_hms(year, month, day, hour, min, 59)
+                .unwrap()
+                + Duration::milliseconds(999),
+        )
+    }
+
+    #[test]
+    fn eval_filter_start_of() {
+        let variables = VariableSet::new();
+        let date = utc(2025, 4, 17, 13, 45, 30);
+
+        let tests = [
+            ("minute", utc(2025, 4, 17, 13, 45, 0)),
+            ("hour", utc(2025, 4, 17, 13, 0, 0)),
+            ("day", utc(2025, 4, 17, 0, 0, 0)),
+            ("month", utc(2025, 4, 1, 0, 0, 0)),
+            ("year", utc(2025, 1, 1, 0, 0, 0)),
+        ];
+        for (unit, expected) in tests {
+            let filter = new_start_of_filter(unit);
+            let ret = eval_filter(&filter, &date, &variables, false);
+            assert_eq!(ret.unwrap().unwrap(), expected, "startOf {unit}");
+        }
+    }
+
+    #[test]
+    fn eval_filter_end_of() {
+        let variables = VariableSet::new();
+        let date = utc(2025, 4, 17, 13, 45, 30);
+
+        let tests = [
+            ("minute", utc_end(2025, 4, 17, 13, 45)),
+            ("hour", utc_end(2025, 4, 17, 13, 59)),
+            ("day", utc_end(2025, 4, 17, 23, 59)),
+            ("month", utc_end(2025, 4, 30, 23, 59)),
+            ("year", utc_end(2025, 12, 31, 23, 59)),
+        ];
+        for (unit, expected) in tests {
+            let filter = new_end_of_filter(unit);
+            let ret = eval_filter(&filter, &date, &variables, false);
+            assert_eq!(ret.unwrap().unwrap(), expected, "endOf {unit}");
+        }
+    }
+
+    #[test]
+    fn eval_filter_end_of_month() {
+        let variables = VariableSet::new();
+        let filter = new_end_of_filter("month");
+
+        // April has 30 days, May has 31 days.
+        let tests = [
+            (utc(2025, 4, 1, 0, 0, 0), utc_end(2025, 4, 30, 23, 59)),
+            (utc(2025, 5, 15, 8, 0, 0), utc_end(2025, 5, 31, 23, 59)),
+            (utc(2024, 2, 10, 8, 0, 0), utc_end(2024, 2, 29, 23, 59)),
+            (utc(2025, 12, 31, 23, 59, 59), utc_end(2025, 12, 31, 23, 59)),
+        ];
+        for (date, expected) in tests {
+            let ret = eval_filter(&filter, &date, &variables, false);
+            assert_eq!(ret.unwrap().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn eval_filter_start_of_day_in_timezone() {
+        let variables = VariableSet::new();
+        let filter = new_start_of_filter("day");
+
+        // 2025-01-01 00:30 in Paris is still 2024-12-31 in UTC.
+        let date = Value::Date(Paris.with_ymd_and_hms(2025, 1, 1, 0, 30, 0).unwrap());
+        let ret = eval_filter(&filter, &date, &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Date(Paris.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn eval_filter_start_of_ko() {
+        let variables = VariableSet::new();
+
+        let filter = new_start_of_filter("week");
+        let ret = eval_filter(&filter, &utc(2025, 4, 17, 13, 45, 30), &variables, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("unit <week> is unknown".to_string())
+        );
+
+        let filter = new_end_of_filter("day");
+        let ret = eval_filter(
+            &filter,
+            &Value::String("2025-04-17".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
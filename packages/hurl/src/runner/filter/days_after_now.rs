@@ -42,6 +42,7 @@ pub fn eval_days_after_now(
 mod tests {
     use chrono::offset::Utc;
     use chrono::Duration;
+    use chrono_tz::UTC;
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
@@ -53,7 +54,7 @@ mod tests {
     fn eval_filter_days_after_before_now() {
         let variables = VariableSet::new();
 
-        let now = Utc::now();
+        let now = Utc::now().with_timezone(&UTC);
         assert_eq!(
             eval_filter(
                 &Filter {
@@ -0,0 +1,117 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::context::FilterContext;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, Value, VariableSet};
+
+/// Logs the representation of `value`, with an optional `label`, to the logger of the filter
+/// `context` and returns `value` unchanged.
+pub fn eval_debug(
+    value: &Value,
+    label: Option<&Template>,
+    variables: &VariableSet,
+    context: &FilterContext,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, RunnerError> {
+    let line = source_info.start.line;
+    let message = match label {
+        Some(label) => {
+            let label = eval_template(label, variables)?;
+            format!("debug <{label}> (line {line}): {}", value.repr())
+        }
+        None => format!("debug (line {line}): {}", value.repr()),
+    };
+    context.debug(&message);
+    Ok(Some(value.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::{Number, Value, VariableSet};
+    use crate::util::logger::{Logger, LoggerOptionsBuilder, Verbosity};
+    use crate::util::term::{Stderr, WriteMode};
+
+    /// Helper function to return a new filter `debug` with an optional `label`.
+    fn new_debug_filter(label: Option<&str>) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(3, 8), Pos::new(3, 13));
+        Filter {
+            source_info,
+            value: FilterValue::Debug {
+                label: label.map(|label| {
+                    let space = Whitespace {
+                        value: " ".to_string(),
+                        source_info,
+                    };
+                    let template = Template::new(
+                        Some('"'),
+                        vec![TemplateElement::String {
+                            value: label.to_string(),
+                            source: label.to_source(),
+                        }],
+                        source_info,
+                    );
+                    (space, template)
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_debug() {
+        let variables = VariableSet::new();
+        let value = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::String("a".to_string()),
+        ]);
+
+        let options = LoggerOptionsBuilder::new()
+            .verbosity(Some(Verbosity::Verbose))
+            .build();
+        let mut logger = Logger::new(&options, Stderr::new(WriteMode::Buffered), &[]);
+        let context = FilterContext::default();
+
+        {
+            let context = context.with_logger(&mut logger);
+            let filter = new_debug_filter(None);
+            let ret = eval_filter_with_context(&filter, &value, &variables, &context, false);
+            assert_eq!(ret.unwrap().unwrap(), value);
+            let filter = new_debug_filter(Some("items"));
+            let ret = eval_filter_with_context(&filter, &value, &variables, &context, false);
+            assert_eq!(ret.unwrap().unwrap(), value);
+        }
+
+        assert_eq!(
+            logger.stderr.buffer(),
+            "* debug (line 3): list <[1,a]>\n\
+             * debug <items> (line 3): list <[1,a]>\n"
+        );
+
+        // Without a logger, the messages are discarded.
+        let filter = new_debug_filter(None);
+        let ret = eval_filter_with_context(&filter, &value, &variables, &context, false);
+        assert_eq!(ret.unwrap().unwrap(), value);
+    }
+}
@@ -0,0 +1,204 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{Placeholder, SourceInfo};
+
+use crate::runner::{expr, RunnerError, Value, VariableSet};
+
+/// Returns true if `value` is deeply equal to the value of the placeholder `expected`.
+///
+/// See [`deep_equal`] for the comparison rules.
+pub fn eval_deep_equal(
+    value: &Value,
+    expected: &Placeholder,
+    variables: &VariableSet,
+    _source_info: SourceInfo,
+    _assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let expected = expr::eval(&expected.expr, variables)?;
+    Ok(Some(Value::Bool(deep_equal(value, &expected))))
+}
+
+/// Compares two values recursively.
+///
+/// The order of object keys is not significant while the order of list items is. Numbers are
+/// compared by their numeric value, so `1` is equal to `1.0`.
+pub fn deep_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => {
+            left.len() == right.len()
+                && left.iter().all(|(key, left)| {
+                    right
+                        .iter()
+                        .find(|(k, _)| k == key)
+                        .is_some_and(|(_, right)| deep_equal(left, right))
+                })
+        }
+        (Value::List(left), Value::List(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right.iter())
+                    .all(|(left, right)| deep_equal(left, right))
+        }
+        _ => left == right,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Placeholder, SourceInfo, Variable, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, Value, VariableSet};
+
+    /// Helper function to return a new filter `deepEqual {{expected}}`.
+    fn new_deep_equal_filter() -> Filter {
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::DeepEqual {
+                space0: whitespace.clone(),
+                expected: Placeholder {
+                    space0: whitespace.clone(),
+                    expr: Expr {
+                        kind: ExprKind::Variable(Variable {
+                            name: "expected".to_string(),
+                            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                        }),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    space1: whitespace,
+                },
+            },
+        }
+    }
+
+    fn deep_equal(value: &Value, expected: &Value) -> bool {
+        let mut variables = VariableSet::new();
+        variables.insert("expected".to_string(), expected.clone());
+        let filter = new_deep_equal_filter();
+        match eval_filter(&filter, value, &variables, false) {
+            Ok(Some(Value::Bool(ret))) => ret,
+            ret => panic!("unexpected result {ret:?}"),
+        }
+    }
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn eval_filter_deep_equal_reordered_keys() {
+        let left = object(&[
+            ("id", Value::Number(Number::Integer(1))),
+            ("name", Value::String("Bob".to_string())),
+        ]);
+        let right = object(&[
+            ("name", Value::String("Bob".to_string())),
+            ("id", Value::Number(Number::Float(1.0))),
+        ]);
+        assert!(deep_equal(&left, &right));
+    }
+
+    #[test]
+    fn eval_filter_deep_equal_different_values() {
+        let left = object(&[("id", Value::Number(Number::Integer(1)))]);
+        let right = object(&[("id", Value::Number(Number::Integer(2)))]);
+        assert!(!deep_equal(&left, &right));
+
+        let right = object(&[
+            ("id", Value::Number(Number::Integer(1))),
+            ("name", Value::Null),
+        ]);
+        assert!(!deep_equal(&left, &right));
+
+        let left = Value::List(vec![Value::Bool(true), Value::Bool(false)]);
+        let right = Value::List(vec![Value::Bool(false), Value::Bool(true)]);
+        assert!(!deep_equal(&left, &right));
+
+        let left = Value::String("1".to_string());
+        let right = Value::Number(Number::Integer(1));
+        assert!(!deep_equal(&left, &right));
+    }
+
+    #[test]
+    fn eval_filter_deep_equal_nested() {
+        let left = object(&[
+            (
+                "user",
+                object(&[
+                    ("name", Value::String("Bob".to_string())),
+                    (
+                        "roles",
+                        Value::List(vec![
+                            object(&[("id", Value::Number(Number::Integer(1)))]),
+                            object(&[("id", Value::Number(Number::Integer(2)))]),
+                        ]),
+                    ),
+                ]),
+            ),
+            ("active", Value::Bool(true)),
+        ]);
+        let right = object(&[
+            ("active", Value::Bool(true)),
+            (
+                "user",
+                object(&[
+                    (
+                        "roles",
+                        Value::List(vec![
+                            object(&[("id", Value::Number(Number::Float(1.0)))]),
+                            object(&[("id", Value::Number(Number::Integer(2)))]),
+                        ]),
+                    ),
+                    ("name", Value::String("Bob".to_string())),
+                ]),
+            ),
+        ]);
+        assert!(deep_equal(&left, &right));
+
+        let right = object(&[
+            ("active", Value::Bool(true)),
+            (
+                "user",
+                object(&[
+                    (
+                        "roles",
+                        Value::List(vec![
+                            object(&[("id", Value::Number(Number::Integer(2)))]),
+                            object(&[("id", Value::Number(Number::Integer(1)))]),
+                        ]),
+                    ),
+                    ("name", Value::String("Bob".to_string())),
+                ]),
+            ),
+        ]);
+        assert!(!deep_equal(&left, &right));
+    }
+}
@@ -0,0 +1,228 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+pub const MS_PER_SECOND: u64 = 1000;
+pub const MS_PER_MINUTE: u64 = 60 * MS_PER_SECOND;
+pub const MS_PER_HOUR: u64 = 60 * MS_PER_MINUTE;
+pub const MS_PER_DAY: u64 = 24 * MS_PER_HOUR;
+
+/// Formats a number of milliseconds `value` to a human-readable duration (ex: `1h 2m 3s`), or to
+/// an `iso` 8601 duration (ex: `PT1H2M3S`).
+pub fn eval_duration_format(
+    value: &Value,
+    iso: bool,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let ms = match value {
+        Value::Number(Number::Integer(v)) if *v >= 0 => *v as u64,
+        Value::Number(Number::Float(v)) if *v >= 0.0 && v.is_finite() => *v as u64,
+        Value::Number(_) => {
+            let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let duration = Duration::from_ms(ms);
+    let formatted = if iso {
+        duration.to_iso()
+    } else {
+        duration.to_human()
+    };
+    Ok(Some(Value::String(formatted)))
+}
+
+/// A duration split in days, hours, minutes, seconds and milliseconds.
+struct Duration {
+    days: u64,
+    hours: u64,
+    minutes: u64,
+    seconds: u64,
+    millis: u64,
+}
+
+impl Duration {
+    fn from_ms(ms: u64) -> Self {
+        Duration {
+            days: ms / MS_PER_DAY,
+            hours: ms % MS_PER_DAY / MS_PER_HOUR,
+            minutes: ms % MS_PER_HOUR / MS_PER_MINUTE,
+            seconds: ms % MS_PER_MINUTE / MS_PER_SECOND,
+            millis: ms % MS_PER_SECOND,
+        }
+    }
+
+    /// Returns this duration formatted like `1d 2h 3m 4s 5ms`, zero components being omitted.
+    fn to_human(&self) -> String {
+        let components = [
+            (self.days, "d"),
+            (self.hours, "h"),
+            (self.minutes, "m"),
+            (self.seconds, "s"),
+            (self.millis, "ms"),
+        ];
+        let formatted = components
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| format!("{value}{unit}"))
+            .collect::<Vec<_>>();
+        if formatted.is_empty() {
+            "0ms".to_string()
+        } else {
+            formatted.join(" ")
+        }
+    }
+
+    /// Returns this duration formatted as an ISO 8601 duration like `P1DT2H3M4.005S`, zero
+    /// components being omitted.
+    fn to_iso(&self) -> String {
+        let mut s = "P".to_string();
+        if self.days > 0 {
+            s.push_str(&format!("{}D", self.days));
+        }
+        let mut time = String::new();
+        if self.hours > 0 {
+            time.push_str(&format!("{}H", self.hours));
+        }
+        if self.minutes > 0 {
+            time.push_str(&format!("{}M", self.minutes));
+        }
+        if self.millis > 0 {
+            let millis = format!("{:03}", self.millis);
+            time.push_str(&format!(
+                "{}.{}S",
+                self.seconds,
+                millis.trim_end_matches('0')
+            ));
+        } else if self.seconds > 0 {
+            time.push_str(&format!("{}S", self.seconds));
+        }
+        if !time.is_empty() {
+            s.push('T');
+            s.push_str(&time);
+        } else if self.days == 0 {
+            s.push_str("T0S");
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_duration_format_filter(iso: bool) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 15)),
+            value: FilterValue::DurationFormat {
+                iso: iso.then(|| Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 16)),
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_duration_format() {
+        let variables = VariableSet::new();
+        let filter = new_duration_format_filter(false);
+
+        let tests = [
+            (0, "0ms"),
+            (250, "250ms"),
+            (1_500, "1s 500ms"),
+            (90_000, "1m 30s"),
+            (754_000, "12m 34s"),
+            (3_723_000, "1h 2m 3s"),
+            (93_600_000, "1d 2h"),
+        ];
+        for (ms, expected) in tests {
+            let ret = eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(ms)),
+                &variables,
+                false,
+            );
+            assert_eq!(ret.unwrap().unwrap(), Value::String(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn eval_filter_duration_format_iso() {
+        let variables = VariableSet::new();
+        let filter = new_duration_format_filter(true);
+
+        let tests = [
+            (0, "PT0S"),
+            (250, "PT0.25S"),
+            (1_005, "PT1.005S"),
+            (90_000, "PT1M30S"),
+            (3_723_000, "PT1H2M3S"),
+            (86_400_000, "P1D"),
+            (93_600_000, "P1DT2H"),
+        ];
+        for (ms, expected) in tests {
+            let ret = eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(ms)),
+                &variables,
+                false,
+            );
+            assert_eq!(ret.unwrap().unwrap(), Value::String(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn eval_filter_duration_format_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = new_duration_format_filter(false);
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(-1)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <-1>".to_string())
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("1000".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
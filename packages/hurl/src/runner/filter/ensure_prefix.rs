@@ -0,0 +1,110 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Prepends `prefix` to the string `value`, unless `value` already starts with it.
+pub fn eval_ensure_prefix(
+    value: &Value,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+    prefix: &Template,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(s) => {
+            let prefix = eval_template(prefix, variables)?;
+            if s.starts_with(&prefix) {
+                Ok(Some(Value::String(s.clone())))
+            } else {
+                Ok(Some(Value::String(format!("{prefix}{s}"))))
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_ensure_prefix() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::EnsurePrefix {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                prefix: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: "https://".to_string(),
+                        source: "https://".to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                ),
+            },
+        };
+
+        let tests = [
+            // Already present, unchanged.
+            ("https://example.org", "https://example.org"),
+            // Missing, added.
+            ("example.org", "https://example.org"),
+            // Empty input.
+            ("", "https://"),
+        ];
+        for (input, output) in tests {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <1>".to_string())
+        );
+    }
+}
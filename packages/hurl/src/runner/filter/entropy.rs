@@ -0,0 +1,104 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns the Shannon entropy of the string `value`, in bits per character.
+pub fn eval_entropy(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(s) => Ok(Some(Value::Number(Number::Float(entropy(s))))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Computes the Shannon entropy of `s` from the frequencies of its chars.
+fn entropy(s: &str) -> f64 {
+    let mut counts = HashMap::new();
+    let mut len = 0;
+    for c in s.chars() {
+        *counts.entry(c).or_insert(0_usize) += 1;
+        len += 1;
+    }
+    let len = len as f64;
+    let entropy = counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum::<f64>();
+    // Avoid returning -0.0 for a single repeated char.
+    entropy.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn entropy(s: &str) -> f64 {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Entropy,
+        };
+        match eval_filter(&filter, &Value::String(s.to_string()), &variables, false) {
+            Ok(Some(Value::Number(Number::Float(f)))) => f,
+            ret => panic!("unexpected result {ret:?}"),
+        }
+    }
+
+    #[test]
+    fn eval_filter_entropy() {
+        assert_eq!(entropy(""), 0.0);
+        assert_eq!(entropy("aaaaaaaa"), 0.0);
+        assert_eq!(entropy("abab"), 1.0);
+        assert_eq!(entropy("éèêë"), 2.0);
+        assert!(entropy("aaaaaaab") < 1.0);
+        assert!(entropy("7Fq2xZ9pLm3KvT8wRb1NcY4hJd6GsE0u") > 4.5);
+    }
+
+    #[test]
+    fn eval_filter_entropy_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Entropy,
+        };
+        let err = eval_filter(&filter, &Value::Bytes(vec![1]), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("bytes".to_string())
+        );
+    }
+}
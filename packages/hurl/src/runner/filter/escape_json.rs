@@ -0,0 +1,92 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Escapes `value` following JSON string rules: quotes, backslashes and control characters are
+/// escaped. Surrounding quotes are not added.
+pub fn eval_escape_json(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            // Serializing a string can't fail, the serialized string is always surrounded by quotes.
+            let escaped = serde_json::to_string(value).unwrap();
+            let escaped = &escaped[1..escaped.len() - 1];
+            Ok(Some(Value::String(escaped.to_string())))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_escape_json() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::EscapeJson,
+        };
+
+        let tests = [
+            ("foo", "foo"),
+            ("say \"hello\"", "say \\\"hello\\\""),
+            ("C:\\temp", "C:\\\\temp"),
+            ("line1\nline2\ttab\r", "line1\\nline2\\ttab\\r"),
+            ("bell\u{7}", "bell\\u0007"),
+            ("café ☕/", "café ☕/"),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer".to_string())
+        );
+    }
+}
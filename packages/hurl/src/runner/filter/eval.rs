@@ -15,8 +15,6 @@
  * limitations under the License.
  *
  */
-use std::path::PathBuf;
-
 use hurl_core::ast::{Filter, FilterValue};
 
 use crate::runner::filter::add::eval_add;
@@ -24,34 +22,90 @@ use crate::runner::filter::base64_decode::eval_base64_decode;
 use crate::runner::filter::base64_encode::eval_base64_encode;
 use crate::runner::filter::base64_url_safe_decode::eval_base64_url_safe_decode;
 use crate::runner::filter::base64_url_safe_encode::eval_base64_url_safe_encode;
+use crate::runner::filter::between::eval_between;
+use crate::runner::filter::canonicalize::eval_canonicalize;
+use crate::runner::filter::case::{eval_camel_case, eval_kebab_case, eval_snake_case};
+use crate::runner::filter::clamp_length::eval_clamp_length;
+use crate::runner::filter::coalesce::eval_coalesce;
+use crate::runner::filter::context::FilterContext;
 use crate::runner::filter::count::eval_count;
+use crate::runner::filter::date_period::{eval_end_of, eval_start_of};
 use crate::runner::filter::days_after_now::eval_days_after_now;
 use crate::runner::filter::days_before_now::eval_days_before_now;
+use crate::runner::filter::debug::eval_debug;
 use crate::runner::filter::decode::eval_decode;
+use crate::runner::filter::deep_equal::eval_deep_equal;
+use crate::runner::filter::drop_while::eval_drop_while;
+use crate::runner::filter::duration_format::eval_duration_format;
+use crate::runner::filter::ensure_prefix::eval_ensure_prefix;
+use crate::runner::filter::ensure_suffix::eval_ensure_suffix;
+use crate::runner::filter::entropy::eval_entropy;
+use crate::runner::filter::escape_json::eval_escape_json;
+use crate::runner::filter::expect::eval_expect;
+use crate::runner::filter::filter_list::eval_filter_list;
 use crate::runner::filter::first::eval_first;
+use crate::runner::filter::form::{eval_form_decode, eval_form_encode};
 use crate::runner::filter::format::eval_date_format;
+use crate::runner::filter::format_bytes::eval_format_bytes;
+use crate::runner::filter::group_by::eval_group_by;
+use crate::runner::filter::hex_dump::eval_hex_dump;
 use crate::runner::filter::html_escape::eval_html_escape;
 use crate::runner::filter::html_unescape::eval_html_unescape;
+use crate::runner::filter::is_leap_year::eval_is_leap_year;
+use crate::runner::filter::jseval::eval_jseval;
+use crate::runner::filter::json_patch::eval_json_patch;
 use crate::runner::filter::jsonpath::eval_jsonpath;
 use crate::runner::filter::jsfilter::eval_jsfilter;
+use crate::runner::filter::jsonpath_keys::eval_jsonpath_keys;
+use crate::runner::filter::jwt_decode::eval_jwt_decode;
 use crate::runner::filter::last::eval_last;
 use crate::runner::filter::location::eval_location;
+use crate::runner::filter::map::eval_map;
+use crate::runner::filter::mask::eval_mask;
+use crate::runner::filter::merge::{eval_merge, eval_merge_deep};
+use crate::runner::filter::non_empty::eval_non_empty;
 use crate::runner::filter::nth::eval_nth;
+use crate::runner::filter::nth_from_end::eval_nth_from_end;
+use crate::runner::filter::number_format::eval_number_format;
+use crate::runner::filter::parse_duration::eval_parse_duration;
+use crate::runner::filter::paths::{eval_leaves, eval_paths};
+use crate::runner::filter::ratio::{eval_percent, eval_ratio};
+use crate::runner::filter::reduce::eval_reduce;
 use crate::runner::filter::regex::eval_regex;
 use crate::runner::filter::replace::eval_replace;
 use crate::runner::filter::replace_regex::eval_replace_regex;
+use crate::runner::filter::round_to::eval_round_to;
+use crate::runner::filter::sample::eval_sample;
+use crate::runner::filter::shuffle::eval_shuffle;
+use crate::runner::filter::sign::eval_sign;
+use crate::runner::filter::sort_by::eval_sort_by;
 use crate::runner::filter::split::eval_split;
+use crate::runner::filter::split_regex::eval_split_regex;
+use crate::runner::filter::sqrt::eval_sqrt;
+use crate::runner::filter::take_while::eval_take_while;
+use crate::runner::filter::to_big_int::eval_to_big_int;
 use crate::runner::filter::to_date::eval_to_date;
 use crate::runner::filter::to_float::eval_to_float;
 use crate::runner::filter::to_hex::eval_to_hex;
 use crate::runner::filter::to_int::eval_to_int;
 use crate::runner::filter::to_string::eval_to_string;
+use crate::runner::filter::to_timezone::eval_to_timezone;
+use crate::runner::filter::to_utc::eval_to_utc;
+use crate::runner::filter::truncate::eval_truncate;
+use crate::runner::filter::type_of::eval_type_of;
+use crate::runner::filter::unescape_json::eval_unescape_json;
 use crate::runner::filter::url_decode::eval_url_decode;
 use crate::runner::filter::url_encode::eval_url_encode;
 use crate::runner::filter::url_query_param::eval_url_query_param;
 use crate::runner::filter::utf8_decode::eval_utf8_decode;
 use crate::runner::filter::utf8_encode::eval_utf8_encode;
+use crate::runner::filter::weekday::eval_weekday;
+use crate::runner::filter::wrap::eval_wrap;
 use crate::runner::filter::xpath::eval_xpath;
+use crate::runner::filter::xpath_all::eval_xpath_all;
+use crate::runner::filter::xpath_boolean::eval_xpath_boolean;
+use crate::runner::filter::xpath_number::eval_xpath_number;
+use crate::runner::filter::yaml::{eval_from_yaml, eval_to_yaml};
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Apply successive `filter` to an input `value`.
@@ -63,22 +117,24 @@ pub fn eval_filters(
     variables: &VariableSet,
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
-    eval_filters_with_js(filters, value, variables, &None, in_assert)
+    let context = FilterContext::default();
+    eval_filters_with_context(filters, value, variables, &context, in_assert)
 }
 
-/// Apply successive `filter` to an input `value` with optional JavaScript filter path.
+/// Apply successive `filter` to an input `value` within a filter `context`.
 /// Specify whether they are executed  `in_assert` or not.
-pub fn eval_filters_with_js(
+pub fn eval_filters_with_context(
     filters: &[&Filter],
     value: &Value,
     variables: &VariableSet,
-    jsfilter_path: &Option<PathBuf>,
+    context: &FilterContext,
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     let mut value = Some(value.clone());
     for filter in filters {
         value = if let Some(value) = value {
-            eval_filter_with_js(filter, &value, variables, jsfilter_path, in_assert)?
+            eval_filter_with_context(filter, &value, variables, context, in_assert)
+                .map_err(|e| with_filter_name(e, filter))?
         } else {
             return Err(RunnerError::new(
                 filter.source_info,
@@ -90,6 +146,21 @@ pub fn eval_filters_with_js(
     Ok(value)
 }
 
+/// Adds the name of the `filter` to an invalid input `error`, so the filter that has rejected its
+/// input can be identified in a chain of filters.
+fn with_filter_name(error: RunnerError, filter: &Filter) -> RunnerError {
+    match error.kind {
+        RunnerErrorKind::FilterInvalidInput(reason) => {
+            let kind = RunnerErrorKind::FilterInvalidInputFor {
+                filter: filter.value.identifier().to_string(),
+                reason,
+            };
+            RunnerError::new(error.source_info, kind, error.assert)
+        }
+        _ => error,
+    }
+}
+
 /// Evaluates a `filter` with an input `value`, given a set of `variables`.
 #[allow(dead_code)]
 pub fn eval_filter(
@@ -98,53 +169,214 @@ pub fn eval_filter(
     variables: &VariableSet,
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
-    eval_filter_with_js(filter, value, variables, &None, in_assert)
+    let context = FilterContext::default();
+    eval_filter_with_context(filter, value, variables, &context, in_assert)
 }
 
-/// Evaluates a `filter` with an input `value`, given a set of `variables` and optional JavaScript filter path.
-pub fn eval_filter_with_js(
+/// Evaluates a `filter` with an input `value`, given a set of `variables` and a filter `context`.
+pub fn eval_filter_with_context(
     filter: &Filter,
     value: &Value,
     variables: &VariableSet,
-    jsfilter_path: &Option<PathBuf>,
+    context: &FilterContext,
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     let source_info = filter.source_info;
+    let jsfilter_path = context.jsfilter_path();
     match &filter.value {
-        FilterValue::Add { value: addend, .. } => {
-            eval_add(value, addend, variables, source_info, in_assert)
-        }
+        FilterValue::Add { value: addend, .. } => eval_add(
+            value,
+            addend,
+            variables,
+            context.strict_arithmetic(),
+            source_info,
+            in_assert,
+        ),
         FilterValue::Base64Decode => eval_base64_decode(value, source_info, in_assert),
         FilterValue::Base64Encode => eval_base64_encode(value, source_info, in_assert),
         FilterValue::Base64UrlSafeDecode => {
             eval_base64_url_safe_decode(value, source_info, in_assert)
         }
-        FilterValue::Base64UrlSafeEncode => {
-            eval_base64_url_safe_encode(value, source_info, in_assert)
+        FilterValue::Base64UrlSafeEncode { pad } => {
+            eval_base64_url_safe_encode(value, pad.is_some(), source_info, in_assert)
+        }
+        FilterValue::Between {
+            min,
+            max,
+            exclusive,
+            ..
+        } => eval_between(
+            value,
+            min,
+            max,
+            exclusive.is_some(),
+            variables,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::CamelCase => eval_camel_case(value, source_info, in_assert),
+        FilterValue::Canonicalize => eval_canonicalize(value, source_info, in_assert),
+        FilterValue::ClampLength { max, .. } => {
+            eval_clamp_length(value, max, variables, source_info, in_assert)
+        }
+        FilterValue::Coalesce { args } => {
+            eval_coalesce(value, args, variables, source_info, in_assert)
         }
         FilterValue::Count => eval_count(value, source_info, in_assert),
         FilterValue::DaysAfterNow => eval_days_after_now(value, source_info, in_assert),
         FilterValue::DaysBeforeNow => eval_days_before_now(value, source_info, in_assert),
+        FilterValue::Debug { label } => {
+            let label = label.as_ref().map(|(_, label)| label);
+            eval_debug(value, label, variables, context, source_info)
+        }
         FilterValue::Decode { encoding, .. } => {
             eval_decode(value, encoding, variables, source_info, in_assert)
         }
-        FilterValue::First => eval_first(value, source_info, in_assert),
+        FilterValue::DeepEqual { expected, .. } => {
+            eval_deep_equal(value, expected, variables, source_info, in_assert)
+        }
+        FilterValue::DropWhile { name, .. } => eval_drop_while(
+            value,
+            name,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::DurationFormat { iso } => {
+            eval_duration_format(value, iso.is_some(), source_info, in_assert)
+        }
+        FilterValue::EndOf { unit, .. } => {
+            eval_end_of(value, unit, variables, source_info, in_assert)
+        }
+        FilterValue::EnsurePrefix { prefix, .. } => {
+            eval_ensure_prefix(value, variables, source_info, in_assert, prefix)
+        }
+        FilterValue::EnsureSuffix { suffix, .. } => {
+            eval_ensure_suffix(value, variables, source_info, in_assert, suffix)
+        }
+        FilterValue::Entropy => eval_entropy(value, source_info, in_assert),
+        FilterValue::EscapeJson => eval_escape_json(value, source_info, in_assert),
+        FilterValue::Expect { name, message, .. } => eval_expect(
+            value,
+            name,
+            message,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::Filter { name, .. } => eval_filter_list(
+            value,
+            name,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::First { n } => {
+            let n = n.as_ref().map(|(_, n)| n);
+            eval_first(value, n, variables, source_info, in_assert)
+        }
         FilterValue::Format { fmt, .. } => {
             eval_date_format(value, fmt, variables, source_info, in_assert)
         }
         FilterValue::DateFormat { fmt, .. } => {
             eval_date_format(value, fmt, variables, source_info, in_assert)
         }
+        FilterValue::FormatBytes { decimal } => {
+            eval_format_bytes(value, decimal.is_some(), source_info, in_assert)
+        }
+        FilterValue::FormDecode => eval_form_decode(value, source_info, in_assert),
+        FilterValue::FormEncode => eval_form_encode(value, source_info, in_assert),
+        FilterValue::FromYaml => eval_from_yaml(value, source_info, in_assert),
+        FilterValue::GroupBy { name, .. } => eval_group_by(
+            value,
+            name,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::HexDump => eval_hex_dump(value, source_info, in_assert),
         FilterValue::HtmlEscape => eval_html_escape(value, source_info, in_assert),
         FilterValue::HtmlUnescape => eval_html_unescape(value, source_info, in_assert),
-        FilterValue::JsonPath { expr, .. } => {
-            eval_jsonpath(value, expr, variables, source_info, in_assert)
+        FilterValue::IsLeapYear => eval_is_leap_year(value, source_info, in_assert),
+        FilterValue::JsEval { expr, .. } => eval_jseval(
+            value,
+            expr,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::JsonPatch { ops, .. } => {
+            eval_json_patch(value, ops, variables, source_info, in_assert)
+        }
+        FilterValue::JsonPath { expr, default, .. } => {
+            let default = default.as_ref().map(|(_, _, default)| default);
+            eval_jsonpath(value, expr, default, variables, source_info, in_assert)
         }
         FilterValue::JsFilter { name, args, .. } => {
             eval_jsfilter(value, name, args, variables, jsfilter_path, source_info, in_assert)
         }
-        FilterValue::Last => eval_last(value, source_info, in_assert),
+        FilterValue::JsonPathKeys { expr, .. } => {
+            eval_jsonpath_keys(value, expr, variables, source_info, in_assert)
+        }
+        FilterValue::JwtDecode { header } => {
+            eval_jwt_decode(value, header.is_some(), source_info, in_assert)
+        }
+        FilterValue::KebabCase => eval_kebab_case(value, source_info, in_assert),
+        FilterValue::Last { n } => {
+            let n = n.as_ref().map(|(_, n)| n);
+            eval_last(value, n, variables, source_info, in_assert)
+        }
+        FilterValue::Leaves => eval_leaves(value, source_info, in_assert),
         FilterValue::Location => eval_location(value, source_info, in_assert),
+        FilterValue::Map { name, .. } => eval_map(
+            value,
+            name,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::Mask {
+            start, end, mask, ..
+        } => {
+            let mask = mask.as_ref().map(|(_, mask)| mask);
+            eval_mask(value, start, end, mask, variables, source_info, in_assert)
+        }
+        FilterValue::Merge { other, .. } => {
+            eval_merge(value, other, variables, source_info, in_assert)
+        }
+        FilterValue::MergeDeep { other, .. } => {
+            eval_merge_deep(value, other, variables, source_info, in_assert)
+        }
+        FilterValue::NonEmpty => eval_non_empty(value, source_info, in_assert),
+        FilterValue::NthFromEnd { n, .. } => {
+            eval_nth_from_end(value, n, variables, source_info, in_assert)
+        }
+        FilterValue::NumberFormat { fmt, .. } => {
+            eval_number_format(value, fmt, variables, source_info, in_assert)
+        }
+        FilterValue::ParseDuration => eval_parse_duration(value, source_info, in_assert),
+        FilterValue::Paths => eval_paths(value, source_info, in_assert),
+        FilterValue::Percent { total, .. } => {
+            eval_percent(value, total, variables, source_info, in_assert)
+        }
+        FilterValue::Ratio { total, .. } => {
+            eval_ratio(value, total, variables, source_info, in_assert)
+        }
+        FilterValue::Reduce { name, init, .. } => eval_reduce(
+            value,
+            name,
+            init,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
         FilterValue::Regex {
             value: regex_value, ..
         } => eval_regex(value, regex_value, variables, source_info, in_assert),
@@ -161,17 +393,89 @@ pub fn eval_filter_with_js(
             old_value,
             new_value,
         ),
+        FilterValue::ReplaceAll {
+            old_value,
+            new_value,
+            ..
+        } => eval_replace(
+            value,
+            variables,
+            source_info,
+            in_assert,
+            old_value,
+            new_value,
+        ),
         FilterValue::ReplaceRegex {
             pattern, new_value, ..
         } => eval_replace_regex(value, variables, source_info, in_assert, pattern, new_value),
+        FilterValue::RoundTo { step, .. } => {
+            eval_round_to(value, step, variables, source_info, in_assert)
+        }
+        FilterValue::Sample { n } => {
+            let n = n.as_ref().map(|(_, n)| n);
+            eval_sample(
+                value,
+                n,
+                variables,
+                &mut context.rng(),
+                source_info,
+                in_assert,
+            )
+        }
+        FilterValue::Shuffle => eval_shuffle(value, &mut context.rng(), source_info, in_assert),
+        FilterValue::Sign => eval_sign(value, source_info, in_assert),
+        FilterValue::SnakeCase => eval_snake_case(value, source_info, in_assert),
+        FilterValue::SortBy { name, .. } => eval_sort_by(
+            value,
+            name,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
         FilterValue::Split { sep, .. } => eval_split(value, variables, source_info, in_assert, sep),
+        FilterValue::SplitRegex { pattern, trim, .. } => eval_split_regex(
+            value,
+            variables,
+            source_info,
+            in_assert,
+            pattern,
+            trim.is_some(),
+        ),
+        FilterValue::Sqrt => eval_sqrt(value, source_info, in_assert),
+        FilterValue::StartOf { unit, .. } => {
+            eval_start_of(value, unit, variables, source_info, in_assert)
+        }
+        FilterValue::TakeWhile { name, .. } => eval_take_while(
+            value,
+            name,
+            variables,
+            jsfilter_path,
+            source_info,
+            in_assert,
+        ),
+        FilterValue::ToBigInt => eval_to_big_int(value, source_info, in_assert),
         FilterValue::ToDate { fmt, .. } => {
             eval_to_date(value, fmt, variables, source_info, in_assert)
         }
         FilterValue::ToFloat => eval_to_float(value, source_info, in_assert),
         FilterValue::ToHex => eval_to_hex(value, source_info, in_assert),
         FilterValue::ToInt => eval_to_int(value, source_info, in_assert),
-        FilterValue::ToString => eval_to_string(value, source_info, in_assert),
+        FilterValue::ToString { fmt } => {
+            let fmt = fmt.as_ref().map(|(_, fmt)| fmt);
+            eval_to_string(value, fmt, variables, source_info, in_assert)
+        }
+        FilterValue::ToTimezone { tz, .. } => {
+            eval_to_timezone(value, tz, variables, source_info, in_assert)
+        }
+        FilterValue::ToUtc => eval_to_utc(value, source_info, in_assert),
+        FilterValue::ToYaml => eval_to_yaml(value, source_info, in_assert),
+        FilterValue::Truncate { len, suffix, .. } => {
+            let suffix = suffix.as_ref().map(|(_, suffix)| suffix);
+            eval_truncate(value, len, suffix, variables, source_info, in_assert)
+        }
+        FilterValue::TypeOf => eval_type_of(value),
+        FilterValue::UnescapeJson => eval_unescape_json(value, source_info, in_assert),
         FilterValue::UrlDecode => eval_url_decode(value, source_info, in_assert),
         FilterValue::UrlEncode => eval_url_encode(value, source_info, in_assert),
         FilterValue::UrlQueryParam { param, .. } => {
@@ -179,19 +483,38 @@ pub fn eval_filter_with_js(
         }
         FilterValue::Utf8Decode => eval_utf8_decode(value, source_info, in_assert),
         FilterValue::Utf8Encode => eval_utf8_encode(value, source_info, in_assert),
+        FilterValue::Weekday { name } => {
+            eval_weekday(value, name.is_some(), source_info, in_assert)
+        }
+        FilterValue::Wrap { left, right, .. } => {
+            let right = right.as_ref().map(|(_, right)| right);
+            eval_wrap(value, left, right, variables, source_info, in_assert)
+        }
         FilterValue::XPath { expr, .. } => {
             eval_xpath(value, expr, variables, source_info, in_assert)
         }
+        FilterValue::XPathAll { expr, .. } => {
+            eval_xpath_all(value, expr, variables, source_info, in_assert)
+        }
+        FilterValue::XPathBoolean { expr, .. } => {
+            eval_xpath_boolean(value, expr, variables, source_info, in_assert)
+        }
+        FilterValue::XPathNumber { expr, .. } => {
+            eval_xpath_number(value, expr, variables, source_info, in_assert)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::ast::{
+        Filter, FilterValue, NumberValue, SourceInfo, Template, TemplateElement, Whitespace, I64,
+    };
     use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
 
     use crate::runner::filter::eval::eval_filters;
-    use crate::runner::{Number, Value, VariableSet};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
     #[test]
     fn test_filters() {
@@ -216,4 +539,72 @@ mod tests {
             Value::Number(Number::Integer(3))
         );
     }
+
+    #[test]
+    fn test_filters_invalid_input_name() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info,
+        };
+        let add = Filter {
+            source_info,
+            value: FilterValue::Add {
+                space0: whitespace.clone(),
+                value: NumberValue::Literal(hurl_core::ast::Number::Integer(I64::new(
+                    1,
+                    "1".to_source(),
+                ))),
+            },
+        };
+        let to_int = Filter {
+            source_info,
+            value: FilterValue::ToInt,
+        };
+        let split = Filter {
+            source_info,
+            value: FilterValue::Split {
+                space0: whitespace,
+                sep: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: ",".to_string(),
+                        source: ",".to_source(),
+                    }],
+                    source_info,
+                ),
+            },
+        };
+
+        let error = eval_filters(&[&add], &Value::Bool(true), &variables, false).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputFor {
+                filter: "add".to_string(),
+                reason: "boolean <true>".to_string(),
+            }
+        );
+
+        // The error names the filter of the chain that has rejected its input.
+        let value = Value::String("12x".to_string());
+        let error = eval_filters(&[&split, &to_int], &value, &variables, false).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputFor {
+                filter: "toInt".to_string(),
+                reason: "list <[12x]>".to_string(),
+            }
+        );
+
+        let value = Value::Number(Number::Integer(12));
+        let error = eval_filters(&[&split], &value, &variables, false).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInputFor {
+                filter: "split".to_string(),
+                reason: "integer <12>".to_string(),
+            }
+        );
+    }
 }
@@ -0,0 +1,131 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::jsfilter::call_js_predicate;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns the input `value` if the JavaScript filter function `name` returns a truthy value for
+/// it, or fails with the user `message` otherwise.
+pub fn eval_expect(
+    value: &Value,
+    name: &Template,
+    message: &Template,
+    variables: &VariableSet,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let name = eval_template(name, variables)?;
+    let message = eval_template(message, variables)?;
+
+    if call_js_predicate(&name, value, jsfilter_path, source_info, assert)? {
+        Ok(Some(value.clone()))
+    } else {
+        let kind = RunnerErrorKind::FilterExpectFailed(message);
+        Err(RunnerError::new(source_info, kind, assert))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::filter::jsfilter::{drop_js_runtime, write_js_filters};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_template(value: &str, source_info: SourceInfo) -> Template {
+        Template::new(
+            None,
+            vec![TemplateElement::String {
+                value: value.to_string(),
+                source: value.to_source(),
+            }],
+            source_info,
+        )
+    }
+
+    /// Helper function to return a new filter `expect name "message"`.
+    fn new_expect_filter(name: &str, message: &str) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let space = Whitespace {
+            value: " ".to_string(),
+            source_info,
+        };
+        Filter {
+            source_info,
+            value: FilterValue::Expect {
+                space0: space.clone(),
+                name: new_template(name, source_info),
+                space1: space,
+                message: new_template(message, source_info),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_expect() {
+        let context = FilterContext::new(Some(write_js_filters(
+            "function filter_isPositive(input) { return input > 0; }",
+        )));
+        let variables = VariableSet::new();
+        let filter = new_expect_filter("isPositive", "count must be positive");
+
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(12)),
+            &variables,
+            &context,
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(12)));
+
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(-1)),
+            &variables,
+            &context,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterExpectFailed("count must be positive".to_string())
+        );
+
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(12)),
+            &variables,
+            &FilterContext::default(),
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::JsFilterNotConfigured
+        );
+
+        drop_js_runtime();
+    }
+}
@@ -15,16 +15,40 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::SourceInfo;
+use hurl_core::ast::{IntegerValue, SourceInfo};
 
-use crate::runner::{RunnerError, RunnerErrorKind, Value};
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Returns the first item in a collection `value`.
+///
+/// If a count `n` is given, returns instead a list of up to `n` items from the front of the
+/// collection.
 pub fn eval_first(
     value: &Value,
+    n: Option<&IntegerValue>,
+    variables: &VariableSet,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
+    if let Some(n) = n {
+        let n = eval_integer_value(n, variables)?;
+        if n < 0 {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!("count <{n}> is negative"));
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+        return match value {
+            Value::List(values) => {
+                let n = n as usize;
+                let values = values.iter().take(n).cloned().collect();
+                Ok(Some(Value::List(values)))
+            }
+            v => {
+                let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        };
+    }
     match value {
         Value::List(values) => match values.first().cloned() {
             Some(first_value) => Ok(Some(first_value)),
@@ -42,8 +66,9 @@ pub fn eval_first(
 
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::ast::{Filter, FilterValue, IntegerValue, SourceInfo, Whitespace, I64};
     use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
 
     use super::*;
     use crate::runner::filter::eval::eval_filter;
@@ -52,10 +77,56 @@ mod tests {
     fn new_first_filter() -> Filter {
         Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 5)),
-            value: FilterValue::First,
+            value: FilterValue::First { n: None },
+        }
+    }
+
+    fn new_first_n_filter(n: i64) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 5)),
+            value: FilterValue::First {
+                n: Some((
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                    },
+                    IntegerValue::Literal(I64::new(n, n.to_string().to_source())),
+                )),
+            },
         }
     }
 
+    #[test]
+    fn eval_filter_first_n() {
+        let variables = VariableSet::new();
+        let values = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(3)),
+            Value::Number(Number::Integer(5)),
+        ]);
+
+        let ret = eval_filter(&new_first_n_filter(2), &values, &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::List(vec![
+                Value::Number(Number::Integer(1)),
+                Value::Number(Number::Integer(3))
+            ])
+        );
+
+        let ret = eval_filter(&new_first_n_filter(10), &values, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), values);
+
+        let ret = eval_filter(&new_first_n_filter(0), &values, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::List(vec![]));
+
+        let ret = eval_filter(&new_first_n_filter(-1), &values, &variables, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("count <-1> is negative".to_string())
+        );
+    }
+
     #[test]
     fn eval_filter_first_ok() {
         let variables = VariableSet::new();
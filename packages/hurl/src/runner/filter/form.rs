@@ -0,0 +1,197 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use url::form_urlencoded;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Encodes the object `value` to an `application/x-www-form-urlencoded` string.
+///
+/// Values can be strings, numbers or booleans. A list value is encoded as a repeated key, one for
+/// each item of the list.
+pub fn eval_form_encode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::Object(fields) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let mut serializer = form_urlencoded::Serializer::new(String::new());
+    for (key, value) in fields {
+        let values = match value {
+            Value::List(values) => values.iter().collect::<Vec<_>>(),
+            value => vec![value],
+        };
+        for value in values {
+            let Some(value) = form_value(value) else {
+                let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                    "value of <{key}> can not be form-encoded: {}",
+                    value.kind()
+                ));
+                return Err(RunnerError::new(source_info, kind, assert));
+            };
+            serializer.append_pair(key, &value);
+        }
+    }
+    Ok(Some(Value::String(serializer.finish())))
+}
+
+/// Returns the form representation of a scalar `value`.
+fn form_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Decodes the `application/x-www-form-urlencoded` string `value` to an object.
+///
+/// The value of a repeated key is the list of all the values of this key, in order.
+pub fn eval_form_decode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::String(s) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let mut fields: Vec<(String, Value)> = vec![];
+    for (key, value) in form_urlencoded::parse(s.as_bytes()) {
+        let value = Value::String(value.into_owned());
+        match fields.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, Value::List(values))) => values.push(value),
+            Some((_, existing)) => *existing = Value::List(vec![existing.clone(), value]),
+            None => fields.push((key.into_owned(), value)),
+        }
+    }
+    Ok(Some(Value::Object(fields)))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(value: FilterValue) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value,
+        }
+    }
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn eval_filter_form_encode() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::FormEncode);
+        let value = object(&[
+            ("name", string("Bob Smith")),
+            ("email", string("bob+test@example.org")),
+            ("q", string("a&b=c/é")),
+            ("age", Value::Number(Number::Integer(30))),
+            ("admin", Value::Bool(false)),
+            ("tags", Value::List(vec![string("a"), string("b")])),
+        ]);
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false)
+                .unwrap()
+                .unwrap(),
+            string(
+                "name=Bob+Smith&email=bob%2Btest%40example.org&q=a%26b%3Dc%2F%C3%A9\
+                 &age=30&admin=false&tags=a&tags=b"
+            )
+        );
+    }
+
+    #[test]
+    fn eval_filter_form_decode() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::FormDecode);
+        let value = string("name=Bob+Smith&q=a%26b%3Dc%2F%C3%A9&tags=a&empty=&tags=b&tags=c");
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false)
+                .unwrap()
+                .unwrap(),
+            object(&[
+                ("name", string("Bob Smith")),
+                ("q", string("a&b=c/é")),
+                (
+                    "tags",
+                    Value::List(vec![string("a"), string("b"), string("c")])
+                ),
+                ("empty", string("")),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_form_round_trip() {
+        let variables = VariableSet::new();
+        let encode = new_filter(FilterValue::FormEncode);
+        let decode = new_filter(FilterValue::FormDecode);
+        let value = object(&[
+            ("message", string("Hello world! 100% sure?")),
+            ("tags", Value::List(vec![string("a b"), string("c+d")])),
+        ]);
+        let encoded = eval_filter(&encode, &value, &variables, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            eval_filter(&decode, &encoded, &variables, false)
+                .unwrap()
+                .unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn eval_filter_form_encode_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::FormEncode);
+        let value = object(&[("user", object(&[]))]);
+        let err = eval_filter(&filter, &value, &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "value of <user> can not be form-encoded: object".to_string()
+            )
+        );
+    }
+}
@@ -53,8 +53,8 @@ pub fn eval_date_format(
 
 #[cfg(test)]
 mod tests {
-    use chrono::offset::Utc;
     use chrono::TimeZone;
+    use chrono_tz::UTC;
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
@@ -89,7 +89,7 @@ mod tests {
     fn eval_filter_format_ok() {
         let variables = VariableSet::new();
 
-        let date = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let date = UTC.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
         let filter = new_date_format_filter("%m/%d/%Y");
         let ret = eval_filter(&filter, &Value::Date(date), &variables, false);
         assert_eq!(
@@ -119,7 +119,7 @@ mod tests {
     fn eval_filter_format_ko_invalid_format() {
         let variables = VariableSet::new();
 
-        let date = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let date = UTC.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
         let filter = new_date_format_filter("%%%");
         let ret = eval_filter(&filter, &Value::Date(date), &variables, false);
         assert_eq!(
@@ -0,0 +1,172 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Units of binary sizes, powers of 1024.
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Units of decimal sizes, powers of 1000.
+const DECIMAL_UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Formats a number of bytes `value` to a human-readable size, with binary units (`KiB`, `MiB`...)
+/// or `decimal` units (`KB`, `MB`...).
+pub fn eval_format_bytes(
+    value: &Value,
+    decimal: bool,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let size = match value {
+        Value::Number(Number::Integer(v)) => *v as f64,
+        Value::Number(Number::Float(v)) => *v,
+        Value::Number(Number::BigInteger(v)) => v.parse::<f64>().unwrap_or(f64::NAN),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    if !size.is_finite() || size < 0.0 {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    let (base, units) = if decimal {
+        (1000.0, DECIMAL_UNITS)
+    } else {
+        (1024.0, BINARY_UNITS)
+    };
+    Ok(Some(Value::String(format_size(size, base, &units))))
+}
+
+/// Formats `size` with the largest unit of `units` (successive powers of `base`) giving a value
+/// greater than or equal to 1, rounded to one decimal.
+fn format_size(size: f64, base: f64, units: &[&str]) -> String {
+    if size < base {
+        return format!("{} {}", size.trunc(), units[0]);
+    }
+    let mut value = size;
+    let mut index = 0;
+    // The rounded value may reach the base (ex: 1023.96 KiB), we then switch to the next unit.
+    while index < units.len() - 1 && (value * 10.0).round() / 10.0 >= base {
+        value /= base;
+        index += 1;
+    }
+    let value = format!("{value:.1}");
+    let value = value.strip_suffix(".0").unwrap_or(&value);
+    format!("{value} {}", units[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_format_bytes_filter(decimal: bool) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::FormatBytes {
+                decimal: decimal.then(|| Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_format_bytes() {
+        let variables = VariableSet::new();
+        let filter = new_format_bytes_filter(false);
+
+        let tests = [
+            (0, "0 B"),
+            (1023, "1023 B"),
+            (1024, "1 KiB"),
+            (1536, "1.5 KiB"),
+            (1_048_575, "1 MiB"),
+            (5_368_709_120, "5 GiB"),
+            (1_234_567_890_123, "1.1 TiB"),
+            (i64::MAX, "8 EiB"),
+        ];
+        for (size, expected) in tests {
+            let ret = eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(size)),
+                &variables,
+                false,
+            );
+            assert_eq!(ret.unwrap().unwrap(), Value::String(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn eval_filter_format_bytes_decimal() {
+        let variables = VariableSet::new();
+        let filter = new_format_bytes_filter(true);
+
+        let tests = [
+            (0, "0 B"),
+            (999, "999 B"),
+            (1000, "1 KB"),
+            (1536, "1.5 KB"),
+            (2_500_000, "2.5 MB"),
+            (1_234_567_890_123, "1.2 TB"),
+        ];
+        for (size, expected) in tests {
+            let ret = eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(size)),
+                &variables,
+                false,
+            );
+            assert_eq!(ret.unwrap().unwrap(), Value::String(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn eval_filter_format_bytes_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = new_format_bytes_filter(false);
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("1024".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(-1)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <-1>".to_string())
+        );
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::jsfilter::{call_jsfilter, with_element_index};
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Groups the elements of a list `value` by the string keys computed by the JavaScript filter
+/// function `name` for each element.
+///
+/// Returns an object mapping each key to the list of its elements. Keys are ordered by first
+/// occurrence, and elements keep their original order within a group.
+pub fn eval_group_by(
+    value: &Value,
+    name: &Template,
+    variables: &VariableSet,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let name = eval_template(name, variables)?;
+
+    match value {
+        Value::List(values) => {
+            let mut groups: Vec<(String, Vec<Value>)> = vec![];
+            for (i, value) in values.iter().enumerate() {
+                let key = call_jsfilter(&name, value, &[], jsfilter_path, source_info, assert)
+                    .map_err(|e| with_element_index(e, i))?;
+                let Value::String(key) = key else {
+                    let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                        "group key {} of element at index {i} is not a string",
+                        key.repr()
+                    ));
+                    return Err(RunnerError::new(source_info, kind, assert));
+                };
+                match groups.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, group)) => group.push(value.clone()),
+                    None => groups.push((key, vec![value.clone()])),
+                }
+            }
+            let groups = groups
+                .into_iter()
+                .map(|(key, group)| (key, Value::List(group)))
+                .collect();
+            Ok(Some(Value::Object(groups)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::filter::jsfilter::{drop_js_runtime, write_js_filters};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter `groupBy name`.
+    fn new_group_by_filter(name: &str) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        Filter {
+            source_info,
+            value: FilterValue::GroupBy {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info,
+                },
+                name: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: name.to_string(),
+                        source: name.to_source(),
+                    }],
+                    source_info,
+                ),
+            },
+        }
+    }
+
+    fn integer(value: i64) -> Value {
+        Value::Number(Number::Integer(value))
+    }
+
+    fn book(title: &str, genre: &str) -> Value {
+        Value::Object(vec![
+            ("title".to_string(), Value::String(title.to_string())),
+            ("genre".to_string(), Value::String(genre.to_string())),
+        ])
+    }
+
+    #[test]
+    fn eval_filter_group_by() {
+        let source = r#"
+            function filter_parity(input) { return input % 2 === 0 ? "even" : "odd"; }
+            function filter_genre(input) { return input.genre; }
+            function filter_length(input) { return input.length; }
+        "#;
+        let context = FilterContext::new(Some(write_js_filters(source)));
+        let variables = VariableSet::new();
+
+        let values = Value::List((1..=5).map(integer).collect());
+        let filter = new_group_by_filter("parity");
+        let ret = eval_filter_with_context(&filter, &values, &variables, &context, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Object(vec![
+                (
+                    "odd".to_string(),
+                    Value::List(vec![integer(1), integer(3), integer(5)])
+                ),
+                (
+                    "even".to_string(),
+                    Value::List(vec![integer(2), integer(4)])
+                ),
+            ])
+        );
+
+        let books = Value::List(vec![
+            book("Dune", "sf"),
+            book("Les Misérables", "novel"),
+            book("Foundation", "sf"),
+        ]);
+        let filter = new_group_by_filter("genre");
+        let ret = eval_filter_with_context(&filter, &books, &variables, &context, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Object(vec![
+                (
+                    "sf".to_string(),
+                    Value::List(vec![book("Dune", "sf"), book("Foundation", "sf")])
+                ),
+                (
+                    "novel".to_string(),
+                    Value::List(vec![book("Les Misérables", "novel")])
+                ),
+            ])
+        );
+
+        let values = Value::List(vec![Value::String("abc".to_string())]);
+        let filter = new_group_by_filter("length");
+        let ret = eval_filter_with_context(&filter, &values, &variables, &context, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "group key integer <3> of element at index 0 is not a string".to_string()
+            )
+        );
+
+        drop_js_runtime();
+    }
+}
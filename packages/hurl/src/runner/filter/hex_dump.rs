@@ -0,0 +1,134 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Number of bytes displayed on each line of the dump.
+const BYTES_PER_LINE: usize = 16;
+
+/// Formats bytes `value` (or the UTF-8 bytes of a string `value`) to a human-readable dump, with
+/// offsets, hexadecimal values and ASCII characters, like `hexdump -C`.
+pub fn eval_hex_dump(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => Ok(Some(Value::String(hex_dump(value)))),
+        Value::String(value) => Ok(Some(Value::String(hex_dump(value.as_bytes())))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the dump of `bytes`, one line per chunk of [`BYTES_PER_LINE`] bytes.
+fn hex_dump(bytes: &[u8]) -> String {
+    let lines = bytes
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let mut line = format!("{:08x}  ", index * BYTES_PER_LINE);
+            for i in 0..BYTES_PER_LINE {
+                if i == BYTES_PER_LINE / 2 {
+                    line.push(' ');
+                }
+                match chunk.get(i) {
+                    Some(byte) => line.push_str(&format!("{byte:02x} ")),
+                    None => line.push_str("   "),
+                }
+            }
+            let ascii = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            line.push_str(&format!(" |{ascii}|"));
+            line
+        })
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    fn eval_filter_hex_dump() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+            value: FilterValue::HexDump,
+        };
+
+        let bytes = b"Hello World!\n\x00\x01\xffHurl".to_vec();
+        let ret = eval_filter(&filter, &Value::Bytes(bytes), &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String(
+                "00000000  48 65 6c 6c 6f 20 57 6f  72 6c 64 21 0a 00 01 ff  |Hello World!....|\n\
+                 00000010  48 75 72 6c                                       |Hurl|"
+                    .to_string()
+            )
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("café".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String(
+                "00000000  63 61 66 c3 a9                                    |caf..|".to_string()
+            )
+        );
+
+        let ret = eval_filter(&filter, &Value::Bytes(vec![]), &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::String(String::new()));
+    }
+
+    #[test]
+    fn eval_filter_hex_dump_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+            value: FilterValue::HexDump,
+        };
+
+        let ret = eval_filter(&filter, &Value::Bool(true), &variables, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
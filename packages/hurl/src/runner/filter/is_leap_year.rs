@@ -0,0 +1,108 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::Datelike;
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns `true` if the year of a date `value`, or a year given as an integer `value`, is a leap
+/// year in the Gregorian calendar.
+pub fn eval_is_leap_year(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Date(date) => Ok(Some(Value::Bool(is_leap_year(date.year() as i64)))),
+        Value::Number(Number::Integer(year)) => Ok(Some(Value::Bool(is_leap_year(*year)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::UTC;
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    fn new_is_leap_year_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::IsLeapYear,
+        }
+    }
+
+    #[test]
+    fn eval_filter_is_leap_year_date() {
+        let variables = VariableSet::new();
+        let filter = new_is_leap_year_filter();
+
+        let tests = [(2000, true), (1900, false), (2024, true), (2025, false)];
+        for (year, expected) in tests {
+            let date = Value::Date(UTC.with_ymd_and_hms(year, 6, 15, 0, 0, 0).unwrap());
+            let ret = eval_filter(&filter, &date, &variables, false);
+            assert_eq!(ret.unwrap().unwrap(), Value::Bool(expected), "year {year}");
+        }
+    }
+
+    #[test]
+    fn eval_filter_is_leap_year_number() {
+        let variables = VariableSet::new();
+        let filter = new_is_leap_year_filter();
+
+        let tests = [(2000, true), (1900, false), (2024, true), (2023, false)];
+        for (year, expected) in tests {
+            let ret = eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(year)),
+                &variables,
+                false,
+            );
+            assert_eq!(ret.unwrap().unwrap(), Value::Bool(expected), "year {year}");
+        }
+    }
+
+    #[test]
+    fn eval_filter_is_leap_year_ko_bad_input_type() {
+        let variables = VariableSet::new();
+        let filter = new_is_leap_year_filter();
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Float(2024.5)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("float".to_string())
+        );
+    }
+}
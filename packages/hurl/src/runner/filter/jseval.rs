@@ -0,0 +1,185 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::jsfilter::eval_js_expression;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, Value, VariableSet};
+
+/// Evaluates the inline JavaScript expression `expr` with the input `value` bound as `input`.
+pub fn eval_jseval(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let expr = eval_template(expr, variables)?;
+    let ret = eval_js_expression(&expr, value, jsfilter_path, source_info, assert)?;
+    Ok(Some(ret))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Placeholder, SourceInfo, Template, TemplateElement,
+        Variable, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::filter::jsfilter::{drop_js_runtime, set_js_config};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter `jseval "<elements>"`.
+    fn new_jseval_filter(elements: Vec<TemplateElement>) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        Filter {
+            source_info,
+            value: FilterValue::JsEval {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info,
+                },
+                expr: Template::new(Some('"'), elements, source_info),
+            },
+        }
+    }
+
+    fn new_string(value: &str) -> TemplateElement {
+        TemplateElement::String {
+            value: value.to_string(),
+            source: value.to_source(),
+        }
+    }
+
+    #[test]
+    fn eval_filter_jseval() {
+        let variables = VariableSet::new();
+
+        let filter = new_jseval_filter(vec![new_string("input * 2")]);
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(21)),
+            &variables,
+            &FilterContext::default(),
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(42)));
+
+        let filter = new_jseval_filter(vec![new_string("input.trim().toUpperCase()")]);
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::String(" hurl ".to_string()),
+            &variables,
+            &FilterContext::default(),
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::String("HURL".to_string()));
+
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn eval_filter_jseval_placeholder() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let mut variables = VariableSet::new();
+        variables.insert("factor".to_string(), Value::Number(Number::Integer(3)));
+
+        // jseval "input * {{factor}}"
+        let filter = new_jseval_filter(vec![
+            new_string("input * "),
+            TemplateElement::Placeholder(Placeholder {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info,
+                },
+                expr: Expr {
+                    kind: ExprKind::Variable(Variable {
+                        name: "factor".to_string(),
+                        source_info,
+                    }),
+                    source_info,
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info,
+                },
+            }),
+        ]);
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(14)),
+            &variables,
+            &FilterContext::default(),
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(42)));
+
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn eval_filter_jseval_config() {
+        let variables = VariableSet::new();
+        set_js_config(HashMap::from([(
+            "factor".to_string(),
+            Value::Number(Number::Integer(3)),
+        )]));
+        let filter = new_jseval_filter(vec![new_string("input * config.factor")]);
+
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(14)),
+            &variables,
+            &FilterContext::default(),
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(42)));
+
+        set_js_config(HashMap::new());
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn eval_filter_jseval_syntax_error() {
+        let variables = VariableSet::new();
+        let filter = new_jseval_filter(vec![new_string("input *")]);
+
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::Number(Number::Integer(21)),
+            &variables,
+            &FilterContext::default(),
+            false,
+        );
+        assert!(matches!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::JsFilterParseError { .. }
+        ));
+
+        drop_js_runtime();
+    }
+}
@@ -18,22 +18,188 @@
 
 //! JavaScript filter evaluator.
 
-use std::cell::RefCell;
-use std::path::PathBuf;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
 
-use hurl_core::ast::{SourceInfo, Template, Whitespace};
+use hurl_core::ast::visit::Visitor;
+use hurl_core::ast::{Entry, Filter, FilterValue, SourceInfo, Template, Whitespace};
 
-use crate::runner::js::{JsError, JsRuntime};
+use crate::http::Response;
+use crate::runner::filter::arg::eval_arg;
+use crate::runner::js::{
+    ArgsMode, FileLoadErrorKind, JsError, JsInvocation, JsResponse, JsRuntime, SharedGlobal,
+    DEFAULT_FILTER_PREFIX,
+};
 use crate::runner::template::eval_template;
-use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// A JavaScript runtime loaded from a file.
+struct LoadedRuntime {
+    path: PathBuf,
+    /// Last modification time of the file when it was loaded.
+    modified: Option<SystemTime>,
+    runtime: JsRuntime,
+}
 
 thread_local! {
     /// Thread-local JavaScript runtime.
     /// Each thread gets its own runtime instance, initialized lazily from the file path.
-    static JS_RUNTIME: RefCell<Option<(PathBuf, JsRuntime)>> = const { RefCell::new(None) };
+    static JS_RUNTIME: RefCell<Option<LoadedRuntime>> = const { RefCell::new(None) };
+
+    /// Thread-local JavaScript runtime evaluating inline expressions when no JavaScript file is
+    /// configured, initialized lazily.
+    static JS_INLINE_RUNTIME: RefCell<Option<JsRuntime>> = const { RefCell::new(None) };
+
+    /// Static configuration of the JavaScript filters run by this thread.
+    static JS_CONFIG: RefCell<HashMap<String, Value>> = RefCell::new(HashMap::new());
+
+    /// Whether the values returned by the JavaScript filters run by this thread are converted
+    /// strictly.
+    static JS_STRICT: Cell<bool> = const { Cell::new(false) };
+
+    /// Whether the JavaScript filters run by this thread follow the `[result, error]` convention
+    /// for their returned values.
+    static JS_TUPLE_RESULTS: Cell<bool> = const { Cell::new(false) };
+
+    /// Whether dates are passed to the JavaScript filters run by this thread as `Date` objects.
+    static JS_DATE_OBJECTS: Cell<bool> = const { Cell::new(false) };
+
+    /// Prefix of the names of the JavaScript filter functions run by this thread.
+    static JS_FILTER_PREFIX: RefCell<String> = RefCell::new(DEFAULT_FILTER_PREFIX.to_string());
+
+    /// Calling convention of the JavaScript filter functions run by this thread.
+    static JS_ARGS_MODE: Cell<ArgsMode> = const { Cell::new(ArgsMode::Positional) };
+
+    /// Whether the JavaScript filters run by this thread returning `undefined` leave their input
+    /// unchanged.
+    static JS_UNDEFINED_UNCHANGED: Cell<bool> = const { Cell::new(false) };
+
+    /// Name of the Hurl file run by this thread, used in the errors of JavaScript filters.
+    static HURL_FILENAME: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Sets the static configuration of the JavaScript filters run by the current thread, exposed as
+/// the `config` global object.
+pub fn set_js_config(config: HashMap<String, Value>) {
+    update_js_runtimes(|runtime| runtime.set_config(config.clone()));
+    JS_CONFIG.with(|cell| *cell.borrow_mut() = config);
+}
+
+/// Enables or disables the strict conversion of the values returned by the JavaScript filters run
+/// by the current thread: functions and symbols are then rejected instead of being converted to an
+/// object or a string.
+pub fn set_js_strict(strict: bool) {
+    update_js_runtimes(|runtime| runtime.set_strict(strict));
+    JS_STRICT.with(|cell| cell.set(strict));
+}
+
+/// Enables or disables the `[result, error]` convention for the values returned by the JavaScript
+/// filters run by the current thread, see [`JsRuntime::set_tuple_results`].
+pub fn set_js_tuple_results(tuple_results: bool) {
+    update_js_runtimes(|runtime| runtime.set_tuple_results(tuple_results));
+    JS_TUPLE_RESULTS.with(|cell| cell.set(tuple_results));
+}
+
+/// Passes dates to the JavaScript filters run by the current thread as `Date` objects instead of
+/// ISO 8601 strings, see [`JsRuntime::set_date_objects`].
+pub fn set_js_date_objects(date_objects: bool) {
+    update_js_runtimes(|runtime| runtime.set_date_objects(date_objects));
+    JS_DATE_OBJECTS.with(|cell| cell.set(date_objects));
+}
+
+/// Sets the prefix of the names of the JavaScript filter functions run by the current thread,
+/// `filter_` by default.
+pub fn set_js_filter_prefix(prefix: &str) {
+    update_js_runtimes(|runtime| runtime.set_filter_prefix(prefix));
+    JS_FILTER_PREFIX.with(|cell| *cell.borrow_mut() = prefix.to_string());
+}
+
+/// Sets the calling convention of the JavaScript filter functions run by the current thread, see
+/// [`JsRuntime::set_args_mode`].
+pub fn set_js_args_mode(args_mode: ArgsMode) {
+    update_js_runtimes(|runtime| runtime.set_args_mode(args_mode));
+    JS_ARGS_MODE.with(|cell| cell.set(args_mode));
+}
+
+/// Makes the JavaScript filters run by the current thread returning `undefined` leave their input
+/// unchanged, see [`JsRuntime::set_undefined_unchanged`].
+pub fn set_js_undefined_unchanged(undefined_unchanged: bool) {
+    update_js_runtimes(|runtime| runtime.set_undefined_unchanged(undefined_unchanged));
+    JS_UNDEFINED_UNCHANGED.with(|cell| cell.set(undefined_unchanged));
+}
+
+/// Applies `f` to the JavaScript runtimes of the current thread that are already initialized.
+fn update_js_runtimes(f: impl Fn(&mut JsRuntime)) {
+    JS_RUNTIME.with(|cell| {
+        if let Some(loaded) = cell.borrow_mut().as_mut() {
+            f(&mut loaded.runtime);
+        }
+    });
+    JS_INLINE_RUNTIME.with(|cell| {
+        if let Some(runtime) = cell.borrow_mut().as_mut() {
+            f(runtime);
+        }
+    });
+}
+
+/// Applies the JavaScript settings of the current thread to a new `runtime`.
+fn init_js_runtime(runtime: &mut JsRuntime) {
+    let shared_global = SHARED_GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    runtime.set_shared_global(shared_global.clone());
+    runtime.set_config(JS_CONFIG.with(|cell| cell.borrow().clone()));
+    runtime.set_strict(JS_STRICT.with(|cell| cell.get()));
+    runtime.set_tuple_results(JS_TUPLE_RESULTS.with(|cell| cell.get()));
+    runtime.set_date_objects(JS_DATE_OBJECTS.with(|cell| cell.get()));
+    JS_FILTER_PREFIX.with(|cell| runtime.set_filter_prefix(&cell.borrow()));
+    runtime.set_args_mode(JS_ARGS_MODE.with(|cell| cell.get()));
+    runtime.set_undefined_unchanged(JS_UNDEFINED_UNCHANGED.with(|cell| cell.get()));
+}
+
+/// Returns a new standalone runtime, for the `jseval` expressions run without JavaScript file.
+fn new_js_inline_runtime() -> JsRuntime {
+    let mut runtime = JsRuntime::new();
+    init_js_runtime(&mut runtime);
+    runtime
+}
+
+/// Sets the name of the Hurl file run by the current thread, reported in the errors thrown by
+/// JavaScript filters.
+pub fn set_js_hurl_filename(filename: Option<String>) {
+    HURL_FILENAME.with(|cell| *cell.borrow_mut() = filename);
+}
+
+/// Storage of the `client.global` object shared by the JavaScript runtimes of all threads, if
+/// enabled with [`set_js_shared_global`].
+static SHARED_GLOBAL: Mutex<Option<SharedGlobal>> = Mutex::new(None);
+
+/// Enables or disables sharing the `client.global` object of JavaScript filters between threads.
+///
+/// By default, each thread has its own JavaScript runtime, and so its own `client.global`: with
+/// parallel workers, data stored by a filter (a counter for instance) is only visible to the
+/// filters running on the same worker. When enabled, `client.global` is shared by all runtimes
+/// created afterwards, at the cost of serializing the execution of JavaScript filters.
+pub fn set_js_shared_global(enabled: bool) {
+    let mut shared_global = SHARED_GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    if !enabled {
+        *shared_global = None;
+    } else if shared_global.is_none() {
+        *shared_global = Some(SharedGlobal::default());
+    }
+}
+
+/// Returns the last modification time of the file `path`, if available.
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
 }
 
 /// Gets or creates a thread-local JsRuntime for the given path.
+///
+/// The runtime is reloaded when the file has been modified since it was loaded, so filters can be
+/// edited during a long run. The `client` object (`client.global` and `client.history`) is kept
+/// across reloads.
 fn with_js_runtime<F, R>(
     path: &PathBuf,
     source_info: SourceInfo,
@@ -47,28 +213,152 @@ where
         let mut opt = cell.borrow_mut();
 
         // Check if we need to initialize or re-initialize the runtime
+        let modified = modified(path);
         let needs_init = match &*opt {
             None => true,
-            Some((existing_path, _)) => existing_path != path,
+            Some(loaded) => &loaded.path != path || loaded.modified != modified,
         };
 
         if needs_init {
-            let mut runtime = JsRuntime::new();
-            runtime
-                .load_file(path)
+            let mut runtime = JsRuntime::from_file(path)
                 .map_err(|e| js_error_to_runner_error(e, source_info, in_assert))?;
-            *opt = Some((path.clone(), runtime));
+            init_js_runtime(&mut runtime);
+            if let Some(loaded) = opt.take().filter(|loaded| &loaded.path == path) {
+                *runtime.client_mut() = loaded.runtime.client().clone();
+            }
+            *opt = Some(LoadedRuntime {
+                path: path.clone(),
+                modified,
+                runtime,
+            });
         }
 
-        let (_, runtime) = opt.as_mut().unwrap();
-        f(runtime).map_err(|e| js_error_to_runner_error(e, source_info, in_assert))
+        let loaded = opt.as_mut().unwrap();
+        f(&mut loaded.runtime).map_err(|e| js_error_to_runner_error(e, source_info, in_assert))
     })
 }
 
+/// Records a new HTTP `response` of `entry` in the thread-local JavaScript runtime of
+/// `jsfilter_path`, for the `response` and `client.history` globals of the JavaScript filters.
+///
+/// Without JavaScript file, the response is recorded in the standalone runtime of the `jseval`
+/// expressions. The response is only recorded if `entry` uses JavaScript filters, or if the
+/// runtime has already been created by a previous entry: entries that don't use JavaScript don't
+/// create the runtime.
+pub fn record_js_response(
+    entry: &Entry,
+    jsfilter_path: &Option<PathBuf>,
+    response: &Response,
+) -> Result<(), RunnerError> {
+    let mut collector = JsFilterCollector::default();
+    collector.visit_entry(entry);
+
+    let Some(path) = jsfilter_path else {
+        JS_INLINE_RUNTIME.with(|cell| {
+            let mut opt = cell.borrow_mut();
+            let runtime = match opt.as_mut() {
+                Some(runtime) => runtime,
+                None if collector.first.is_some() => opt.insert(new_js_inline_runtime()),
+                None => return,
+            };
+            runtime.push_response(JsResponse::from_http_response(response));
+        });
+        return Ok(());
+    };
+
+    if let Some(source_info) = collector.first {
+        let response = JsResponse::from_http_response(response);
+        return with_js_runtime(path, source_info, false, |runtime| {
+            runtime.push_response(response);
+            Ok(())
+        });
+    }
+
+    JS_RUNTIME.with(|cell| {
+        let mut opt = cell.borrow_mut();
+        if let Some(loaded) = opt.as_mut().filter(|loaded| &loaded.path == path) {
+            let response = JsResponse::from_http_response(response);
+            loaded.runtime.push_response(response);
+        }
+    });
+    Ok(())
+}
+
+/// Collects the JavaScript filters of an AST node.
+#[derive(Default)]
+struct JsFilterCollector {
+    /// The filters calling a JavaScript function by its name.
+    filters: Vec<(Template, SourceInfo)>,
+    /// The first filter evaluated with JavaScript, `jseval` included.
+    first: Option<SourceInfo>,
+}
+
+impl Visitor for JsFilterCollector {
+    fn visit_filter(&mut self, filter: &Filter) {
+        match &filter.value {
+            FilterValue::JsEval { .. } => {
+                self.first.get_or_insert(filter.source_info);
+            }
+            FilterValue::JsFilter { name, .. }
+            | FilterValue::DropWhile { name, .. }
+            | FilterValue::Expect { name, .. }
+            | FilterValue::Filter { name, .. }
+            | FilterValue::GroupBy { name, .. }
+            | FilterValue::Map { name, .. }
+            | FilterValue::Reduce { name, .. }
+            | FilterValue::SortBy { name, .. }
+            | FilterValue::TakeWhile { name, .. } => {
+                self.filters.push((name.clone(), filter.source_info));
+                self.first.get_or_insert(filter.source_info);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks that all the JavaScript filters used in `entry` are defined in the file `path`.
+///
+/// Unknown filters are reported as a single error, before the entry is run. Filters whose name
+/// can't be evaluated with `variables` (for instance, a variable captured by this entry) are only
+/// checked when they're evaluated.
+pub fn check_js_filters(
+    entry: &Entry,
+    variables: &VariableSet,
+    path: &PathBuf,
+) -> Result<(), RunnerError> {
+    let mut collector = JsFilterCollector::default();
+    collector.visit_entry(entry);
+    let Some((_, source_info)) = collector.filters.first() else {
+        return Ok(());
+    };
+
+    let (unknown, available) = with_js_runtime(path, *source_info, false, |runtime| {
+        let mut unknown: Vec<(String, SourceInfo)> = vec![];
+        for (name, source_info) in &collector.filters {
+            let Ok(name) = eval_template(name, variables) else {
+                continue;
+            };
+            if !runtime.has_filter(&name) && !unknown.iter().any(|(n, _)| n == &name) {
+                unknown.push((name, *source_info));
+            }
+        }
+        Ok((unknown, runtime.list_filters()))
+    })?;
+
+    match unknown.first() {
+        None => Ok(()),
+        Some((_, source_info)) => {
+            let names = unknown.iter().map(|(name, _)| name.clone()).collect();
+            let kind = RunnerErrorKind::JsFilterUnknownFilters { names, available };
+            Err(RunnerError::new(*source_info, kind, false))
+        }
+    }
+}
+
 /// Evaluates a JavaScript filter.
 ///
-/// The filter function is looked up in the JavaScript runtime by name with
-/// the `filter_` prefix. For example, `jsfilter add 1` will call `filter_add(input, 1)`.
+/// The filter function is looked up in the JavaScript runtime by name with the filter prefix,
+/// `filter_` by default. For example, `jsfilter add 1` will call `filter_add(input, 1)`.
 pub fn eval_jsfilter(
     value: &Value,
     name: &Template,
@@ -78,31 +368,156 @@ pub fn eval_jsfilter(
     source_info: SourceInfo,
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
-    // Check if JS filter path is configured
-    let path = jsfilter_path.as_ref().ok_or_else(|| {
-        RunnerError::new(source_info, RunnerErrorKind::JsFilterNotConfigured, in_assert)
-    })?;
-
     // Evaluate the function name
     let name_str = eval_template(name, variables)?;
 
     // Evaluate arguments
     let mut evaluated_args = Vec::with_capacity(args.len());
     for (_, arg_template) in args {
-        let arg_str = eval_template(arg_template, variables)?;
-        // Try to parse as number, otherwise keep as string
-        let arg_value = parse_arg_value(&arg_str);
-        evaluated_args.push(arg_value);
+        evaluated_args.push(eval_arg(arg_template, variables)?);
     }
 
-    // Call the JavaScript function using thread-local runtime
-    let result = with_js_runtime(path, source_info, in_assert, |runtime| {
-        runtime.call_filter(&name_str, value, &evaluated_args)
-    })?;
+    let result = call_jsfilter(
+        &name_str,
+        value,
+        &evaluated_args,
+        jsfilter_path,
+        source_info,
+        in_assert,
+    )?;
 
     Ok(Some(result))
 }
 
+/// Calls the JavaScript filter function `name` with an `input` value and evaluated `args`.
+pub fn call_jsfilter(
+    name: &str,
+    input: &Value,
+    args: &[Value],
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    in_assert: bool,
+) -> Result<Value, RunnerError> {
+    // Check if JS filter path is configured
+    let path = jsfilter_path.as_ref().ok_or_else(|| {
+        RunnerError::new(
+            source_info,
+            RunnerErrorKind::JsFilterNotConfigured,
+            in_assert,
+        )
+    })?;
+
+    // Call the JavaScript function using thread-local runtime
+    let invocation = JsInvocation {
+        filename: HURL_FILENAME.with(|cell| cell.borrow().clone()),
+        line: source_info.start.line,
+    };
+    with_js_runtime(path, source_info, in_assert, |runtime| {
+        runtime.set_invocation(Some(invocation));
+        runtime.call_filter(name, input, args)
+    })
+}
+
+/// Evaluates the JavaScript expression `expr`, `input` being bound to the `input` variable.
+///
+/// The expression is evaluated in the runtime of the JavaScript file `jsfilter_path` if any, seeing
+/// the same globals as the JavaScript filters, or in a standalone runtime otherwise.
+pub fn eval_js_expression(
+    expr: &str,
+    input: &Value,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    in_assert: bool,
+) -> Result<Value, RunnerError> {
+    let invocation = JsInvocation {
+        filename: HURL_FILENAME.with(|cell| cell.borrow().clone()),
+        line: source_info.start.line,
+    };
+    if let Some(path) = jsfilter_path {
+        return with_js_runtime(path, source_info, in_assert, |runtime| {
+            runtime.set_invocation(Some(invocation));
+            runtime.eval_expression(expr, input)
+        });
+    }
+
+    JS_INLINE_RUNTIME.with(|cell| {
+        let mut opt = cell.borrow_mut();
+        let runtime = opt.get_or_insert_with(new_js_inline_runtime);
+        runtime.set_invocation(Some(invocation));
+        runtime
+            .eval_expression(expr, input)
+            .map_err(|e| js_error_to_runner_error(e, source_info, in_assert))
+    })
+}
+
+/// Calls the JavaScript filter function `name` as a predicate on an `input` value.
+///
+/// The value returned by the function is converted to a boolean following the JavaScript rules:
+/// `false`, `0`, `NaN`, `""`, `null` and `undefined` are false, any other value is true.
+pub fn call_js_predicate(
+    name: &str,
+    input: &Value,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    in_assert: bool,
+) -> Result<bool, RunnerError> {
+    let result = call_jsfilter(name, input, &[], jsfilter_path, source_info, in_assert)?;
+    Ok(is_truthy(&result))
+}
+
+/// Adds the `index` of the list element being processed to the message of a JavaScript `error`.
+pub fn with_element_index(mut error: RunnerError, index: usize) -> RunnerError {
+    match &mut error.kind {
+        RunnerErrorKind::JsFilterRuntimeError { message }
+        | RunnerErrorKind::JsFilterConversionError { message } => {
+            *message = format!("element at index {index}: {message}");
+        }
+        _ => {}
+    }
+    error
+}
+
+/// Returns `true` if a value returned by a JavaScript function is truthy.
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Bool(value) => *value,
+        Value::Null | Value::Unit => false,
+        Value::Number(Number::Integer(value)) => *value != 0,
+        Value::Number(Number::Float(value)) => *value != 0.0 && !value.is_nan(),
+        Value::Number(Number::BigInteger(value)) => value != "0",
+        Value::String(value) => !value.is_empty(),
+        _ => true,
+    }
+}
+
+/// Drops the JavaScript runtimes of the current thread.
+///
+/// In tests, the runtimes must be dropped before the thread-local storage of the JavaScript
+/// engine is destroyed.
+#[cfg(test)]
+pub fn drop_js_runtime() {
+    JS_RUNTIME.with(|cell| cell.borrow_mut().take());
+    JS_INLINE_RUNTIME.with(|cell| cell.borrow_mut().take());
+}
+
+/// Writes a JavaScript filter file with `source` in a new temporary directory, and returns its
+/// path.
+///
+/// Each call uses its own directory, so tests running concurrently don't overwrite each other
+/// files.
+#[cfg(test)]
+pub fn write_js_filters(source: &str) -> PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    let count = COUNT.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("hurl_js_{}_{count}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("filters.js");
+    std::fs::write(&path, source).unwrap();
+    path
+}
+
 /// Converts a JavaScript error to a runner error.
 fn js_error_to_runner_error(
     error: JsError,
@@ -110,61 +525,446 @@ fn js_error_to_runner_error(
     in_assert: bool,
 ) -> RunnerError {
     let kind = match error {
-        JsError::FunctionNotFound { name } => RunnerErrorKind::JsFilterFunctionNotFound { name },
+        JsError::FunctionNotFound {
+            function,
+            available,
+        } => RunnerErrorKind::JsFilterFunctionNotFound {
+            function,
+            available,
+        },
+        JsError::ArityMismatch {
+            function,
+            expected,
+            got,
+        } => RunnerErrorKind::JsFilterArityMismatch {
+            function,
+            expected,
+            got,
+        },
         JsError::RuntimeError { message } => RunnerErrorKind::JsFilterRuntimeError { message },
-        JsError::ConversionError { message } => RunnerErrorKind::JsFilterRuntimeError { message },
-        JsError::FileLoadError { message, .. } => RunnerErrorKind::JsFilterRuntimeError { message },
-        JsError::ParseError { message } => RunnerErrorKind::JsFilterRuntimeError { message },
+        JsError::ConversionError { message } => {
+            RunnerErrorKind::JsFilterConversionError { message }
+        }
+        JsError::FileLoadError {
+            path,
+            kind,
+            message,
+        } => match kind {
+            FileLoadErrorKind::NotFound => RunnerErrorKind::JsFilterFileNotFound { path },
+            FileLoadErrorKind::PermissionDenied => {
+                RunnerErrorKind::JsFilterFilePermissionDenied { path }
+            }
+            FileLoadErrorKind::Other => RunnerErrorKind::JsFilterFileLoadError { path, message },
+        },
+        JsError::ParseError { message } => RunnerErrorKind::JsFilterParseError { message },
     };
     RunnerError::new(source_info, kind, in_assert)
 }
 
-/// Parses an argument string into a Value.
-///
-/// Tries to parse as integer, then float, then keeps as string.
-fn parse_arg_value(s: &str) -> Value {
-    // Try integer
-    if let Ok(i) = s.parse::<i64>() {
-        return Value::Number(crate::runner::Number::Integer(i));
-    }
-    // Try float
-    if let Ok(f) = s.parse::<f64>() {
-        return Value::Number(crate::runner::Number::Float(f));
-    }
-    // Keep as string
-    Value::String(s.to_string())
-}
-
 #[cfg(test)]
 mod tests {
+    use hurl_core::ast::TemplateElement;
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
     use super::*;
     use crate::runner::Number;
 
+    fn kind(error: JsError) -> RunnerErrorKind {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        js_error_to_runner_error(error, source_info, false).kind
+    }
+
     #[test]
-    fn test_parse_arg_value_integer() {
+    fn test_js_error_to_runner_error() {
         assert_eq!(
-            parse_arg_value("42"),
-            Value::Number(Number::Integer(42))
+            kind(JsError::FunctionNotFound {
+                function: "filter_foo".to_string(),
+                available: vec!["bar".to_string()],
+            }),
+            RunnerErrorKind::JsFilterFunctionNotFound {
+                function: "filter_foo".to_string(),
+                available: vec!["bar".to_string()],
+            }
         );
         assert_eq!(
-            parse_arg_value("-10"),
-            Value::Number(Number::Integer(-10))
+            kind(JsError::ArityMismatch {
+                function: "filter_foo".to_string(),
+                expected: 3,
+                got: 2,
+            }),
+            RunnerErrorKind::JsFilterArityMismatch {
+                function: "filter_foo".to_string(),
+                expected: 3,
+                got: 2,
+            }
+        );
+        assert_eq!(
+            kind(JsError::RuntimeError {
+                message: "boom".to_string(),
+            }),
+            RunnerErrorKind::JsFilterRuntimeError {
+                message: "boom".to_string(),
+            }
+        );
+        assert_eq!(
+            kind(JsError::ConversionError {
+                message: "unsupported type".to_string(),
+            }),
+            RunnerErrorKind::JsFilterConversionError {
+                message: "unsupported type".to_string(),
+            }
+        );
+        assert_eq!(
+            kind(JsError::FileLoadError {
+                path: "filters.js".to_string(),
+                kind: FileLoadErrorKind::NotFound,
+                message: "No such file or directory".to_string(),
+            }),
+            RunnerErrorKind::JsFilterFileNotFound {
+                path: "filters.js".to_string(),
+            }
+        );
+        assert_eq!(
+            kind(JsError::FileLoadError {
+                path: "filters.js".to_string(),
+                kind: FileLoadErrorKind::PermissionDenied,
+                message: "Permission denied".to_string(),
+            }),
+            RunnerErrorKind::JsFilterFilePermissionDenied {
+                path: "filters.js".to_string(),
+            }
+        );
+        assert_eq!(
+            kind(JsError::FileLoadError {
+                path: "filters".to_string(),
+                kind: FileLoadErrorKind::Other,
+                message: "Is a directory".to_string(),
+            }),
+            RunnerErrorKind::JsFilterFileLoadError {
+                path: "filters".to_string(),
+                message: "Is a directory".to_string(),
+            }
+        );
+        assert_eq!(
+            kind(JsError::ParseError {
+                message: "filters.js: unexpected token".to_string(),
+            }),
+            RunnerErrorKind::JsFilterParseError {
+                message: "filters.js: unexpected token".to_string(),
+            }
         );
     }
 
     #[test]
-    fn test_parse_arg_value_float() {
+    fn test_check_js_filters() {
+        let path = write_js_filters("function filter_upper(input) { return input.toUpperCase(); }");
+        let variables = VariableSet::new();
+
+        let content = r#"GET http://localhost:8000/hello
+HTTP 200
+[Captures]
+a: body jsfilter upper
+b: body jsfilter lower
+[Asserts]
+body jsfilter upper jsfilter trim jsfilter lower == "HELLO"
+"#;
+        let file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let error = check_js_filters(&file.entries[0], &variables, &path).unwrap_err();
         assert_eq!(
-            parse_arg_value("3.14"),
-            Value::Number(Number::Float(3.14))
+            error.kind,
+            RunnerErrorKind::JsFilterUnknownFilters {
+                names: vec!["lower".to_string(), "trim".to_string()],
+                available: vec!["upper".to_string()],
+            }
         );
+        assert_eq!(error.source_info.start, Pos::new(5, 9));
+
+        let content = r#"GET http://localhost:8000/hello
+HTTP 200
+[Asserts]
+body jsfilter upper == "HELLO"
+"#;
+        let file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        assert!(check_js_filters(&file.entries[0], &variables, &path).is_ok());
+
+        let content = r#"GET http://localhost:8000/hello
+HTTP 200
+[Asserts]
+body jsfilter upper expect isShouting "body must be upper case" == "HELLO"
+"#;
+        let file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let error = check_js_filters(&file.entries[0], &variables, &path).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::JsFilterUnknownFilters {
+                names: vec!["isShouting".to_string()],
+                available: vec!["upper".to_string()],
+            }
+        );
+        assert_eq!(error.source_info.start, Pos::new(4, 21));
+
+        // Drops the runtime before the thread-local storage of the JavaScript engine is destroyed.
+        JS_RUNTIME.with(|cell| cell.borrow_mut().take());
     }
 
     #[test]
-    fn test_parse_arg_value_string() {
+    fn test_record_js_response() {
+        let path = write_js_filters("function filter_status(input) { return response.status; }");
+        let jsfilter_path = Some(path.clone());
+        let content = r#"GET http://localhost:8000/hello
+HTTP 200
+
+GET http://localhost:8000/hello
+HTTP 200
+[Asserts]
+body jsfilter status == 200
+"#;
+        let file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = crate::http::hello_http_response();
+
+        // An entry without JavaScript filters doesn't load the runtime.
+        record_js_response(&file.entries[0], &jsfilter_path, &response).unwrap();
+        assert!(JS_RUNTIME.with(|cell| cell.borrow().is_none()));
+
+        record_js_response(&file.entries[1], &jsfilter_path, &response).unwrap();
+        let history_len = JS_RUNTIME.with(|cell| {
+            let opt = cell.borrow();
+            opt.as_ref().unwrap().runtime.client().history.len()
+        });
+        assert_eq!(history_len, 1);
+
+        // Once loaded, the runtime records the responses of all the entries.
+        record_js_response(&file.entries[0], &jsfilter_path, &response).unwrap();
+        let history_len = JS_RUNTIME.with(|cell| {
+            let opt = cell.borrow();
+            opt.as_ref().unwrap().runtime.client().history.len()
+        });
+        assert_eq!(history_len, 2);
+
+        // A file that can't be loaded is reported.
+        let path = path.with_file_name("missing.js");
+        let jsfilter_path = Some(path.clone());
+        let error = record_js_response(&file.entries[1], &jsfilter_path, &response).unwrap_err();
         assert_eq!(
-            parse_arg_value("hello"),
-            Value::String("hello".to_string())
+            error.kind,
+            RunnerErrorKind::JsFilterFileNotFound {
+                path: path.display().to_string()
+            }
         );
+        assert_eq!(error.source_info.start, Pos::new(7, 6));
+
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn test_record_js_response_inline() {
+        let content = r#"GET http://localhost:8000/hello
+HTTP 200
+
+GET http://localhost:8000/hello
+HTTP 200
+[Asserts]
+body jseval "response.status + ' ' + client.history.length" == "200 1"
+"#;
+        let file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = crate::http::hello_http_response();
+
+        // Without JavaScript file, an entry without JavaScript filters doesn't create the
+        // standalone runtime.
+        record_js_response(&file.entries[0], &None, &response).unwrap();
+        assert!(JS_INLINE_RUNTIME.with(|cell| cell.borrow().is_none()));
+
+        // The `jseval` expressions see the response of their entry.
+        record_js_response(&file.entries[1], &None, &response).unwrap();
+        let source_info = SourceInfo::new(Pos::new(7, 6), Pos::new(7, 12));
+        let expr = "response.status + ' ' + client.history.length";
+        let result = eval_js_expression(expr, &Value::Null, &None, source_info, false);
+        assert_eq!(result.unwrap(), Value::String("200 1".to_string()));
+
+        // Once created, the runtime records the responses of all the entries.
+        record_js_response(&file.entries[0], &None, &response).unwrap();
+        let result = eval_js_expression(expr, &Value::Null, &None, source_info, false);
+        assert_eq!(result.unwrap(), Value::String("200 2".to_string()));
+
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn test_set_js_tuple_results() {
+        let path = Some(write_js_filters(
+            "function filter_parse(input) { return [parseInt(input), null]; }",
+        ));
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let input = Value::String("42".to_string());
+        let call = || call_jsfilter("parse", &input, &[], &path, source_info, false).unwrap();
+
+        assert_eq!(
+            call(),
+            Value::List(vec![Value::Number(Number::Integer(42)), Value::Null])
+        );
+
+        // The setting is applied to the loaded runtime, and to the runtimes created afterwards.
+        set_js_tuple_results(true);
+        assert_eq!(call(), Value::Number(Number::Integer(42)));
+        drop_js_runtime();
+        assert_eq!(call(), Value::Number(Number::Integer(42)));
+
+        set_js_tuple_results(false);
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn test_set_js_date_objects() {
+        let path = Some(write_js_filters(
+            "function filter_type(input) { return typeof input; }",
+        ));
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let date = chrono::DateTime::parse_from_rfc3339("2026-02-28T10:30:00Z")
+            .unwrap()
+            .with_timezone(&chrono_tz::UTC);
+        let input = Value::Date(date);
+        let call = || call_jsfilter("type", &input, &[], &path, source_info, false).unwrap();
+
+        assert_eq!(call(), Value::String("string".to_string()));
+
+        set_js_date_objects(true);
+        assert_eq!(call(), Value::String("object".to_string()));
+        drop_js_runtime();
+        assert_eq!(call(), Value::String("object".to_string()));
+
+        set_js_date_objects(false);
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn test_set_js_filter_prefix() {
+        let path = Some(write_js_filters(
+            "function hurl_upper(input) { return input.toUpperCase(); }",
+        ));
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let input = Value::String("hello".to_string());
+        let call = || call_jsfilter("upper", &input, &[], &path, source_info, false);
+
+        assert_eq!(
+            call().unwrap_err().kind,
+            RunnerErrorKind::JsFilterFunctionNotFound {
+                function: "filter_upper".to_string(),
+                available: vec![],
+            }
+        );
+
+        set_js_filter_prefix("hurl_");
+        assert_eq!(call().unwrap(), Value::String("HELLO".to_string()));
+        drop_js_runtime();
+        assert_eq!(call().unwrap(), Value::String("HELLO".to_string()));
+
+        set_js_filter_prefix(DEFAULT_FILTER_PREFIX);
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn test_set_js_args_mode() {
+        let path = Some(write_js_filters(
+            "function filter_count(input, args) { return Array.isArray(args) ? args.length : -1; }",
+        ));
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let args = [Value::Bool(true), Value::Bool(false)];
+        let call =
+            || call_jsfilter("count", &Value::Null, &args, &path, source_info, false).unwrap();
+
+        assert_eq!(call(), Value::Number(Number::Integer(-1)));
+
+        set_js_args_mode(ArgsMode::Array);
+        assert_eq!(call(), Value::Number(Number::Integer(2)));
+        drop_js_runtime();
+        assert_eq!(call(), Value::Number(Number::Integer(2)));
+
+        set_js_args_mode(ArgsMode::Positional);
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn test_set_js_undefined_unchanged() {
+        let path = Some(write_js_filters("function filter_noop(input) {}"));
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let input = Value::String("hello".to_string());
+        let call = || call_jsfilter("noop", &input, &[], &path, source_info, false).unwrap();
+
+        assert_eq!(call(), Value::Null);
+
+        set_js_undefined_unchanged(true);
+        assert_eq!(call(), input);
+        drop_js_runtime();
+        assert_eq!(call(), input);
+
+        set_js_undefined_unchanged(false);
+        drop_js_runtime();
+    }
+
+    #[test]
+    fn test_eval_jsfilter_bytes() {
+        let path = write_js_filters(
+            r#"
+            function filter_invert(input) {
+                const output = new Uint8Array(input.length);
+                for (let i = 0; i < input.length; i++) {
+                    output[i] = 255 - input[i];
+                }
+                return output;
+            }
+        "#,
+        );
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 16));
+        let name = Template {
+            delimiter: None,
+            elements: vec![TemplateElement::String {
+                value: "invert".to_string(),
+                source: "invert".to_source(),
+            }],
+            source_info,
+        };
+        let variables = VariableSet::new();
+
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        let inverted = bytes.iter().rev().copied().collect::<Vec<u8>>();
+        let result = eval_jsfilter(
+            &Value::Bytes(bytes),
+            &name,
+            &[],
+            &variables,
+            &Some(path),
+            source_info,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result, Some(Value::Bytes(inverted)));
+
+        // Drops the runtime before the thread-local storage of the JavaScript engine is destroyed.
+        JS_RUNTIME.with(|cell| cell.borrow_mut().take());
+    }
+
+    #[test]
+    fn test_reload_modified_file() {
+        let path = write_js_filters("function filter_version(input) { return 1; }");
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let call = || {
+            with_js_runtime(&path, source_info, false, |runtime| {
+                runtime.call_filter("version", &Value::Null, &[])
+            })
+            .unwrap()
+        };
+
+        assert_eq!(call(), Value::Number(Number::Integer(1)));
+
+        std::fs::write(&path, "function filter_version(input) { return 2; }").unwrap();
+        // Makes sure the modification time changes, whatever the file system resolution.
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(call(), Value::Number(Number::Integer(2)));
+
+        // Drops the runtime before the thread-local storage of the JavaScript engine is destroyed.
+        JS_RUNTIME.with(|cell| cell.borrow_mut().take());
     }
 }
@@ -0,0 +1,442 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{Placeholder, SourceInfo};
+
+use crate::runner::filter::deep_equal::deep_equal;
+use crate::runner::{expr, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Applies a list of [JSON Patch](https://datatracker.ietf.org/doc/html/rfc6902) operations `ops`
+/// to `value` and returns the patched value.
+///
+/// Supported operations are `add`, `remove`, `replace`, `move`, `copy` and `test`. Operations are
+/// applied in order; the filter fails on the first operation that can't be applied, or on a
+/// failing `test` operation.
+pub fn eval_json_patch(
+    value: &Value,
+    ops: &Placeholder,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let ops = match expr::eval(&ops.expr, variables)? {
+        Value::List(ops) => ops,
+        v => {
+            let kind = RunnerErrorKind::ExpressionInvalidType {
+                value: v.repr(),
+                expecting: "list".to_string(),
+            };
+            return Err(RunnerError::new(ops.expr.source_info, kind, false));
+        }
+    };
+
+    let mut doc = value.clone();
+    for (index, op) in ops.iter().enumerate() {
+        if let Err(reason) = apply_op(&mut doc, op) {
+            let kind = RunnerErrorKind::FilterJsonPatch(format!("operation {index}: {reason}"));
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    }
+    Ok(Some(doc))
+}
+
+/// Applies a single JSON Patch operation `op` to `doc`.
+fn apply_op(doc: &mut Value, op: &Value) -> Result<(), String> {
+    let Value::Object(fields) = op else {
+        return Err(format!("{} is not an object", op.repr()));
+    };
+    let name = string_member(fields, "op")?;
+    let path = string_member(fields, "path")?;
+    match name {
+        "add" => {
+            let value = member(fields, "value")?.clone();
+            add(doc, path, value)
+        }
+        "remove" => remove(doc, path).map(|_| ()),
+        "replace" => {
+            let value = member(fields, "value")?.clone();
+            let target = get_mut(doc, path)?;
+            *target = value;
+            Ok(())
+        }
+        "move" => {
+            let from = string_member(fields, "from")?;
+            if path.starts_with(&format!("{from}/")) {
+                return Err(format!(
+                    "path <{from}> can not be moved into one of its children <{path}>"
+                ));
+            }
+            let value = remove(doc, from)?;
+            add(doc, path, value)
+        }
+        "copy" => {
+            let from = string_member(fields, "from")?;
+            let value = get_mut(doc, from)?.clone();
+            add(doc, path, value)
+        }
+        "test" => {
+            let expected = member(fields, "value")?;
+            let actual = get_mut(doc, path)?;
+            if deep_equal(actual, expected) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "test failed, value at path <{path}> is {}, expected {}",
+                    actual.repr(),
+                    expected.repr()
+                ))
+            }
+        }
+        _ => Err(format!("operation <{name}> is not supported")),
+    }
+}
+
+/// Returns the member `name` of a JSON Patch operation.
+fn member<'a>(fields: &'a [(String, Value)], name: &str) -> Result<&'a Value, String> {
+    fields
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value)
+        .ok_or_else(|| format!("missing <{name}> member"))
+}
+
+/// Returns the string member `name` of a JSON Patch operation.
+fn string_member<'a>(fields: &'a [(String, Value)], name: &str) -> Result<&'a str, String> {
+    match member(fields, name)? {
+        Value::String(value) => Ok(value),
+        v => Err(format!("<{name}> member {} is not a string", v.repr())),
+    }
+}
+
+/// Splits a [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) `path` into its
+/// unescaped reference tokens.
+fn parse_pointer(path: &str) -> Result<Vec<String>, String> {
+    if path.is_empty() {
+        return Ok(vec![]);
+    }
+    let Some(path) = path.strip_prefix('/') else {
+        return Err(format!("path <{path}> is not a valid JSON pointer"));
+    };
+    let tokens = path
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect();
+    Ok(tokens)
+}
+
+/// Returns a mutable reference to the value at `tokens` in `doc`, or `None` if there is no value.
+fn lookup<'a>(doc: &'a mut Value, tokens: &[String]) -> Option<&'a mut Value> {
+    let mut current = doc;
+    for token in tokens {
+        current = match current {
+            Value::Object(fields) => fields
+                .iter_mut()
+                .find(|(key, _)| key == token)
+                .map(|(_, value)| value)?,
+            Value::List(items) => items.get_mut(token.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+/// Returns a mutable reference to the value at `path` in `doc`.
+fn get_mut<'a>(doc: &'a mut Value, path: &str) -> Result<&'a mut Value, String> {
+    let tokens = parse_pointer(path)?;
+    lookup(doc, &tokens).ok_or_else(|| format!("path <{path}> does not exist"))
+}
+
+/// Adds `value` at `path` in `doc`.
+fn add(doc: &mut Value, path: &str, value: Value) -> Result<(), String> {
+    let tokens = parse_pointer(path)?;
+    let Some((last, parent)) = tokens.split_last() else {
+        *doc = value;
+        return Ok(());
+    };
+    match lookup(doc, parent) {
+        Some(Value::Object(fields)) => {
+            match fields.iter_mut().find(|(key, _)| key == last) {
+                Some((_, existing)) => *existing = value,
+                None => fields.push((last.clone(), value)),
+            }
+            Ok(())
+        }
+        Some(Value::List(items)) if last == "-" => {
+            items.push(value);
+            Ok(())
+        }
+        Some(Value::List(items)) => match last.parse::<usize>() {
+            Ok(index) if index <= items.len() => {
+                items.insert(index, value);
+                Ok(())
+            }
+            _ => Err(format!("path <{path}> is out of bounds")),
+        },
+        _ => Err(format!("path <{path}> does not exist")),
+    }
+}
+
+/// Removes the value at `path` in `doc` and returns it.
+fn remove(doc: &mut Value, path: &str) -> Result<Value, String> {
+    let tokens = parse_pointer(path)?;
+    let Some((last, parent)) = tokens.split_last() else {
+        return Err("root value can not be removed".to_string());
+    };
+    match lookup(doc, parent) {
+        Some(Value::Object(fields)) => match fields.iter().position(|(key, _)| key == last) {
+            Some(index) => Ok(fields.remove(index).1),
+            None => Err(format!("path <{path}> does not exist")),
+        },
+        Some(Value::List(items)) => match last.parse::<usize>() {
+            Ok(index) if index < items.len() => Ok(items.remove(index)),
+            _ => Err(format!("path <{path}> does not exist")),
+        },
+        _ => Err(format!("path <{path}> does not exist")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Placeholder, SourceInfo, Variable, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter `jsonPatch {{ops}}`.
+    fn new_json_patch_filter() -> Filter {
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPatch {
+                space0: whitespace.clone(),
+                ops: Placeholder {
+                    space0: whitespace.clone(),
+                    expr: Expr {
+                        kind: ExprKind::Variable(Variable {
+                            name: "ops".to_string(),
+                            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                        }),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    space1: whitespace,
+                },
+            },
+        }
+    }
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn string(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+
+    fn integer(value: i64) -> Value {
+        Value::Number(Number::Integer(value))
+    }
+
+    fn patch(value: &Value, ops: Vec<Value>) -> Result<Value, RunnerErrorKind> {
+        let mut variables = VariableSet::new();
+        variables.insert("ops".to_string(), Value::List(ops));
+        let filter = new_json_patch_filter();
+        eval_filter(&filter, value, &variables, false)
+            .map(|value| value.unwrap())
+            .map_err(|e| e.kind)
+    }
+
+    fn doc() -> Value {
+        object(&[
+            ("name", string("Bob")),
+            ("tags", Value::List(vec![string("a"), string("b")])),
+        ])
+    }
+
+    #[test]
+    fn eval_filter_json_patch_add() {
+        let ops = vec![
+            object(&[
+                ("op", string("add")),
+                ("path", string("/age")),
+                ("value", integer(42)),
+            ]),
+            object(&[
+                ("op", string("add")),
+                ("path", string("/tags/1")),
+                ("value", string("x")),
+            ]),
+            object(&[
+                ("op", string("add")),
+                ("path", string("/tags/-")),
+                ("value", string("z")),
+            ]),
+        ];
+        assert_eq!(
+            patch(&doc(), ops).unwrap(),
+            object(&[
+                ("name", string("Bob")),
+                (
+                    "tags",
+                    Value::List(vec![string("a"), string("x"), string("b"), string("z")])
+                ),
+                ("age", integer(42)),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_json_patch_remove() {
+        let ops = vec![
+            object(&[("op", string("remove")), ("path", string("/name"))]),
+            object(&[("op", string("remove")), ("path", string("/tags/0"))]),
+        ];
+        assert_eq!(
+            patch(&doc(), ops).unwrap(),
+            object(&[("tags", Value::List(vec![string("b")]))])
+        );
+    }
+
+    #[test]
+    fn eval_filter_json_patch_replace() {
+        let ops = vec![object(&[
+            ("op", string("replace")),
+            ("path", string("/name")),
+            ("value", string("Alice")),
+        ])];
+        assert_eq!(
+            patch(&doc(), ops).unwrap(),
+            object(&[
+                ("name", string("Alice")),
+                ("tags", Value::List(vec![string("a"), string("b")])),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_json_patch_move() {
+        let ops = vec![object(&[
+            ("op", string("move")),
+            ("from", string("/name")),
+            ("path", string("/first~1name")),
+        ])];
+        assert_eq!(
+            patch(&doc(), ops).unwrap(),
+            object(&[
+                ("tags", Value::List(vec![string("a"), string("b")])),
+                ("first/name", string("Bob")),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_json_patch_copy() {
+        let ops = vec![object(&[
+            ("op", string("copy")),
+            ("from", string("/tags/1")),
+            ("path", string("/tags/0")),
+        ])];
+        assert_eq!(
+            patch(&doc(), ops).unwrap(),
+            object(&[
+                ("name", string("Bob")),
+                (
+                    "tags",
+                    Value::List(vec![string("b"), string("a"), string("b")])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_json_patch_test() {
+        let ops = vec![
+            object(&[
+                ("op", string("test")),
+                ("path", string("/name")),
+                ("value", string("Bob")),
+            ]),
+            object(&[
+                ("op", string("test")),
+                ("path", string("")),
+                (
+                    "value",
+                    object(&[
+                        ("tags", Value::List(vec![string("a"), string("b")])),
+                        ("name", string("Bob")),
+                    ]),
+                ),
+            ]),
+        ];
+        assert_eq!(patch(&doc(), ops).unwrap(), doc());
+
+        let ops = vec![object(&[
+            ("op", string("test")),
+            ("path", string("/name")),
+            ("value", string("Alice")),
+        ])];
+        assert_eq!(
+            patch(&doc(), ops).unwrap_err(),
+            RunnerErrorKind::FilterJsonPatch(
+                "operation 0: test failed, value at path </name> is string <Bob>, expected string <Alice>"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn eval_filter_json_patch_error() {
+        let ops = vec![object(&[
+            ("op", string("remove")),
+            ("path", string("/foo")),
+        ])];
+        assert_eq!(
+            patch(&doc(), ops).unwrap_err(),
+            RunnerErrorKind::FilterJsonPatch("operation 0: path </foo> does not exist".to_string())
+        );
+
+        let ops = vec![object(&[
+            ("op", string("add")),
+            ("path", string("/tags/5")),
+            ("value", integer(1)),
+        ])];
+        assert_eq!(
+            patch(&doc(), ops).unwrap_err(),
+            RunnerErrorKind::FilterJsonPatch(
+                "operation 0: path </tags/5> is out of bounds".to_string()
+            )
+        );
+
+        let ops = vec![object(&[("op", string("merge")), ("path", string("/"))])];
+        assert_eq!(
+            patch(&doc(), ops).unwrap_err(),
+            RunnerErrorKind::FilterJsonPatch(
+                "operation 0: operation <merge> is not supported".to_string()
+            )
+        );
+    }
+}
@@ -18,13 +18,17 @@
 use hurl_core::ast::{SourceInfo, Template};
 
 use crate::jsonpath;
+use crate::runner::filter::arg::eval_arg;
 use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Evaluates a JSONPath expression `expr` against a `value`.
+///
+/// When no node matches, the filter returns the evaluated `default` if any.
 pub fn eval_jsonpath(
     value: &Value,
     expr: &Template,
+    default: Option<&Template>,
     variables: &VariableSet,
     source_info: SourceInfo,
     assert: bool,
@@ -56,7 +60,10 @@ pub fn eval_jsonpath(
             return Err(RunnerError::new(source_info, kind, assert));
         }
     };
-    eval_jsonpath_json(&json, expr, variables)
+    match (eval_jsonpath_json(&json, expr, variables)?, default) {
+        (None, Some(default)) => Ok(Some(eval_arg(default, variables)?)),
+        (result, _) => Ok(result),
+    }
 }
 
 impl Value {
@@ -127,7 +134,10 @@ pub fn eval_jsonpath_json(
 mod tests {
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Placeholder, SourceInfo, Template, TemplateElement,
+        Variable, Whitespace,
+    };
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
 
@@ -150,6 +160,7 @@ mod tests {
                     value: String::new(),
                     source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
                 },
+                default: None,
             },
         };
         assert_eq!(
@@ -165,6 +176,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eval_filter_jsonpath_default() {
+        let mut variables = VariableSet::new();
+        variables.insert("fallback".to_string(), Value::String("Goodbye".to_string()));
+
+        let template = |delimiter: Option<char>, elements: Vec<TemplateElement>| {
+            Template::new(
+                delimiter,
+                elements,
+                SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            )
+        };
+        let whitespace = || Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let filter = |path: &str| Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPath {
+                space0: whitespace(),
+                expr: template(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: path.to_string(),
+                        source: path.to_source(),
+                    }],
+                ),
+                default: Some((
+                    whitespace(),
+                    whitespace(),
+                    template(
+                        None,
+                        vec![TemplateElement::Placeholder(Placeholder {
+                            space0: whitespace(),
+                            expr: Expr {
+                                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                                kind: ExprKind::Variable(Variable {
+                                    name: "fallback".to_string(),
+                                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                                }),
+                            },
+                            space1: whitespace(),
+                        })],
+                    ),
+                )),
+            },
+        };
+        let input = Value::String(r#"{"message":"Hello"}"#.to_string());
+
+        // Matching path, the fallback is ignored.
+        assert_eq!(
+            eval_filter(&filter("$.message"), &input, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("Hello".to_string())
+        );
+
+        // Non-matching path, the fallback is used.
+        assert_eq!(
+            eval_filter(&filter("$.maybe"), &input, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("Goodbye".to_string())
+        );
+    }
+
     // #[test]
     // fn test_try_to_json_bool() {
     //     let input = Value::Bool(true);
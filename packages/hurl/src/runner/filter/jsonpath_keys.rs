@@ -0,0 +1,117 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::jsonpath::eval_jsonpath_json;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Evaluates a JSONPath expression `expr` against a `value` and returns the keys of the selected
+/// object.
+///
+/// If no node matches the expression, an empty list is returned.
+pub fn eval_jsonpath_keys(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let json = match value {
+        Value::String(text) => match serde_json::from_str(text) {
+            Ok(v) => v,
+            Err(_) => {
+                let kind =
+                    RunnerErrorKind::FilterInvalidInput("value is not a valid JSON".to_string());
+                return Err(RunnerError::new(source_info, kind, false));
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    match eval_jsonpath_json(&json, expr, variables)? {
+        None => Ok(Some(Value::List(vec![]))),
+        Some(Value::Object(fields)) => {
+            let keys = fields.into_iter().map(|(k, _)| Value::String(k)).collect();
+            Ok(Some(Value::List(keys)))
+        }
+        Some(v) => {
+            let kind =
+                RunnerErrorKind::FilterInvalidInput(format!("{} is not an object", v.repr()));
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(expr: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPathKeys {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: expr.to_string(),
+                        source: expr.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_jsonpath_keys() {
+        let variables = VariableSet::new();
+        let input = Value::String(
+            r#"{"config":{"debug":true,"retries":3,"timeout":10},"name":"Bob"}"#.to_string(),
+        );
+
+        let ret = eval_filter(&new_filter("$.config"), &input, &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::List(vec![
+                Value::String("debug".to_string()),
+                Value::String("retries".to_string()),
+                Value::String("timeout".to_string()),
+            ])
+        );
+
+        let ret = eval_filter(&new_filter("$.name"), &input, &variables, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string <Bob> is not an object".to_string())
+        );
+
+        let ret = eval_filter(&new_filter("$.missing"), &input, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::List(vec![]));
+    }
+}
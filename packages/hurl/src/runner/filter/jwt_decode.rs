@@ -0,0 +1,165 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Decodes the payload of the JWT `value` (or its header if `header` is true) to an object.
+///
+/// The signature of the token is not verified.
+pub fn eval_jwt_decode(
+    value: &Value,
+    header: bool,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::String(token) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let invalid_token = |reason: &str| {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!("invalid JWT, {reason}"));
+        RunnerError::new(source_info, kind, assert)
+    };
+    let parts = token.split('.').collect::<Vec<_>>();
+    if parts.len() != 3 {
+        return Err(invalid_token("expecting 3 parts separated by dots"));
+    }
+    let (name, part) = if header {
+        ("header", parts[0])
+    } else {
+        ("payload", parts[1])
+    };
+    // Padding is not used in JWT but is tolerated.
+    let Ok(decoded) = BASE64_URL_SAFE_NO_PAD.decode(part.trim_end_matches('=')) else {
+        return Err(invalid_token(&format!(
+            "{name} is not base64 URL safe encoded"
+        )));
+    };
+    match serde_json::from_slice::<serde_json::Value>(&decoded) {
+        Ok(json @ serde_json::Value::Object(_)) => Ok(Some(Value::from_json(&json))),
+        _ => Err(invalid_token(&format!("{name} is not a JSON object"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Token with the header `{"alg":"HS256","typ":"JWT"}` and the payload
+    /// `{"sub":"1234567890","name":"John Doe","iat":1516239022}`.
+    const TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+        eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+        SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+
+    fn new_filter(header: bool) -> Filter {
+        let header = header.then(|| Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        });
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JwtDecode { header },
+        }
+    }
+
+    #[test]
+    fn eval_filter_jwt_decode() {
+        let variables = VariableSet::new();
+        let filter = new_filter(false);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String(TOKEN.to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Object(vec![
+                (
+                    "iat".to_string(),
+                    Value::Number(Number::Integer(1516239022))
+                ),
+                ("name".to_string(), Value::String("John Doe".to_string())),
+                ("sub".to_string(), Value::String("1234567890".to_string())),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_jwt_decode_header() {
+        let variables = VariableSet::new();
+        let filter = new_filter(true);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String(TOKEN.to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Object(vec![
+                ("alg".to_string(), Value::String("HS256".to_string())),
+                ("typ".to_string(), Value::String("JWT".to_string())),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_jwt_decode_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(false);
+        let tokens = [
+            (
+                "abc.def",
+                "invalid JWT, expecting 3 parts separated by dots",
+            ),
+            (
+                "abc.d$f.ghi",
+                "invalid JWT, payload is not base64 URL safe encoded",
+            ),
+            // `WzEsMl0` is the encoding of `[1,2]`
+            (
+                "abc.WzEsMl0.ghi",
+                "invalid JWT, payload is not a JSON object",
+            ),
+        ];
+        for (token, message) in tokens {
+            let err = eval_filter(
+                &filter,
+                &Value::String(token.to_string()),
+                &variables,
+                false,
+            )
+            .err()
+            .unwrap();
+            assert_eq!(
+                err.kind,
+                RunnerErrorKind::FilterInvalidInput(message.to_string())
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::jsfilter::{call_jsfilter, with_element_index};
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Applies the JavaScript filter function `name` to each element of a list `value`, and returns
+/// the list of the results.
+pub fn eval_map(
+    value: &Value,
+    name: &Template,
+    variables: &VariableSet,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let name = eval_template(name, variables)?;
+
+    match value {
+        Value::List(values) => {
+            let mut mapped = Vec::with_capacity(values.len());
+            for (i, value) in values.iter().enumerate() {
+                let value = call_jsfilter(&name, value, &[], jsfilter_path, source_info, assert)
+                    .map_err(|e| with_element_index(e, i))?;
+                mapped.push(value);
+            }
+            Ok(Some(Value::List(mapped)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::filter::jsfilter::{drop_js_runtime, write_js_filters};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter `map name`.
+    fn new_map_filter(name: &str) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        Filter {
+            source_info,
+            value: FilterValue::Map {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info,
+                },
+                name: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: name.to_string(),
+                        source: name.to_source(),
+                    }],
+                    source_info,
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_map() {
+        let source = r#"
+            function filter_double(input) {
+                return input * 2;
+            }
+            function filter_inverse(input) {
+                if (input === 0) {
+                    throw new Error("division by zero");
+                }
+                return 1 / input;
+            }
+        "#;
+        let context = FilterContext::new(Some(write_js_filters(source)));
+        let variables = VariableSet::new();
+        let values = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(2)),
+            Value::Number(Number::Integer(3)),
+        ]);
+
+        let ret = eval_filter_with_context(
+            &new_map_filter("double"),
+            &values,
+            &variables,
+            &context,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::List(vec![
+                Value::Number(Number::Integer(2)),
+                Value::Number(Number::Integer(4)),
+                Value::Number(Number::Integer(6)),
+            ])
+        );
+
+        let values = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(0)),
+        ]);
+        let ret = eval_filter_with_context(
+            &new_map_filter("inverse"),
+            &values,
+            &variables,
+            &context,
+            false,
+        );
+        let RunnerErrorKind::JsFilterRuntimeError { message } = ret.unwrap_err().kind else {
+            panic!("expected a JavaScript runtime error");
+        };
+        assert!(message.starts_with("element at index 1: "));
+        assert!(message.contains("division by zero"));
+
+        let ret = eval_filter_with_context(
+            &new_map_filter("double"),
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            &context,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <1>".to_string())
+        );
+
+        drop_js_runtime();
+    }
+}
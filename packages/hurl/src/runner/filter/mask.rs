@@ -0,0 +1,189 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{IntegerValue, SourceInfo, Template};
+
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Masks a string `value`, keeping its first `start` and last `end` characters and replacing
+/// every other character by `mask` (`*` by default).
+///
+/// Strings that are not longer than the kept characters are fully masked.
+pub fn eval_mask(
+    value: &Value,
+    start: &IntegerValue,
+    end: &IntegerValue,
+    mask: Option<&Template>,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let start = eval_integer_value(start, variables)?;
+    let end = eval_integer_value(end, variables)?;
+    let mask = match mask {
+        Some(mask) => eval_template(mask, variables)?,
+        None => "*".to_string(),
+    };
+    for n in [start, end] {
+        if n < 0 {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!("length <{n}> is negative"));
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    }
+    let (start, end) = (start as usize, end as usize);
+
+    match value {
+        Value::String(s) => {
+            let count = s.chars().count();
+            let masked = if count <= start + end {
+                mask.repeat(count)
+            } else {
+                s.chars()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        if i < start || i >= count - end {
+                            c.to_string()
+                        } else {
+                            mask.clone()
+                        }
+                    })
+                    .collect()
+            };
+            Ok(Some(Value::String(masked)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{
+        Filter, FilterValue, IntegerValue, SourceInfo, Template, TemplateElement, Whitespace, I64,
+    };
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter given a `start`, `end` and an optional `mask`.
+    fn new_mask_filter(start: i64, end: i64, mask: Option<&str>) -> Filter {
+        // Example: mask 4 4 "#"
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Mask {
+                space0: whitespace.clone(),
+                start: Box::new(IntegerValue::Literal(I64::new(
+                    start,
+                    start.to_string().to_source(),
+                ))),
+                space1: whitespace.clone(),
+                end: Box::new(IntegerValue::Literal(I64::new(
+                    end,
+                    end.to_string().to_source(),
+                ))),
+                mask: mask.map(|mask| {
+                    let template = Template::new(
+                        None,
+                        vec![TemplateElement::String {
+                            value: mask.to_string(),
+                            source: mask.to_source(),
+                        }],
+                        SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    );
+                    (whitespace, template)
+                }),
+            },
+        }
+    }
+
+    fn mask(start: i64, end: i64, mask: Option<&str>, value: &str) -> Value {
+        let variables = VariableSet::new();
+        let filter = new_mask_filter(start, end, mask);
+        eval_filter(
+            &filter,
+            &Value::String(value.to_string()),
+            &variables,
+            false,
+        )
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn eval_filter_mask() {
+        assert_eq!(
+            mask(4, 4, None, "sk_live_1234567890abcd"),
+            Value::String("sk_l**************abcd".to_string())
+        );
+        assert_eq!(
+            mask(0, 4, None, "4111111111111111"),
+            Value::String("************1111".to_string())
+        );
+        assert_eq!(
+            mask(2, 0, None, "éàçùê"),
+            Value::String("éà***".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_mask_short_string() {
+        assert_eq!(
+            mask(4, 4, None, "12345678"),
+            Value::String("********".to_string())
+        );
+        assert_eq!(mask(4, 4, None, "abc"), Value::String("***".to_string()));
+        assert_eq!(mask(4, 4, None, ""), Value::String(String::new()));
+    }
+
+    #[test]
+    fn eval_filter_mask_custom_char() {
+        assert_eq!(
+            mask(2, 2, Some("#"), "secret-token"),
+            Value::String("se########en".to_string())
+        );
+        assert_eq!(
+            mask(1, 1, Some("•"), "hello"),
+            Value::String("h•••o".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_mask_error() {
+        let variables = VariableSet::new();
+        let filter = new_mask_filter(4, 4, None);
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1234)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <1234>".to_string())
+        );
+    }
+}
@@ -0,0 +1,255 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{Placeholder, SourceInfo};
+
+use crate::runner::{expr, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Merges shallowly the object placeholder `other` into the object `value`: the keys of `other`
+/// override the keys of `value`.
+pub fn eval_merge(
+    value: &Value,
+    other: &Placeholder,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_merge_objects(value, other, false, variables, source_info, assert)
+}
+
+/// Merges recursively the object placeholder `other` into the object `value`: nested objects
+/// present in both objects are merged, other keys of `other` override the keys of `value`.
+pub fn eval_merge_deep(
+    value: &Value,
+    other: &Placeholder,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_merge_objects(value, other, true, variables, source_info, assert)
+}
+
+/// Merges the object placeholder `other` into the object `value`, recursively if `deep` is true.
+fn eval_merge_objects(
+    value: &Value,
+    other: &Placeholder,
+    deep: bool,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::Object(fields) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    match expr::eval(&other.expr, variables)? {
+        Value::Object(other) => Ok(Some(Value::Object(merge(fields.clone(), other, deep)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                "merged value {} is not an object",
+                v.repr()
+            ));
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Merges the fields `other` into `fields`. Existing keys keep their position, new keys are
+/// appended. If `deep` is true, objects present in both fields are merged recursively.
+fn merge(
+    mut fields: Vec<(String, Value)>,
+    other: Vec<(String, Value)>,
+    deep: bool,
+) -> Vec<(String, Value)> {
+    for (key, value) in other {
+        match fields.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => {
+                *existing = match (existing.clone(), value) {
+                    (Value::Object(left), Value::Object(right)) if deep => {
+                        Value::Object(merge(left, right, deep))
+                    }
+                    (_, value) => value,
+                };
+            }
+            None => fields.push((key, value)),
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Placeholder, SourceInfo, Variable, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter `merge {{other}}` or `mergeDeep {{other}}`.
+    fn new_merge_filter(deep: bool) -> Filter {
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let other = Placeholder {
+            space0: whitespace.clone(),
+            expr: Expr {
+                kind: ExprKind::Variable(Variable {
+                    name: "other".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                }),
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            },
+            space1: whitespace.clone(),
+        };
+        let value = if deep {
+            FilterValue::MergeDeep {
+                space0: whitespace,
+                other,
+            }
+        } else {
+            FilterValue::Merge {
+                space0: whitespace,
+                other,
+            }
+        };
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value,
+        }
+    }
+
+    fn merge(value: &Value, other: &Value, deep: bool) -> Value {
+        let mut variables = VariableSet::new();
+        variables.insert("other".to_string(), other.clone());
+        let filter = new_merge_filter(deep);
+        eval_filter(&filter, value, &variables, false)
+            .unwrap()
+            .unwrap()
+    }
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn integer(n: i64) -> Value {
+        Value::Number(Number::Integer(n))
+    }
+
+    #[test]
+    fn eval_filter_merge_overlapping_keys() {
+        let value = object(&[("id", integer(1)), ("name", Value::Null)]);
+        let other = object(&[("name", Value::String("Bob".to_string()))]);
+        assert_eq!(
+            merge(&value, &other, false),
+            object(&[
+                ("id", integer(1)),
+                ("name", Value::String("Bob".to_string()))
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_merge_disjoint_keys() {
+        let value = object(&[("id", integer(1))]);
+        let other = object(&[("age", integer(30)), ("admin", Value::Bool(false))]);
+        assert_eq!(
+            merge(&value, &other, false),
+            object(&[
+                ("id", integer(1)),
+                ("age", integer(30)),
+                ("admin", Value::Bool(false))
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_merge_deep() {
+        let value = object(&[
+            ("id", integer(1)),
+            (
+                "address",
+                object(&[
+                    ("city", Value::String("Paris".to_string())),
+                    ("zip", integer(75001)),
+                ]),
+            ),
+            ("tags", Value::List(vec![integer(1)])),
+        ]);
+        let other = object(&[
+            ("address", object(&[("zip", integer(75002))])),
+            ("tags", Value::List(vec![integer(2)])),
+        ]);
+
+        // A shallow merge replaces the nested object...
+        assert_eq!(
+            merge(&value, &other, false),
+            object(&[
+                ("id", integer(1)),
+                ("address", object(&[("zip", integer(75002))])),
+                ("tags", Value::List(vec![integer(2)])),
+            ])
+        );
+        // ... while a deep merge merges it (lists are replaced).
+        assert_eq!(
+            merge(&value, &other, true),
+            object(&[
+                ("id", integer(1)),
+                (
+                    "address",
+                    object(&[
+                        ("city", Value::String("Paris".to_string())),
+                        ("zip", integer(75002))
+                    ]),
+                ),
+                ("tags", Value::List(vec![integer(2)])),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_merge_error() {
+        let mut variables = VariableSet::new();
+        variables.insert("other".to_string(), object(&[]));
+        let filter = new_merge_filter(false);
+        let err = eval_filter(&filter, &integer(1), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("integer <1>".to_string())
+        );
+
+        variables.insert("other".to_string(), Value::List(vec![]));
+        let err = eval_filter(&filter, &object(&[]), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "merged value list <[]> is not an object".to_string()
+            )
+        );
+    }
+}
@@ -16,41 +16,106 @@
  *
  */
 
-pub use eval::eval_filters_with_js;
+pub use context::FilterContext;
+pub use eval::eval_filters_with_context;
+#[cfg(test)]
+pub use jsfilter::write_js_filters;
+pub use jsfilter::{
+    check_js_filters, record_js_response, set_js_args_mode, set_js_config, set_js_date_objects,
+    set_js_filter_prefix, set_js_hurl_filename, set_js_shared_global, set_js_strict,
+    set_js_tuple_results, set_js_undefined_unchanged,
+};
 pub use jsonpath::eval_jsonpath_json;
 pub use xpath::eval_xpath_doc;
 
 mod add;
+mod arg;
 mod base64_decode;
 mod base64_encode;
 mod base64_url_safe_decode;
 mod base64_url_safe_encode;
+mod between;
+mod canonicalize;
+mod case;
+mod clamp_length;
+mod coalesce;
+mod context;
 mod count;
+mod date_period;
 mod days_after_now;
 mod days_before_now;
+mod debug;
 mod decode;
+mod deep_equal;
+mod drop_while;
+mod duration_format;
+mod ensure_prefix;
+mod ensure_suffix;
+mod entropy;
+mod escape_json;
 mod eval;
+mod expect;
+mod filter_list;
 mod first;
+mod form;
 mod format;
+mod format_bytes;
+mod group_by;
+mod hex_dump;
 mod html_escape;
 mod html_unescape;
+mod is_leap_year;
+mod jseval;
+mod json_patch;
 mod jsonpath;
 mod jsfilter;
+mod jsonpath_keys;
+mod jwt_decode;
 mod last;
 mod location;
+mod map;
+mod mask;
+mod merge;
+mod non_empty;
 mod nth;
+mod nth_from_end;
+mod number_format;
+mod parse_duration;
+mod paths;
+mod ratio;
+mod reduce;
 mod regex;
 mod replace;
 mod replace_regex;
+mod round_to;
+mod sample;
+mod shuffle;
+mod sign;
+mod sort_by;
 mod split;
+mod split_regex;
+mod sqrt;
+mod take_while;
+mod to_big_int;
 mod to_date;
 mod to_float;
 mod to_hex;
 mod to_int;
 mod to_string;
+mod to_timezone;
+mod to_utc;
+mod truncate;
+mod type_of;
+mod unescape_json;
 mod url_decode;
 mod url_encode;
 mod url_query_param;
 mod utf8_decode;
 mod utf8_encode;
+mod weekday;
+mod wrap;
 mod xpath;
+mod xpath_all;
+mod xpath_boolean;
+mod xpath_number;
+mod yaml;
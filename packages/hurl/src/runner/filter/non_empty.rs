@@ -0,0 +1,104 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Returns `value` unchanged if it's a non-empty string, list, object, bytes or nodeset, and
+/// fails otherwise.
+pub fn eval_non_empty(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let is_empty = match value {
+        Value::Bytes(v) => v.is_empty(),
+        Value::List(v) => v.is_empty(),
+        Value::Nodeset(size) => *size == 0,
+        Value::Null => true,
+        Value::Object(v) => v.is_empty(),
+        Value::String(v) => v.is_empty(),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    if is_empty {
+        let kind = RunnerErrorKind::FilterInvalidInput("value is empty".to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    Ok(Some(value.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_non_empty() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::NonEmpty,
+        };
+
+        let values = [
+            Value::String("foo".to_string()),
+            Value::List(vec![Value::Null]),
+            Value::Object(vec![("id".to_string(), Value::Number(Number::Integer(1)))]),
+        ];
+        for value in values {
+            assert_eq!(
+                eval_filter(&filter, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                value
+            );
+        }
+
+        let values = [
+            Value::String(String::new()),
+            Value::List(vec![]),
+            Value::Object(vec![]),
+            Value::Null,
+        ];
+        for value in values {
+            assert_eq!(
+                eval_filter(&filter, &value, &variables, false)
+                    .unwrap_err()
+                    .kind,
+                RunnerErrorKind::FilterInvalidInput("value is empty".to_string())
+            );
+        }
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <1>".to_string())
+        );
+    }
+}
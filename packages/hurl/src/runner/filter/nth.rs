@@ -60,7 +60,7 @@ fn try_nth<U>(items: &[U], index: i64) -> Result<&U, String> {
 }
 
 /// Evaluates an [`IntegerValue`] against a variable set.
-fn eval_integer_value(n: &IntegerValue, variables: &VariableSet) -> Result<i64, RunnerError> {
+pub fn eval_integer_value(n: &IntegerValue, variables: &VariableSet) -> Result<i64, RunnerError> {
     match n {
         IntegerValue::Literal(value) => Ok(value.as_i64()),
         IntegerValue::Placeholder(Placeholder { expr, .. }) => match expr::eval(expr, variables)? {
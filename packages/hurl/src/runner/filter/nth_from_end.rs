@@ -0,0 +1,111 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{IntegerValue, SourceInfo};
+
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns the element from a collection `value` at a zero-based index counted from the end of the
+/// collection: `0` is the last element.
+pub fn eval_nth_from_end(
+    value: &Value,
+    n: &IntegerValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let n = eval_integer_value(n, variables)?;
+
+    match value {
+        Value::List(values) => {
+            let len = values.len();
+            match usize::try_from(n) {
+                Ok(n) if n < len => Ok(Some(values[len - 1 - n].clone())),
+                _ => {
+                    let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                        "index <{n}> from end out of bound - size is {len}"
+                    ));
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, IntegerValue, SourceInfo, Whitespace, I64};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(n: i64) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::NthFromEnd {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                n: IntegerValue::Literal(I64::new(n, n.to_string().to_source())),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_nth_from_end() {
+        let variables = VariableSet::new();
+        let values = Value::List(vec![
+            Value::Number(Number::Integer(0)),
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(2)),
+            Value::Number(Number::Integer(3)),
+        ]);
+
+        let ret = eval_filter(&new_filter(0), &values, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(3)));
+
+        let ret = eval_filter(&new_filter(1), &values, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(2)));
+
+        let ret = eval_filter(&new_filter(3), &values, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(0)));
+
+        let ret = eval_filter(&new_filter(4), &values, &variables, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "index <4> from end out of bound - size is 4".to_string()
+            )
+        );
+
+        let ret = eval_filter(&new_filter(-1), &values, &variables, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "index <-1> from end out of bound - size is 4".to_string()
+            )
+        );
+    }
+}
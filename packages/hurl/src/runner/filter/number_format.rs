@@ -0,0 +1,269 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Formats a number `value` to a string given a decimal `format` pattern like `#,##0.00`.
+///
+/// In the integer part of the pattern, `0` is a mandatory digit, `#` an optional digit and `,`
+/// the grouping separator (the group size is the number of digits after the last `,`). In the
+/// fractional part, `0` is a mandatory digit and `#` an optional digit: the number is rounded to
+/// the total count of fractional digits.
+pub fn eval_number_format(
+    value: &Value,
+    format: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let format = eval_template(format, variables)?;
+    let Some(pattern) = NumberFormat::parse(&format) else {
+        let kind = RunnerErrorKind::FilterInvalidNumberFormat(format);
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+
+    let (negative, int_digits, frac_digits) = match value {
+        Value::Number(Number::Integer(v)) => (
+            *v < 0,
+            v.unsigned_abs().to_string(),
+            "0".repeat(pattern.max_frac),
+        ),
+        Value::Number(Number::Float(v)) if v.is_finite() => {
+            let s = format!("{:.*}", pattern.max_frac, v.abs());
+            let (int_digits, frac_digits) = s.split_once('.').unwrap_or((&s, ""));
+            (*v < 0.0, int_digits.to_string(), frac_digits.to_string())
+        }
+        Value::Number(Number::BigInteger(v)) => match v.strip_prefix('-') {
+            Some(v) => (true, v.to_string(), "0".repeat(pattern.max_frac)),
+            None => (false, v.to_string(), "0".repeat(pattern.max_frac)),
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let formatted = pattern.format(negative, &int_digits, &frac_digits);
+    Ok(Some(Value::String(formatted)))
+}
+
+/// A decimal format pattern, like `#,##0.00`.
+struct NumberFormat {
+    /// Size of the digit groups of the integer part, if grouping is used.
+    grouping: Option<usize>,
+    /// Minimum number of digits of the integer part.
+    min_int: usize,
+    /// Minimum number of digits of the fractional part.
+    min_frac: usize,
+    /// Maximum number of digits of the fractional part.
+    max_frac: usize,
+}
+
+impl NumberFormat {
+    /// Parses a decimal format `pattern`, returning `None` if the pattern is not valid.
+    fn parse(pattern: &str) -> Option<NumberFormat> {
+        let (int_part, frac_part) = pattern.split_once('.').unwrap_or((pattern, ""));
+        if int_part.is_empty() || !int_part.chars().all(|c| matches!(c, '#' | '0' | ',')) {
+            return None;
+        }
+        let grouping = match int_part.rfind(',') {
+            Some(index) => {
+                let size = int_part.len() - index - 1;
+                if size == 0 {
+                    return None;
+                }
+                Some(size)
+            }
+            None => None,
+        };
+        let min_int = int_part.chars().filter(|c| *c == '0').count();
+        let min_frac = frac_part.chars().take_while(|c| *c == '0').count();
+        if !frac_part[min_frac..].chars().all(|c| c == '#') {
+            return None;
+        }
+        Some(NumberFormat {
+            grouping,
+            min_int,
+            min_frac,
+            max_frac: frac_part.len(),
+        })
+    }
+
+    /// Formats a number given the digits of its absolute value, already rounded to the maximum
+    /// number of fractional digits.
+    fn format(&self, negative: bool, int_digits: &str, frac_digits: &str) -> String {
+        let mut frac = frac_digits.to_string();
+        while frac.len() > self.min_frac && frac.ends_with('0') {
+            frac.pop();
+        }
+        let mut int = int_digits.trim_start_matches('0').to_string();
+        if int.len() < self.min_int {
+            int = format!("{}{int}", "0".repeat(self.min_int - int.len()));
+        }
+        if int.is_empty() && frac.is_empty() {
+            int = "0".to_string();
+        }
+        let is_zero = int.chars().chain(frac.chars()).all(|c| c == '0');
+
+        let mut s = String::new();
+        if negative && !is_zero {
+            s.push('-');
+        }
+        for (i, c) in int.chars().enumerate() {
+            if let Some(size) = self.grouping {
+                if i > 0 && (int.len() - i).is_multiple_of(size) {
+                    s.push(',');
+                }
+            }
+            s.push(c);
+        }
+        if !frac.is_empty() {
+            s.push('.');
+            s.push_str(&frac);
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter given a `fmt`
+    fn new_number_format_filter(fmt: &str) -> Filter {
+        // Example: numberFormat "#,##0.00"
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::NumberFormat {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(13, 1), Pos::new(14, 1)),
+                },
+                fmt: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: fmt.to_string(),
+                        source: fmt.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(14, 1), Pos::new(14 + fmt.len(), 1)),
+                ),
+            },
+        }
+    }
+
+    fn format(fmt: &str, value: Value) -> String {
+        let variables = VariableSet::new();
+        let filter = new_number_format_filter(fmt);
+        match eval_filter(&filter, &value, &variables, false) {
+            Ok(Some(Value::String(s))) => s,
+            ret => panic!("unexpected result {ret:?}"),
+        }
+    }
+
+    #[test]
+    fn eval_filter_number_format_grouping() {
+        assert_eq!(
+            format("#,##0", Value::Number(Number::Integer(1234567))),
+            "1,234,567"
+        );
+        assert_eq!(format("#,##0", Value::Number(Number::Integer(123))), "123");
+        assert_eq!(format("#,##0", Value::Number(Number::Integer(0))), "0");
+        assert_eq!(
+            format("#,####", Value::Number(Number::Integer(123456789))),
+            "1,2345,6789"
+        );
+        assert_eq!(
+            format(
+                "#,##0",
+                Value::Number(Number::BigInteger("12345678901234567890".to_string()))
+            ),
+            "12,345,678,901,234,567,890"
+        );
+    }
+
+    #[test]
+    fn eval_filter_number_format_decimals() {
+        assert_eq!(
+            format("#,##0.00", Value::Number(Number::Float(1234.5))),
+            "1,234.50"
+        );
+        assert_eq!(
+            format("0.00", Value::Number(Number::Float(1.23456))),
+            "1.23"
+        );
+        assert_eq!(format("0.00", Value::Number(Number::Integer(3))), "3.00");
+        assert_eq!(format("0.##", Value::Number(Number::Float(2.5))), "2.5");
+        assert_eq!(format("0.##", Value::Number(Number::Float(2.0))), "2");
+        assert_eq!(format("#.00", Value::Number(Number::Float(0.5))), ".50");
+        assert_eq!(format("000", Value::Number(Number::Integer(7))), "007");
+    }
+
+    #[test]
+    fn eval_filter_number_format_negative() {
+        assert_eq!(
+            format("#,##0.00", Value::Number(Number::Float(-1234567.891))),
+            "-1,234,567.89"
+        );
+        assert_eq!(
+            format("#,##0", Value::Number(Number::Integer(-1000))),
+            "-1,000"
+        );
+        assert_eq!(
+            format(
+                "#,##0",
+                Value::Number(Number::BigInteger("-10000000000000000000".to_string()))
+            ),
+            "-10,000,000,000,000,000,000"
+        );
+        assert_eq!(format("0.0", Value::Number(Number::Float(-0.01))), "0.0");
+    }
+
+    #[test]
+    fn eval_filter_number_format_error() {
+        let variables = VariableSet::new();
+        let filter = new_number_format_filter("#,##0");
+        let ret = eval_filter(
+            &filter,
+            &Value::String("1234".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string <1234>".to_string())
+        );
+
+        let filter = new_number_format_filter("#,##0.0#0");
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidNumberFormat("#,##0.0#0".to_string())
+        );
+    }
+}
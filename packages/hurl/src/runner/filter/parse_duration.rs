@@ -0,0 +1,244 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::filter::duration_format::{
+    MS_PER_DAY, MS_PER_HOUR, MS_PER_MINUTE, MS_PER_SECOND,
+};
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Parses a human-readable duration (ex: `1h30m`) or an ISO 8601 duration (ex: `PT1H30M`) string
+/// `value` to a number of milliseconds.
+pub fn eval_parse_duration(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(s) => {
+            let ms = if let Some(iso) = s.strip_prefix('P') {
+                parse_iso_duration(iso)
+            } else {
+                parse_human_duration(s)
+            };
+            match ms.and_then(|ms| i64::try_from(ms).ok()) {
+                Some(ms) => Ok(Some(Value::Number(Number::Integer(ms)))),
+                None => {
+                    let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Parses a human-readable duration `s`, a sequence of integers followed by a unit (`d`, `h`,
+/// `m`, `s` or `ms`), optionally separated by spaces.
+fn parse_human_duration(s: &str) -> Option<u64> {
+    let mut chars = s.trim().chars().peekable();
+    let mut total = 0_u64;
+    let mut empty = true;
+    while chars.peek().is_some() {
+        let value = parse_integer(&mut chars)?;
+        let mut unit = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+            unit.push(c);
+        }
+        let factor = match unit.as_str() {
+            "d" => MS_PER_DAY,
+            "h" => MS_PER_HOUR,
+            "m" => MS_PER_MINUTE,
+            "s" => MS_PER_SECOND,
+            "ms" => 1,
+            _ => return None,
+        };
+        total = total.checked_add(value.checked_mul(factor)?)?;
+        empty = false;
+        while chars.next_if_eq(&' ').is_some() {}
+    }
+    if empty {
+        None
+    } else {
+        Some(total)
+    }
+}
+
+/// Parses an ISO 8601 duration `s`, without its leading `P`. Only weeks, days, hours, minutes
+/// and seconds (with an optional fraction) are supported, years and months having no fixed
+/// length.
+fn parse_iso_duration(s: &str) -> Option<u64> {
+    let (date, time) = match s.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return None;
+    }
+
+    let mut total = 0_u64;
+    let mut chars = date.chars().peekable();
+    while chars.peek().is_some() {
+        let value = parse_integer(&mut chars)?;
+        let factor = match chars.next()? {
+            'W' => 7 * MS_PER_DAY,
+            'D' => MS_PER_DAY,
+            _ => return None,
+        };
+        total = total.checked_add(value.checked_mul(factor)?)?;
+    }
+
+    let Some(time) = time else {
+        return Some(total);
+    };
+    let mut chars = time.chars().peekable();
+    while chars.peek().is_some() {
+        let value = parse_integer(&mut chars)?;
+        let ms = match chars.next()? {
+            'H' => value.checked_mul(MS_PER_HOUR)?,
+            'M' => value.checked_mul(MS_PER_MINUTE)?,
+            'S' => value.checked_mul(MS_PER_SECOND)?,
+            '.' | ',' => {
+                // Fraction of seconds, truncated to milliseconds.
+                let mut fraction = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                    fraction.push(c);
+                }
+                if fraction.is_empty() || chars.next() != Some('S') {
+                    return None;
+                }
+                let millis = format!("{fraction:0<3}")[..3].parse::<u64>().ok()?;
+                value.checked_mul(MS_PER_SECOND)?.checked_add(millis)?
+            }
+            _ => return None,
+        };
+        total = total.checked_add(ms)?;
+    }
+    Some(total)
+}
+
+/// Parses a non-empty sequence of ASCII digits from `chars`.
+fn parse_integer(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<u64> {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+        digits.push(c);
+    }
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_parse_duration_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::ParseDuration,
+        }
+    }
+
+    fn parse_duration(s: &str) -> Value {
+        let variables = VariableSet::new();
+        eval_filter(
+            &new_parse_duration_filter(),
+            &Value::String(s.to_string()),
+            &variables,
+            false,
+        )
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn eval_filter_parse_duration() {
+        let tests = [
+            ("500ms", 500),
+            ("0ms", 0),
+            ("1h30m", 5_400_000),
+            ("1h 2m 3s", 3_723_000),
+            ("1m 30s 250ms", 90_250),
+            ("2d", 172_800_000),
+        ];
+        for (s, expected) in tests {
+            assert_eq!(parse_duration(s), Value::Number(Number::Integer(expected)));
+        }
+    }
+
+    #[test]
+    fn eval_filter_parse_duration_iso() {
+        let tests = [
+            ("PT1H30M", 5_400_000),
+            ("PT0S", 0),
+            ("PT0.25S", 250),
+            ("PT1,5S", 1_500),
+            ("P1DT2H", 93_600_000),
+            ("P1W", 604_800_000),
+            ("P2D", 172_800_000),
+        ];
+        for (s, expected) in tests {
+            assert_eq!(parse_duration(s), Value::Number(Number::Integer(expected)));
+        }
+    }
+
+    #[test]
+    fn eval_filter_parse_duration_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = new_parse_duration_filter();
+
+        for s in [
+            "",
+            "1",
+            "h",
+            "1x",
+            "1h-30m",
+            "1.5h",
+            "P",
+            "PT",
+            "P1Y",
+            "P1M",
+            "PT1.S",
+            "PT1H2",
+            "99999999999999999999ms",
+        ] {
+            let ret = eval_filter(&filter, &Value::String(s.to_string()), &variables, false);
+            assert_eq!(
+                ret.unwrap_err().kind,
+                RunnerErrorKind::FilterInvalidInput(format!("string <{s}>"))
+            );
+        }
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1000)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer".to_string())
+        );
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Returns the JSONPath-like paths of the leaves of a collection `value` (an object or a list).
+pub fn eval_paths(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let leaves = eval_leaf_entries(value, source_info, assert)?;
+    let paths = leaves.into_iter().map(|(path, _)| Value::String(path));
+    Ok(Some(Value::List(paths.collect())))
+}
+
+/// Returns the leaf values of a collection `value` (an object or a list).
+pub fn eval_leaves(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let leaves = eval_leaf_entries(value, source_info, assert)?;
+    let values = leaves.into_iter().map(|(_, value)| value.clone());
+    Ok(Some(Value::List(values.collect())))
+}
+
+/// Walks the collection `value` depth-first and returns the path and the value of each leaf.
+fn eval_leaf_entries(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Vec<(String, &Value)>, RunnerError> {
+    match value {
+        Value::Object(_) | Value::List(_) => {
+            let mut leaves = vec![];
+            walk(value, "$".to_string(), &mut leaves);
+            Ok(leaves)
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Pushes the leaves of `value`, located at `path`, to `leaves`. Empty objects and lists are
+/// leaves.
+fn walk<'a>(value: &'a Value, path: String, leaves: &mut Vec<(String, &'a Value)>) {
+    match value {
+        Value::Object(fields) if !fields.is_empty() => {
+            for (key, value) in fields {
+                walk(value, format!("{path}{}", key_segment(key)), leaves);
+            }
+        }
+        Value::List(values) if !values.is_empty() => {
+            for (index, value) in values.iter().enumerate() {
+                walk(value, format!("{path}[{index}]"), leaves);
+            }
+        }
+        _ => leaves.push((path, value)),
+    }
+}
+
+/// Returns the path segment of an object `key`, using the dot notation if the key is a valid
+/// identifier, and the bracket notation otherwise.
+fn key_segment(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        format!(".{key}")
+    } else {
+        let key = key.replace('\\', "\\\\").replace('\'', "\\'");
+        format!("['{key}']")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(value: FilterValue) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value,
+        }
+    }
+
+    /// Returns `{"a": {"b": [1, {"c": true}], "d e": null, "f": []}, "g": "h"}`.
+    fn nested_object() -> Value {
+        Value::Object(vec![
+            (
+                "a".to_string(),
+                Value::Object(vec![
+                    (
+                        "b".to_string(),
+                        Value::List(vec![
+                            Value::Number(Number::Integer(1)),
+                            Value::Object(vec![("c".to_string(), Value::Bool(true))]),
+                        ]),
+                    ),
+                    ("d e".to_string(), Value::Null),
+                    ("f".to_string(), Value::List(vec![])),
+                ]),
+            ),
+            ("g".to_string(), Value::String("h".to_string())),
+        ])
+    }
+
+    #[test]
+    fn eval_filter_paths() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::Paths);
+        assert_eq!(
+            eval_filter(&filter, &nested_object(), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![
+                Value::String("$.a.b[0]".to_string()),
+                Value::String("$.a.b[1].c".to_string()),
+                Value::String("$.a['d e']".to_string()),
+                Value::String("$.a.f".to_string()),
+                Value::String("$.g".to_string()),
+            ])
+        );
+
+        let value = Value::List(vec![
+            Value::List(vec![Value::Number(Number::Integer(1))]),
+            Value::Object(vec![]),
+        ]);
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![
+                Value::String("$[0][0]".to_string()),
+                Value::String("$[1]".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_leaves() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::Leaves);
+        assert_eq!(
+            eval_filter(&filter, &nested_object(), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![
+                Value::Number(Number::Integer(1)),
+                Value::Bool(true),
+                Value::Null,
+                Value::List(vec![]),
+                Value::String("h".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn eval_filter_paths_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::Paths);
+        let err = eval_filter(&filter, &Value::String("{}".to_string()), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
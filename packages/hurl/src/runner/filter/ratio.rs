@@ -0,0 +1,207 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{NumberValue, SourceInfo};
+
+use crate::runner::filter::add::eval_number_value;
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns the number `value` as a fraction of `total`. Numeric strings are accepted.
+pub fn eval_ratio(
+    value: &Value,
+    total: &NumberValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let ratio = ratio(value, total, variables, source_info, assert)?;
+    Ok(Some(Value::Number(Number::Float(ratio))))
+}
+
+/// Returns the number `value` as a percentage of `total`. Numeric strings are accepted.
+pub fn eval_percent(
+    value: &Value,
+    total: &NumberValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let ratio = ratio(value, total, variables, source_info, assert)?;
+    Ok(Some(Value::Number(Number::Float(ratio * 100.0))))
+}
+
+/// Divides the number `value` by `total`.
+fn ratio(
+    value: &Value,
+    total: &NumberValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<f64, RunnerError> {
+    let total = eval_number_value(total, variables)?.to_f64();
+    let n = match value {
+        Value::Number(n) => n.to_f64(),
+        Value::String(s) => match s.parse::<f64>() {
+            Ok(f) => f,
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+                return Err(RunnerError::new(source_info, kind, assert));
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    if total == 0.0 {
+        let kind = RunnerErrorKind::FilterDivisionByZero;
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    Ok(n / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, NumberValue, Placeholder, SourceInfo, Variable,
+        Whitespace, I64,
+    };
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
+    fn total_literal(n: i64) -> NumberValue {
+        NumberValue::Literal(hurl_core::ast::Number::Integer(I64::new(
+            n,
+            n.to_string().to_source(),
+        )))
+    }
+
+    fn total_variable() -> NumberValue {
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        NumberValue::Placeholder(Placeholder {
+            space0: Whitespace {
+                value: String::new(),
+                source_info,
+            },
+            expr: Expr {
+                kind: ExprKind::Variable(Variable {
+                    name: "total".to_string(),
+                    source_info,
+                }),
+                source_info,
+            },
+            space1: Whitespace {
+                value: String::new(),
+                source_info,
+            },
+        })
+    }
+
+    fn new_filter(value: FilterValue) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value,
+        }
+    }
+
+    #[test]
+    fn eval_filter_ratio() {
+        let mut variables = VariableSet::new();
+        variables.insert("total".to_string(), Value::Number(Number::Integer(200)));
+        let filter = new_filter(FilterValue::Ratio {
+            space0: whitespace(),
+            total: total_variable(),
+        });
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(5)),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(0.025))
+        );
+        assert_eq!(
+            eval_filter(&filter, &Value::String("50".to_string()), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Float(0.25))
+        );
+    }
+
+    #[test]
+    fn eval_filter_percent() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::Percent {
+            space0: whitespace(),
+            total: total_literal(8),
+        });
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(2)),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(25.0))
+        );
+    }
+
+    #[test]
+    fn eval_filter_ratio_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::Ratio {
+            space0: whitespace(),
+            total: total_literal(0),
+        });
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(5)),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(err.kind, RunnerErrorKind::FilterDivisionByZero);
+
+        let filter = new_filter(FilterValue::Percent {
+            space0: whitespace(),
+            total: total_literal(10),
+        });
+        let err = eval_filter(&filter, &Value::Null, &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("null <null>".to_string())
+        );
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::arg::eval_arg;
+use crate::runner::filter::jsfilter::{call_jsfilter, with_element_index};
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Folds a list `value` with the JavaScript filter function `name`.
+///
+/// Starting from the evaluated `init` value, the function is called as `name(accumulator, element)`
+/// for each element of the list, its result being the accumulator of the next call. The final
+/// accumulator is returned.
+pub fn eval_reduce(
+    value: &Value,
+    name: &Template,
+    init: &Template,
+    variables: &VariableSet,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let name = eval_template(name, variables)?;
+    let init = eval_arg(init, variables)?;
+
+    match value {
+        Value::List(values) => {
+            let mut acc = init;
+            for (i, value) in values.iter().enumerate() {
+                let args = [value.clone()];
+                acc = call_jsfilter(&name, &acc, &args, jsfilter_path, source_info, assert)
+                    .map_err(|e| with_element_index(e, i))?;
+            }
+            Ok(Some(acc))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::filter::jsfilter::{drop_js_runtime, write_js_filters};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_template(delimiter: Option<char>, value: &str) -> Template {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        Template::new(
+            delimiter,
+            vec![TemplateElement::String {
+                value: value.to_string(),
+                source: value.to_source(),
+            }],
+            source_info,
+        )
+    }
+
+    /// Helper function to return a new filter `reduce name init`.
+    fn new_reduce_filter(name: &str, init: Template) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info,
+        };
+        Filter {
+            source_info,
+            value: FilterValue::Reduce {
+                space0: whitespace.clone(),
+                name: new_template(None, name),
+                space1: whitespace,
+                init,
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_reduce() {
+        let source = r#"
+            function filter_sum(acc, value) {
+                return acc + value;
+            }
+            function filter_divide(acc, value) {
+                if (value === 0) {
+                    throw new Error("division by zero");
+                }
+                return acc / value;
+            }
+        "#;
+        let context = FilterContext::new(Some(write_js_filters(source)));
+        let variables = VariableSet::new();
+
+        let values = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(2)),
+            Value::Number(Number::Integer(3)),
+        ]);
+        let filter = new_reduce_filter("sum", new_template(None, "10"));
+        let ret = eval_filter_with_context(&filter, &values, &variables, &context, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(16)));
+
+        let values = Value::List(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+            Value::String("c".to_string()),
+        ]);
+        let filter = new_reduce_filter("sum", new_template(Some('"'), ""));
+        let ret = eval_filter_with_context(&filter, &values, &variables, &context, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::String("abc".to_string()));
+
+        let filter = new_reduce_filter("sum", new_template(Some('"'), "init"));
+        let ret =
+            eval_filter_with_context(&filter, &Value::List(vec![]), &variables, &context, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::String("init".to_string()));
+
+        let values = Value::List(vec![
+            Value::Number(Number::Integer(2)),
+            Value::Number(Number::Integer(0)),
+        ]);
+        let filter = new_reduce_filter("divide", new_template(None, "8"));
+        let ret = eval_filter_with_context(&filter, &values, &variables, &context, false);
+        let RunnerErrorKind::JsFilterRuntimeError { message } = ret.unwrap_err().kind else {
+            panic!("expected a JavaScript runtime error");
+        };
+        assert!(message.starts_with("element at index 1: "));
+        assert!(message.contains("division by zero"));
+
+        drop_js_runtime();
+    }
+}
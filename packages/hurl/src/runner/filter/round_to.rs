@@ -0,0 +1,211 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{NumberValue, SourceInfo};
+
+use crate::runner::filter::add::eval_number_value;
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Rounds the number `value` to the nearest multiple of `step`, ties being rounded away from zero.
+/// Numeric strings are accepted.
+pub fn eval_round_to(
+    value: &Value,
+    step: &NumberValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let step = eval_number_value(step, variables)?;
+    if step.to_f64().is_nan() || step.to_f64() <= 0.0 {
+        let kind =
+            RunnerErrorKind::FilterInvalidInput(format!("step <{step}> must be strictly positive"));
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+
+    let n = match value {
+        Value::Number(n) => n.clone(),
+        Value::String(s) => match s.parse::<i64>() {
+            Ok(i) => Number::Integer(i),
+            Err(_) => match s.parse::<f64>() {
+                Ok(f) => Number::Float(f),
+                Err(_) => {
+                    let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+                    return Err(RunnerError::new(source_info, kind, assert));
+                }
+            },
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+
+    let rounded = match (&n, &step) {
+        (Number::Integer(n), Number::Integer(step)) => round_integer(*n, *step),
+        _ => None,
+    };
+    let rounded = match rounded {
+        Some(rounded) => Number::Integer(rounded),
+        None => {
+            let step = step.to_f64();
+            Number::Float((n.to_f64() / step).round() * step)
+        }
+    };
+    Ok(Some(Value::Number(rounded)))
+}
+
+/// Rounds the integer `n` to the nearest multiple of the positive integer `step`, returning `None`
+/// on overflow.
+fn round_integer(n: i64, step: i64) -> Option<i64> {
+    let (n, step) = (i128::from(n), i128::from(step));
+    let remainder = n % step;
+    let rounded = if 2 * remainder.abs() >= step {
+        n - remainder + remainder.signum() * step
+    } else {
+        n - remainder
+    };
+    i64::try_from(rounded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, NumberValue, SourceInfo, Whitespace, I64};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(step: hurl_core::ast::Number) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::RoundTo {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                step: NumberValue::Literal(step),
+            },
+        }
+    }
+
+    fn integer(n: i64) -> hurl_core::ast::Number {
+        hurl_core::ast::Number::Integer(I64::new(n, n.to_string().to_source()))
+    }
+
+    #[test]
+    fn eval_filter_round_to_integer() {
+        let variables = VariableSet::new();
+        let filter = new_filter(integer(50));
+        let values = [
+            (127, 150),
+            (124, 100),
+            (125, 150),
+            (-125, -150),
+            (-124, -100),
+            (0, 0),
+        ];
+        for (value, expected) in values {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::Number(Number::Integer(value)),
+                    &variables,
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::Number(Number::Integer(expected)),
+                "{value}"
+            );
+        }
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("127".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(150))
+        );
+    }
+
+    #[test]
+    fn eval_filter_round_to_float() {
+        let variables = VariableSet::new();
+        let step =
+            hurl_core::ast::Number::Float(hurl_core::ast::Float::new(0.25, "0.25".to_source()));
+        let filter = new_filter(step);
+        let values = [
+            (Value::Number(Number::Float(1.1)), 1.0),
+            (Value::Number(Number::Float(1.125)), 1.25),
+            (Value::Number(Number::Integer(3)), 3.0),
+            (Value::String("-0.9".to_string()), -1.0),
+        ];
+        for (value, expected) in values {
+            assert_eq!(
+                eval_filter(&filter, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::Number(Number::Float(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_round_to_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(integer(0));
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(127)),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("step <0> must be strictly positive".to_string())
+        );
+
+        let filter = new_filter(integer(-5));
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(127)),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("step <-5> must be strictly positive".to_string())
+        );
+
+        let filter = new_filter(integer(50));
+        let err = eval_filter(&filter, &Value::Bool(true), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean <true>".to_string())
+        );
+    }
+}
@@ -0,0 +1,189 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{IntegerValue, SourceInfo};
+use rand::rngs::StdRng;
+use rand::seq::{index, IndexedRandom};
+
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns a random item of a list `value`, or `null` if the list is empty.
+///
+/// If a count `n` is given, returns instead a list of up to `n` distinct items picked randomly
+/// from the list. Items are picked with the random generator `rng`.
+pub fn eval_sample(
+    value: &Value,
+    n: Option<&IntegerValue>,
+    variables: &VariableSet,
+    rng: &mut StdRng,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let n = match n {
+        Some(n) => {
+            let n = eval_integer_value(n, variables)?;
+            if n < 0 {
+                let kind = RunnerErrorKind::FilterInvalidInput(format!("count <{n}> is negative"));
+                return Err(RunnerError::new(source_info, kind, assert));
+            }
+            Some(n as usize)
+        }
+        None => None,
+    };
+    let Value::List(values) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let sample = match n {
+        Some(n) => {
+            let n = n.min(values.len());
+            let indices = index::sample(rng, values.len(), n);
+            Value::List(indices.iter().map(|i| values[i].clone()).collect())
+        }
+        None => values.choose(rng).cloned().unwrap_or(Value::Null),
+    };
+    Ok(Some(sample))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, IntegerValue, SourceInfo, Whitespace, I64};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use super::*;
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::{eval_filter, eval_filter_with_context};
+    use crate::runner::{Number, VariableSet};
+
+    fn new_sample_filter(n: Option<i64>) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: FilterValue::Sample {
+                n: n.map(|n| {
+                    (
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                        },
+                        IntegerValue::Literal(I64::new(n, n.to_string().to_source())),
+                    )
+                }),
+            },
+        }
+    }
+
+    fn numbers(range: std::ops::Range<i64>) -> Value {
+        Value::List(range.map(|i| Value::Number(Number::Integer(i))).collect())
+    }
+
+    fn sorted(value: Value) -> Vec<Value> {
+        let Value::List(mut items) = value else {
+            panic!("expecting a list");
+        };
+        items.sort_by_key(|item| match item {
+            Value::Number(Number::Integer(i)) => *i,
+            _ => unreachable!(),
+        });
+        items
+    }
+
+    #[test]
+    fn eval_filter_sample_seeded() {
+        let variables = VariableSet::new();
+        let values = numbers(0..100);
+        let filter = new_sample_filter(None);
+
+        let run = |context: FilterContext| {
+            (0..5)
+                .map(|_| {
+                    eval_filter_with_context(&filter, &values, &variables, &context, false)
+                        .unwrap()
+                        .unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+        let first_run = run(FilterContext::default().with_seed(Some(42)));
+        let second_run = run(FilterContext::default().with_seed(Some(42)));
+        assert_eq!(first_run, second_run);
+        let Value::List(values) = values else {
+            unreachable!()
+        };
+        assert!(first_run.iter().all(|item| values.contains(item)));
+    }
+
+    #[test]
+    fn eval_filter_sample_n() {
+        let variables = VariableSet::new();
+        let values = numbers(0..10);
+        let filter = new_sample_filter(Some(4));
+
+        let run = |context: FilterContext| {
+            eval_filter_with_context(&filter, &values, &variables, &context, false)
+                .unwrap()
+                .unwrap()
+        };
+        let first_run = run(FilterContext::default().with_seed(Some(7)));
+        let second_run = run(FilterContext::default().with_seed(Some(7)));
+        assert_eq!(first_run, second_run);
+
+        let mut items = sorted(first_run);
+        assert_eq!(items.len(), 4);
+        items.dedup();
+        assert_eq!(items.len(), 4);
+
+        // The sample can't be larger than the list, all items are returned.
+        let ret = eval_filter(&new_sample_filter(Some(20)), &values, &variables, false);
+        let items = sorted(ret.unwrap().unwrap());
+        assert_eq!(Value::List(items), values);
+
+        let ret = eval_filter(&new_sample_filter(Some(-1)), &values, &variables, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("count <-1> is negative".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_sample_empty_list() {
+        let variables = VariableSet::new();
+        let values = Value::List(vec![]);
+
+        let ret = eval_filter(&new_sample_filter(None), &values, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::Null);
+
+        let ret = eval_filter(&new_sample_filter(Some(2)), &values, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::List(vec![]));
+    }
+
+    #[test]
+    fn eval_filter_sample_ko_invalid_input() {
+        let variables = VariableSet::new();
+
+        let ret = eval_filter(
+            &new_sample_filter(None),
+            &Value::Bool(true),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
@@ -0,0 +1,120 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Returns a copy of a list `value` with its items in random order, given by the random generator
+/// `rng`.
+pub fn eval_shuffle(
+    value: &Value,
+    rng: &mut StdRng,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::List(values) => {
+            let mut values = values.clone();
+            values.shuffle(rng);
+            Ok(Some(Value::List(values)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::{eval_filter, eval_filter_with_context};
+    use crate::runner::{Number, VariableSet};
+
+    fn new_shuffle_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+            value: FilterValue::Shuffle,
+        }
+    }
+
+    fn integers(value: Value) -> Vec<i64> {
+        let Value::List(items) = value else {
+            panic!("expecting a list");
+        };
+        items
+            .iter()
+            .map(|item| match item {
+                Value::Number(Number::Integer(i)) => *i,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn eval_filter_shuffle() {
+        let variables = VariableSet::new();
+        let filter = new_shuffle_filter();
+        let values = Value::List(
+            [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5]
+                .iter()
+                .map(|i| Value::Number(Number::Integer(*i)))
+                .collect(),
+        );
+
+        let run = |context: FilterContext| {
+            eval_filter_with_context(&filter, &values, &variables, &context, false)
+                .unwrap()
+                .unwrap()
+        };
+        let first_run = run(FilterContext::default().with_seed(Some(42)));
+        let second_run = run(FilterContext::default().with_seed(Some(42)));
+        assert_eq!(first_run, second_run);
+
+        let mut shuffled = integers(first_run);
+        let mut expected = integers(values);
+        shuffled.sort();
+        expected.sort();
+        assert_eq!(shuffled, expected);
+
+        let ret = eval_filter(&filter, &Value::List(vec![]), &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::List(vec![]));
+    }
+
+    #[test]
+    fn eval_filter_shuffle_ko_invalid_input() {
+        let variables = VariableSet::new();
+
+        let ret = eval_filter(
+            &new_shuffle_filter(),
+            &Value::String("abc".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns the sign of `value`: -1 for a negative number, 0 for zero and 1 for a positive number.
+/// Numeric strings are accepted.
+pub fn eval_sign(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let sign = match value {
+        Value::Number(Number::Integer(v)) => Some(v.signum()),
+        Value::Number(Number::Float(v)) => float_sign(*v),
+        Value::Number(Number::BigInteger(v)) => Some(if v.starts_with('-') { -1 } else { 1 }),
+        Value::String(v) => match v.parse::<i64>() {
+            Ok(i) => Some(i.signum()),
+            Err(_) => v.parse::<f64>().ok().and_then(float_sign),
+        },
+        _ => None,
+    };
+    match sign {
+        Some(sign) => Ok(Some(Value::Number(Number::Integer(sign)))),
+        None => {
+            let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the sign of a float, or `None` if `value` is NaN.
+fn float_sign(value: f64) -> Option<i64> {
+    if value > 0.0 {
+        Some(1)
+    } else if value < 0.0 {
+        Some(-1)
+    } else if value == 0.0 {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn sign_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Sign,
+        }
+    }
+
+    #[test]
+    fn eval_filter_sign() {
+        let variables = VariableSet::new();
+        let filter = sign_filter();
+        let values = [
+            (Value::Number(Number::Integer(-42)), -1),
+            (Value::Number(Number::Integer(0)), 0),
+            (Value::Number(Number::Integer(7)), 1),
+            (Value::Number(Number::Float(-0.5)), -1),
+            (Value::Number(Number::Float(0.0)), 0),
+            (
+                Value::Number(Number::BigInteger("-100000000000000000000".to_string())),
+                -1,
+            ),
+            (Value::String("-3".to_string()), -1),
+            (Value::String("2.5".to_string()), 1),
+        ];
+        for (value, expected) in values {
+            assert_eq!(
+                eval_filter(&filter, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::Number(Number::Integer(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_sign_error() {
+        let variables = VariableSet::new();
+        let filter = sign_filter();
+        let err = eval_filter(
+            &filter,
+            &Value::String("abc".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("string <abc>".to_string())
+        );
+        let err = eval_filter(&filter, &Value::Bool(true), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean <true>".to_string())
+        );
+    }
+}
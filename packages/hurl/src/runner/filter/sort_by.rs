@@ -0,0 +1,175 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::jsfilter::{call_jsfilter, with_element_index};
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Sorts a list `value` by the keys computed by the JavaScript filter function `name` for each
+/// element.
+///
+/// Keys must be all numbers or all strings. The sort is stable: elements with equal keys keep
+/// their original order.
+pub fn eval_sort_by(
+    value: &Value,
+    name: &Template,
+    variables: &VariableSet,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let name = eval_template(name, variables)?;
+
+    match value {
+        Value::List(values) => {
+            let mut keys = Vec::with_capacity(values.len());
+            for (i, value) in values.iter().enumerate() {
+                let key = call_jsfilter(&name, value, &[], jsfilter_path, source_info, assert)
+                    .map_err(|e| with_element_index(e, i))?;
+                let valid = matches!(
+                    (&key, keys.first()),
+                    (Value::Number(_), None | Some(Value::Number(_)))
+                        | (Value::String(_), None | Some(Value::String(_)))
+                );
+                if !valid {
+                    let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                        "sort key {} of element at index {i} is not comparable",
+                        key.repr()
+                    ));
+                    return Err(RunnerError::new(source_info, kind, assert));
+                }
+                keys.push(key);
+            }
+            let mut sorted = values.iter().zip(keys).collect::<Vec<_>>();
+            sorted.sort_by(|(_, key1), (_, key2)| compare_keys(key1, key2));
+            let sorted = sorted.into_iter().map(|(value, _)| value.clone()).collect();
+            Ok(Some(Value::List(sorted)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Compares two sort keys, both numbers or both strings.
+fn compare_keys(key1: &Value, key2: &Value) -> Ordering {
+    match (key1, key2) {
+        (Value::Number(n1), Value::Number(n2)) => n1.cmp_value(n2),
+        (Value::String(s1), Value::String(s2)) => s1.cmp(s2),
+        _ => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::filter::jsfilter::{drop_js_runtime, write_js_filters};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter `sortBy name`.
+    fn new_sort_by_filter(name: &str) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        Filter {
+            source_info,
+            value: FilterValue::SortBy {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info,
+                },
+                name: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: name.to_string(),
+                        source: name.to_source(),
+                    }],
+                    source_info,
+                ),
+            },
+        }
+    }
+
+    fn person(name: &str, age: i64) -> Value {
+        Value::Object(vec![
+            ("name".to_string(), Value::String(name.to_string())),
+            ("age".to_string(), Value::Number(Number::Integer(age))),
+        ])
+    }
+
+    #[test]
+    fn eval_filter_sort_by() {
+        let source = r#"
+            function filter_age(input) { return input.age; }
+            function filter_name(input) { return input.name; }
+            function filter_ageOrName(input) { return input.age > 30 ? input.age : input.name; }
+        "#;
+        let context = FilterContext::new(Some(write_js_filters(source)));
+        let variables = VariableSet::new();
+        let people = Value::List(vec![
+            person("Carol", 35),
+            person("Alice", 28),
+            person("Bob", 35),
+            person("Dave", 19),
+        ]);
+
+        // Bob and Carol have the same age and keep their order.
+        let filter = new_sort_by_filter("age");
+        let ret = eval_filter_with_context(&filter, &people, &variables, &context, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::List(vec![
+                person("Dave", 19),
+                person("Alice", 28),
+                person("Carol", 35),
+                person("Bob", 35),
+            ])
+        );
+
+        let filter = new_sort_by_filter("name");
+        let ret = eval_filter_with_context(&filter, &people, &variables, &context, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::List(vec![
+                person("Alice", 28),
+                person("Bob", 35),
+                person("Carol", 35),
+                person("Dave", 19),
+            ])
+        );
+
+        let filter = new_sort_by_filter("ageOrName");
+        let ret = eval_filter_with_context(&filter, &people, &variables, &context, false);
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "sort key string <Alice> of element at index 1 is not comparable".to_string()
+            )
+        );
+
+        drop_js_runtime();
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{RegexValue, SourceInfo};
+
+use crate::runner::regex::eval_regex_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Splits the string `value` to a list of strings around matches of the regex `pattern`.
+/// If `trim` is set, empty strings are removed from the list.
+pub fn eval_split_regex(
+    value: &Value,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+    pattern: &RegexValue,
+    trim: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(s) => {
+            let re = eval_regex_value(pattern, variables)?;
+            let values = re
+                .split(s)
+                .filter(|v| !trim || !v.is_empty())
+                .map(|v| Value::String(v.to_string()))
+                .collect();
+            Ok(Some(Value::List(values)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, Regex, RegexValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
+    fn new_filter(pattern: &str, trim: bool) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::SplitRegex {
+                space0: whitespace(),
+                pattern: RegexValue::Regex(Regex {
+                    inner: regex::Regex::new(pattern).unwrap(),
+                    source: format!("/{pattern}/").to_source(),
+                }),
+                trim: trim.then(whitespace),
+            },
+        }
+    }
+
+    fn strings(values: &[&str]) -> Value {
+        Value::List(
+            values
+                .iter()
+                .map(|v| Value::String(v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn eval_filter_split_regex() {
+        let variables = VariableSet::new();
+
+        let filter = new_filter(r"\s+", false);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("a b\t\tc  d".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            strings(&["a", "b", "c", "d"])
+        );
+
+        let filter = new_filter("[,;]", false);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("a,b;c,,d".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            strings(&["a", "b", "c", "", "d"])
+        );
+    }
+
+    #[test]
+    fn eval_filter_split_regex_leading_trailing() {
+        let variables = VariableSet::new();
+        let value = Value::String(";a,,b;".to_string());
+
+        let filter = new_filter("[,;]", false);
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false)
+                .unwrap()
+                .unwrap(),
+            strings(&["", "a", "", "b", ""])
+        );
+
+        let filter = new_filter("[,;]", true);
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false)
+                .unwrap()
+                .unwrap(),
+            strings(&["a", "b"])
+        );
+    }
+
+    #[test]
+    fn eval_filter_split_regex_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(r"\s+", false);
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("integer <1>".to_string())
+        );
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns the square root of `value` as a float number. Negative numbers are rejected.
+pub fn eval_sqrt(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let n = match value {
+        Value::Number(Number::Integer(v)) => *v as f64,
+        Value::Number(Number::Float(v)) => *v,
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    if n < 0.0 {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!(
+            "{} is negative, square root is not defined",
+            value.repr()
+        ));
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    Ok(Some(Value::Number(Number::Float(n.sqrt()))))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn sqrt_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Sqrt,
+        }
+    }
+
+    #[test]
+    fn eval_filter_sqrt() {
+        let variables = VariableSet::new();
+        let filter = sqrt_filter();
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(16)),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(4.0))
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Float(2.0)),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(std::f64::consts::SQRT_2))
+        );
+    }
+
+    #[test]
+    fn eval_filter_sqrt_error() {
+        let variables = VariableSet::new();
+        let filter = sqrt_filter();
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(-4)),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "integer <-4> is negative, square root is not defined".to_string()
+            )
+        );
+        let err = eval_filter(&filter, &Value::String("16".to_string()), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("string <16>".to_string())
+        );
+    }
+}
@@ -0,0 +1,141 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::jsfilter::call_js_predicate;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns the leading elements of a list `value` for which the JavaScript filter function `name`
+/// returns a truthy value, stopping at the first element for which it returns a falsy value.
+pub fn eval_take_while(
+    value: &Value,
+    name: &Template,
+    variables: &VariableSet,
+    jsfilter_path: &Option<PathBuf>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let name = eval_template(name, variables)?;
+
+    match value {
+        Value::List(values) => {
+            let mut taken = vec![];
+            for value in values {
+                if !call_js_predicate(&name, value, jsfilter_path, source_info, assert)? {
+                    break;
+                }
+                taken.push(value.clone());
+            }
+            Ok(Some(Value::List(taken)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::context::FilterContext;
+    use crate::runner::filter::eval::eval_filter_with_context;
+    use crate::runner::filter::jsfilter::{drop_js_runtime, write_js_filters};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter `takeWhile name`.
+    fn new_take_while_filter(name: &str) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        Filter {
+            source_info,
+            value: FilterValue::TakeWhile {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info,
+                },
+                name: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: name.to_string(),
+                        source: name.to_source(),
+                    }],
+                    source_info,
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_take_while() {
+        let context = FilterContext::new(Some(write_js_filters(
+            "function filter_isSmall(input) { return input < 3; }",
+        )));
+        let variables = VariableSet::new();
+        let filter = new_take_while_filter("isSmall");
+        let values = [1, 2, 3, 1]
+            .iter()
+            .map(|n| Value::Number(Number::Integer(*n)))
+            .collect::<Vec<_>>();
+
+        let ret =
+            eval_filter_with_context(&filter, &Value::List(values), &variables, &context, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::List(vec![
+                Value::Number(Number::Integer(1)),
+                Value::Number(Number::Integer(2)),
+            ])
+        );
+
+        let ret =
+            eval_filter_with_context(&filter, &Value::List(vec![]), &variables, &context, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::List(vec![]));
+
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::String("abc".to_string()),
+            &variables,
+            &context,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string <abc>".to_string())
+        );
+
+        let ret = eval_filter_with_context(
+            &filter,
+            &Value::List(vec![Value::Number(Number::Integer(1))]),
+            &variables,
+            &FilterContext::default(),
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::JsFilterNotConfigured
+        );
+
+        drop_js_runtime();
+    }
+}
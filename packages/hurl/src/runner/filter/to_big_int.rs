@@ -0,0 +1,154 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use num_bigint::BigInt;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Converts `value` to an arbitrary-precision integer.
+pub fn eval_to_big_int(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Number(Number::Integer(v)) => {
+            Ok(Some(Value::Number(Number::BigInteger(v.to_string()))))
+        }
+        Value::Number(Number::BigInteger(v)) => match parse_big_int(v) {
+            Some(i) => Ok(Some(Value::Number(Number::BigInteger(i.to_string())))),
+            None => {
+                let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        Value::String(v) => match parse_big_int(v) {
+            Some(i) => Ok(Some(Value::Number(Number::BigInteger(i.to_string())))),
+            None => {
+                let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Parses a string of digits `s`, with an optional leading `-`, to a [`BigInt`].
+fn parse_big_int(s: &str) -> Option<BigInt> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_to_big_int_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::ToBigInt,
+        }
+    }
+
+    #[test]
+    fn eval_filter_to_big_int() {
+        let variables = VariableSet::new();
+        let filter = new_to_big_int_filter();
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("9223372036854775808".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger("9223372036854775808".to_string()))
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("-123456789012345678901234567890".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger(
+                "-123456789012345678901234567890".to_string()
+            ))
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("00042".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger("42".to_string()))
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(-7)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Number(Number::BigInteger("-7".to_string()))
+        );
+    }
+
+    #[test]
+    fn eval_filter_to_big_int_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = new_to_big_int_filter();
+
+        for s in ["12a3", "", "-", "1.5", "1_000", " 12"] {
+            let ret = eval_filter(&filter, &Value::String(s.to_string()), &variables, false);
+            assert_eq!(
+                ret.unwrap_err().kind,
+                RunnerErrorKind::FilterInvalidInput(format!("string <{s}>"))
+            );
+        }
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Float(1.5)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("float <1.5>".to_string())
+        );
+    }
+}
@@ -15,7 +15,8 @@
  * limitations under the License.
  *
  */
-use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use chrono_tz::UTC;
 use hurl_core::ast::{SourceInfo, Template};
 
 use crate::runner::template::eval_template;
@@ -41,20 +42,16 @@ pub fn eval_to_date(
             // format to the information-less format: date + time + timezone, date + time and finally
             // date.
             if let Ok(dt) = DateTime::parse_from_str(v, format.as_str()) {
-                return Ok(Some(Value::Date(dt.with_timezone(&Utc))));
+                return Ok(Some(Value::Date(dt.with_timezone(&UTC))));
             }
 
             if let Ok(dt) = NaiveDateTime::parse_from_str(v, format.as_str()) {
-                return Ok(Some(Value::Date(
-                    DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc),
-                )));
+                return Ok(Some(Value::Date(dt.and_utc().with_timezone(&UTC))));
             }
 
             if let Ok(date) = NaiveDate::parse_from_str(v, format.as_str()) {
                 let dt = date.and_hms_opt(0, 0, 0).unwrap();
-                return Ok(Some(Value::Date(
-                    DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc),
-                )));
+                return Ok(Some(Value::Date(dt.and_utc().with_timezone(&UTC))));
             }
 
             let kind = RunnerErrorKind::FilterDateParsingError {
@@ -72,7 +69,8 @@ pub fn eval_to_date(
 
 #[cfg(test)]
 mod tests {
-    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+    use chrono_tz::UTC;
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
@@ -105,7 +103,7 @@ mod tests {
         let datetime_utc =
             DateTime::parse_from_str("Thu Aug 27 09:07:46 2020 +0200", "%a %b %d %H:%M:%S %Y %z")
                 .unwrap()
-                .with_timezone(&Utc);
+                .with_timezone(&UTC);
 
         let ret = eval_filter(
             &filter,
@@ -141,7 +139,8 @@ mod tests {
         let datetime_utc =
             NaiveDateTime::parse_from_str("2020-08-27 09:07:46", "%Y-%m-%d %H:%M:%S")
                 .unwrap()
-                .and_utc();
+                .and_utc()
+                .with_timezone(&UTC);
 
         let ret = eval_filter(
             &filter,
@@ -174,10 +173,11 @@ mod tests {
             },
         };
 
-        let datetime_utc: DateTime<Utc> = NaiveDate::parse_from_str("2020-08-27", "%Y-%m-%d")
+        let datetime_utc = NaiveDate::parse_from_str("2020-08-27", "%Y-%m-%d")
             .unwrap()
             .and_time(NaiveTime::MIN)
-            .and_utc();
+            .and_utc()
+            .with_timezone(&UTC);
 
         let ret = eval_filter(
             &filter,
@@ -15,18 +15,31 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::SourceInfo;
+use std::fmt::Write;
 
-use crate::runner::{RunnerError, RunnerErrorKind, Value};
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Converts `value` to an string.
 ///
+/// Without `format`, the value is rendered as is. With a `format`, numbers are formatted with
+/// a printf-like specification (`%.2f`, `%d`) and dates with a strftime specification
+/// (`%Y-%m-%d`), see <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>.
+///
 /// return a RunnerError if the value is not renderable
 pub fn eval_to_string(
     value: &Value,
+    format: Option<&Template>,
+    variables: &VariableSet,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
+    if let Some(format) = format {
+        let format = eval_template(format, variables)?;
+        return format_value(value, format, source_info, assert).map(Some);
+    }
     match value.render() {
         Some(value) => Ok(Some(Value::String(value))),
         None => {
@@ -39,10 +52,105 @@ pub fn eval_to_string(
     }
 }
 
+/// Formats a number or a date `value` with a `format` specification.
+fn format_value(
+    value: &Value,
+    format: String,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Value, RunnerError> {
+    match value {
+        Value::Date(date) => {
+            let mut formatted = String::new();
+            match write!(formatted, "{}", date.format(format.as_str())) {
+                Ok(_) => Ok(Value::String(formatted)),
+                Err(_) => {
+                    let kind = RunnerErrorKind::FilterInvalidFormatSpecifier(format);
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        Value::Number(number) => match format_number(number, &format) {
+            Some(formatted) => Ok(Value::String(formatted)),
+            None => {
+                let kind = RunnerErrorKind::FilterInvalidNumberFormat(format);
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                "{} can not be formatted with <{format}>",
+                v.repr()
+            ));
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Formats a `number` with a printf-like `format`, returning `None` if the format is not valid.
+///
+/// The format must contain exactly one conversion, `%d` (integer) or `%f` (fixed-point, with an
+/// optional precision as in `%.2f`, 6 by default), and literal text where `%` is escaped as `%%`.
+/// Fixed-point never uses the scientific notation.
+fn format_number(number: &Number, format: &str) -> Option<String> {
+    let mut formatted = String::new();
+    let mut converted = false;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            formatted.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            formatted.push('%');
+            continue;
+        }
+        if converted {
+            return None;
+        }
+        converted = true;
+        let mut precision = None;
+        if chars.peek() == Some(&'.') {
+            chars.next();
+            let mut digits = String::new();
+            while let Some(d) = chars.next_if(char::is_ascii_digit) {
+                digits.push(d);
+            }
+            precision = Some(digits.parse::<usize>().ok()?);
+        }
+        match (chars.next()?, precision) {
+            ('d', None) => match number {
+                Number::Integer(i) => write!(formatted, "{i}").ok()?,
+                Number::Float(f) => write!(formatted, "{f:.0}").ok()?,
+                Number::BigInteger(s) => formatted.push_str(s),
+            },
+            ('f', precision) => {
+                let precision = precision.unwrap_or(6);
+                let f = match number {
+                    Number::Integer(i) => *i as f64,
+                    Number::Float(f) => *f,
+                    Number::BigInteger(s) => s.parse::<f64>().ok()?,
+                };
+                write!(formatted, "{f:.precision$}").ok()?;
+            }
+            _ => return None,
+        }
+    }
+    if converted {
+        Some(formatted)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use chrono::TimeZone;
+    use chrono_tz::UTC;
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
 
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
@@ -52,7 +160,7 @@ mod tests {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
-            value: FilterValue::ToString,
+            value: FilterValue::ToString { fmt: None },
         };
         assert_eq!(
             eval_filter(
@@ -72,7 +180,7 @@ mod tests {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
-            value: FilterValue::ToString,
+            value: FilterValue::ToString { fmt: None },
         };
         let err = eval_filter(&filter, &Value::List(vec![]), &variables, false)
             .err()
@@ -84,4 +192,97 @@ mod tests {
             )
         );
     }
+
+    fn new_filter(fmt: &str) -> Filter {
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToString {
+                fmt: Some((
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info,
+                    },
+                    Template::new(
+                        Some('"'),
+                        vec![TemplateElement::String {
+                            value: fmt.to_string(),
+                            source: fmt.to_source(),
+                        }],
+                        source_info,
+                    ),
+                )),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_to_string_number_format() {
+        let variables = VariableSet::new();
+        let values = [
+            ("%.2f", Number::Float(1.23456), "1.23"),
+            ("%.2f", Number::Integer(3), "3.00"),
+            ("%f", Number::Float(1e21), "1000000000000000000000.000000"),
+            ("%.0f", Number::Float(2.5e-7), "0"),
+            ("%d ms", Number::Integer(42), "42 ms"),
+            ("%.1f%%", Number::Float(99.95), "100.0%"),
+        ];
+        for (fmt, number, expected) in values {
+            let filter = new_filter(fmt);
+            assert_eq!(
+                eval_filter(&filter, &Value::Number(number), &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::String(expected.to_string()),
+                "{fmt}"
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_to_string_date_format() {
+        let variables = VariableSet::new();
+        let filter = new_filter("%Y-%m-%d");
+        let date = UTC.with_ymd_and_hms(2026, 10, 16, 8, 30, 0).unwrap();
+        assert_eq!(
+            eval_filter(&filter, &Value::Date(date), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("2026-10-16".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_to_string_format_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter("%Y-%m-%d");
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Float(1.5)),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidNumberFormat("%Y-%m-%d".to_string())
+        );
+
+        let filter = new_filter("%.2f");
+        let err = eval_filter(
+            &filter,
+            &Value::String("1.5".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "string <1.5> can not be formatted with <%.2f>".to_string()
+            )
+        );
+    }
 }
@@ -0,0 +1,154 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono_tz::Tz;
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Converts a date `value` to the IANA timezone `tz` (e.g. `Europe/Paris`).
+///
+/// The converted date represents the same instant, only its local time changes.
+pub fn eval_to_timezone(
+    value: &Value,
+    tz: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let tz = eval_template(tz, variables)?;
+    let Ok(timezone) = tz.parse::<Tz>() else {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!("timezone <{tz}> is unknown"));
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+
+    match value {
+        Value::Date(value) => Ok(Some(Value::Date(value.with_timezone(&timezone)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::UTC;
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use super::*;
+    use crate::runner::filter::eval::{eval_filter, eval_filters};
+    use crate::runner::VariableSet;
+
+    /// Helper function to return a new filter with a single template argument.
+    fn new_filter(value: fn(Whitespace, Template) -> FilterValue, arg: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: value(
+                Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: arg.to_string(),
+                        source: arg.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                ),
+            ),
+        }
+    }
+
+    fn to_timezone(tz: &str) -> Filter {
+        new_filter(|space0, tz| FilterValue::ToTimezone { space0, tz }, tz)
+    }
+
+    fn date_format(fmt: &str) -> Filter {
+        new_filter(|space0, fmt| FilterValue::DateFormat { space0, fmt }, fmt)
+    }
+
+    #[test]
+    fn eval_filter_to_timezone() {
+        let variables = VariableSet::new();
+        let date = Value::Date(UTC.with_ymd_and_hms(2025, 1, 1, 12, 30, 0).unwrap());
+
+        let filters = [
+            &to_timezone("Europe/Paris"),
+            &date_format("%Y-%m-%d %H:%M %Z"),
+        ];
+        assert_eq!(
+            eval_filters(&filters, &date, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("2025-01-01 13:30 CET".to_string())
+        );
+
+        let filters = [
+            &to_timezone("America/New_York"),
+            &date_format("%Y-%m-%d %H:%M %Z"),
+        ];
+        assert_eq!(
+            eval_filters(&filters, &date, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("2025-01-01 07:30 EST".to_string())
+        );
+
+        // The instant is unchanged.
+        let filters = [&to_timezone("Asia/Tokyo")];
+        assert_eq!(
+            eval_filters(&filters, &date, &variables, false)
+                .unwrap()
+                .unwrap(),
+            date
+        );
+    }
+
+    #[test]
+    fn eval_filter_to_timezone_ko() {
+        let variables = VariableSet::new();
+        let date = Value::Date(UTC.with_ymd_and_hms(2025, 1, 1, 12, 30, 0).unwrap());
+
+        let filter = to_timezone("Mars/Olympus_Mons");
+        let err = eval_filter(&filter, &date, &variables, false).unwrap_err();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "timezone <Mars/Olympus_Mons> is unknown".to_string()
+            )
+        );
+
+        let filter = to_timezone("Europe/Paris");
+        let err = eval_filter(
+            &filter,
+            &Value::String("2025-01-01".to_string()),
+            &variables,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono_tz::UTC;
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts a date `value` to UTC, whatever its timezone.
+pub fn eval_to_utc(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Date(value) => Ok(Some(Value::Date(value.with_timezone(&UTC)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::Paris;
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use super::*;
+    use crate::runner::filter::eval::{eval_filter, eval_filters};
+    use crate::runner::VariableSet;
+
+    fn new_to_utc_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToUtc,
+        }
+    }
+
+    fn new_date_format_filter(fmt: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::DateFormat {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                fmt: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: fmt.to_string(),
+                        source: fmt.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_to_utc() {
+        let variables = VariableSet::new();
+        let date = Paris.with_ymd_and_hms(2025, 7, 1, 14, 0, 0).unwrap();
+
+        let ret = eval_filter(&new_to_utc_filter(), &Value::Date(date), &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Date(UTC.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap())
+        );
+
+        let filters = [
+            &new_to_utc_filter(),
+            &new_date_format_filter("%Y-%m-%d %H:%M %Z"),
+        ];
+        let ret = eval_filters(&filters, &Value::Date(date), &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("2025-07-01 12:00 UTC".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_to_utc_ko_bad_input_type() {
+        let variables = VariableSet::new();
+
+        let ret = eval_filter(
+            &new_to_utc_filter(),
+            &Value::String("2025-07-01".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
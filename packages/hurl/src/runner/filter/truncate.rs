@@ -0,0 +1,188 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{IntegerValue, SourceInfo, Template};
+
+use crate::runner::filter::nth::eval_integer_value;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Truncates a string `value` to `len` characters.
+///
+/// If the string is truncated, the optional `suffix` is appended. The suffix counts toward the
+/// limit, so the result is never longer than `len` characters: if the suffix is itself longer
+/// than `len`, only its first `len` characters are kept.
+pub fn eval_truncate(
+    value: &Value,
+    len: &IntegerValue,
+    suffix: Option<&Template>,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let len = eval_integer_value(len, variables)?;
+    let suffix = match suffix {
+        Some(suffix) => eval_template(suffix, variables)?,
+        None => String::new(),
+    };
+    if len < 0 {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!("length <{len}> is negative"));
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    let len = len as usize;
+
+    match value {
+        Value::String(s) => {
+            if s.chars().count() <= len {
+                return Ok(Some(Value::String(s.clone())));
+            }
+            let suffix_len = suffix.chars().count().min(len);
+            let truncated = s
+                .chars()
+                .take(len - suffix_len)
+                .chain(suffix.chars().take(suffix_len))
+                .collect::<String>();
+            Ok(Some(Value::String(truncated)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{
+        Filter, FilterValue, IntegerValue, SourceInfo, Template, TemplateElement, Whitespace, I64,
+    };
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    /// Helper function to return a new filter given a `len` and an optional `suffix`.
+    fn new_truncate_filter(len: i64, suffix: Option<&str>) -> Filter {
+        // Example: truncate 10 "..."
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Truncate {
+                space0: whitespace.clone(),
+                len: Box::new(IntegerValue::Literal(I64::new(
+                    len,
+                    len.to_string().to_source(),
+                ))),
+                suffix: suffix.map(|suffix| {
+                    let template = Template::new(
+                        None,
+                        vec![TemplateElement::String {
+                            value: suffix.to_string(),
+                            source: suffix.to_source(),
+                        }],
+                        SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    );
+                    (whitespace, template)
+                }),
+            },
+        }
+    }
+
+    fn truncate(len: i64, suffix: Option<&str>, value: &str) -> Value {
+        let variables = VariableSet::new();
+        let filter = new_truncate_filter(len, suffix);
+        eval_filter(
+            &filter,
+            &Value::String(value.to_string()),
+            &variables,
+            false,
+        )
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn eval_filter_truncate() {
+        // Shorter than the limit
+        assert_eq!(
+            truncate(10, Some("..."), "Hello"),
+            Value::String("Hello".to_string())
+        );
+        // Exactly the limit
+        assert_eq!(
+            truncate(5, Some("..."), "Hello"),
+            Value::String("Hello".to_string())
+        );
+        // Longer than the limit
+        assert_eq!(
+            truncate(8, None, "Hello World"),
+            Value::String("Hello Wo".to_string())
+        );
+        assert_eq!(
+            truncate(8, Some("..."), "Hello World"),
+            Value::String("Hello...".to_string())
+        );
+        assert_eq!(
+            truncate(2, Some("..."), "Hello World"),
+            Value::String("..".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_truncate_multibyte() {
+        assert_eq!(
+            truncate(4, Some("…"), "Привет мир"),
+            Value::String("При…".to_string())
+        );
+        assert_eq!(
+            truncate(3, None, "😀😁😂🤣"),
+            Value::String("😀😁😂".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_truncate_error() {
+        let variables = VariableSet::new();
+        let filter = new_truncate_filter(3, None);
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(12345)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <12345>".to_string())
+        );
+
+        let filter = new_truncate_filter(-1, None);
+        let ret = eval_filter(
+            &filter,
+            &Value::String("Hello".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("length <-1> is negative".to_string())
+        );
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::runner::{RunnerError, Value};
+
+/// Returns the name of the type of `value`.
+pub fn eval_type_of(value: &Value) -> Result<Option<Value>, RunnerError> {
+    let name = match value {
+        Value::Bool(_) => "boolean",
+        Value::Bytes(_) => "bytes",
+        Value::Date(_) => "date",
+        Value::HttpResponse(_) => "httpResponse",
+        Value::List(_) => "list",
+        Value::Nodeset(_) => "nodeset",
+        Value::Null => "null",
+        Value::Number(_) => "number",
+        Value::Object(_) => "object",
+        Value::Regex(_) => "regex",
+        Value::String(_) => "string",
+        Value::Unit => "unit",
+    };
+    Ok(Some(Value::String(name.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::UTC;
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_type_of() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::TypeOf,
+        };
+        let date = UTC.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let values = [
+            (Value::Bool(true), "boolean"),
+            (Value::Bytes(vec![0x01, 0x02]), "bytes"),
+            (Value::Date(date), "date"),
+            (Value::List(vec![]), "list"),
+            (Value::Null, "null"),
+            (Value::Number(Number::Integer(1)), "number"),
+            (Value::Number(Number::Float(1.5)), "number"),
+            (
+                Value::Number(Number::BigInteger("1000000000000000000000".to_string())),
+                "number",
+            ),
+            (Value::Object(vec![]), "object"),
+            (Value::String("hello".to_string()), "string"),
+        ];
+        for (value, name) in values {
+            assert_eq!(
+                eval_filter(&filter, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::String(name.to_string())
+            );
+        }
+    }
+}
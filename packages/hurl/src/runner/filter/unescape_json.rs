@@ -0,0 +1,97 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Unescapes `value` following JSON string rules, reversing the `escapeJson` filter: escape
+/// sequences (e.g. `\"`, `\n`, `\u00e9`) are converted to the corresponding characters.
+pub fn eval_unescape_json(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => match serde_json::from_str::<String>(&format!("\"{value}\"")) {
+            Ok(unescaped) => Ok(Some(Value::String(unescaped))),
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                    "<{value}> is not a valid JSON escaped string"
+                ));
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_unescape_json() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::UnescapeJson,
+        };
+
+        let tests = [
+            ("foo", "foo"),
+            ("say \\\"hello\\\"", "say \"hello\""),
+            ("C:\\\\temp\\/", "C:\\temp/"),
+            ("line1\\nline2\\ttab\\r", "line1\nline2\ttab\r"),
+            ("caf\\u00e9 \\u2615", "café ☕"),
+            ("\\ud83d\\ude00", "😀"),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("foo\\x".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "<foo\\x> is not a valid JSON escaped string".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::{Datelike, Weekday};
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns the ISO weekday of a date `value`, from 1 (Monday) to 7 (Sunday), or its English name
+/// if `name` is `true`.
+pub fn eval_weekday(
+    value: &Value,
+    name: bool,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Date(date) => {
+            let weekday = date.weekday();
+            if name {
+                Ok(Some(Value::String(weekday_name(weekday).to_string())))
+            } else {
+                let number = weekday.number_from_monday() as i64;
+                Ok(Some(Value::Number(Number::Integer(number))))
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the English name of a `weekday`.
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::Asia::Tokyo;
+    use chrono_tz::UTC;
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    fn new_weekday_filter(name: bool) -> Filter {
+        let name = name.then(|| Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        });
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Weekday { name },
+        }
+    }
+
+    #[test]
+    fn eval_filter_weekday() {
+        let variables = VariableSet::new();
+        // 2021-01-13 is a Wednesday.
+        let date = Value::Date(UTC.with_ymd_and_hms(2021, 1, 13, 22, 23, 1).unwrap());
+
+        let ret = eval_filter(&new_weekday_filter(false), &date, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(3)));
+
+        let ret = eval_filter(&new_weekday_filter(true), &date, &variables, false);
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("Wednesday".to_string())
+        );
+
+        // 2025-01-05 is a Sunday.
+        let date = Value::Date(UTC.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap());
+        let ret = eval_filter(&new_weekday_filter(false), &date, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(7)));
+    }
+
+    #[test]
+    fn eval_filter_weekday_in_timezone() {
+        let variables = VariableSet::new();
+        // Wednesday 22:00 in UTC is already Thursday in Tokyo.
+        let date = Value::Date(
+            UTC.with_ymd_and_hms(2021, 1, 13, 22, 0, 0)
+                .unwrap()
+                .with_timezone(&Tokyo),
+        );
+
+        let ret = eval_filter(&new_weekday_filter(true), &date, &variables, false);
+        assert_eq!(ret.unwrap().unwrap(), Value::String("Thursday".to_string()));
+    }
+
+    #[test]
+    fn eval_filter_weekday_ko_bad_input_type() {
+        let variables = VariableSet::new();
+
+        let ret = eval_filter(
+            &new_weekday_filter(false),
+            &Value::String("2021-01-13".to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
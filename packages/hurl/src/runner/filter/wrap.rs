@@ -0,0 +1,133 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Surrounds a string `value` with `left` and `right`. If `right` is not given, `value` is
+/// surrounded by `left` on both sides.
+pub fn eval_wrap(
+    value: &Value,
+    left: &Template,
+    right: Option<&Template>,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let left = eval_template(left, variables)?;
+    let right = match right {
+        Some(right) => eval_template(right, variables)?,
+        None => left.clone(),
+    };
+    match value {
+        Value::String(value) => Ok(Some(Value::String(format!("{left}{value}{right}")))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn template(value: &str) -> Template {
+        Template::new(
+            None,
+            vec![TemplateElement::String {
+                value: value.to_string(),
+                source: value.to_source(),
+            }],
+            SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        )
+    }
+
+    /// Helper function to return a new filter given a `left` and an optional `right`.
+    fn new_wrap_filter(left: &str, right: Option<&str>) -> Filter {
+        // Example: wrap "[" "]"
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Wrap {
+                space0: whitespace.clone(),
+                left: template(left),
+                right: right.map(|right| (whitespace, template(right))),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_wrap() {
+        let variables = VariableSet::new();
+        let filter = new_wrap_filter("[", Some("]"));
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("foo".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("[foo]".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_wrap_symmetric() {
+        let variables = VariableSet::new();
+        let filter = new_wrap_filter("\"", None);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("foo".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("\"foo\"".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_wrap_error() {
+        let variables = VariableSet::new();
+        let filter = new_wrap_filter("[", Some("]"));
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(42)),
+            &variables,
+            false,
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer <42>".to_string())
+        );
+    }
+}
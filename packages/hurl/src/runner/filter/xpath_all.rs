@@ -0,0 +1,137 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::xpath::{Document, Format, XPathError};
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Evaluates a XPath expression `expr` against a `value` and returns the string content of all the
+/// matched nodes.
+pub fn eval_xpath_all(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(xml) => {
+            // The filter will use the HTML parser that should also work with XML input
+            let Ok(doc) = Document::parse(xml, Format::Html) else {
+                return Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::FilterInvalidInput("value is not a valid XML".to_string()),
+                    false,
+                ));
+            };
+            let expr_str = eval_template(expr, variables)?;
+            match doc.eval_xpath_nodes(&expr_str) {
+                Ok(contents) => {
+                    let values = contents.into_iter().map(Value::String).collect();
+                    Ok(Some(Value::List(values)))
+                }
+                Err(XPathError::Eval) | Err(XPathError::Unsupported) => Err(RunnerError::new(
+                    expr.source_info,
+                    RunnerErrorKind::InvalidXPathEval,
+                    false,
+                )),
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Value, VariableSet};
+
+    /// Helper function to return a new filter given a `expr`
+    fn new_xpath_all_filter(expr: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::XPathAll {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(9, 1), Pos::new(10, 1)),
+                },
+                expr: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: expr.to_string(),
+                        source: expr.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(10, 1), Pos::new(10 + expr.len(), 1)),
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_xpath_all() {
+        let variables = VariableSet::new();
+        let xml = Value::String(
+            r#"<catalog>
+  <item><title>Foo</title></item>
+  <item><title>Bar</title></item>
+  <item><title>Baz</title></item>
+  <summary>3 items</summary>
+</catalog>"#
+                .to_string(),
+        );
+
+        // Repeated elements.
+        let filter = new_xpath_all_filter("//item/title");
+        assert_eq!(
+            eval_filter(&filter, &xml, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![
+                Value::String("Foo".to_string()),
+                Value::String("Bar".to_string()),
+                Value::String("Baz".to_string()),
+            ])
+        );
+
+        // Single match.
+        let filter = new_xpath_all_filter("//summary");
+        assert_eq!(
+            eval_filter(&filter, &xml, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![Value::String("3 items".to_string())])
+        );
+
+        // No match.
+        let filter = new_xpath_all_filter("//price");
+        assert_eq!(
+            eval_filter(&filter, &xml, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![])
+        );
+    }
+}
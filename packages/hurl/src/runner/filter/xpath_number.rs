@@ -0,0 +1,99 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::filter::xpath::eval_xpath;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Evaluates a XPath expression `expr` against a `value`, the expression returning a number.
+pub fn eval_xpath_number(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match eval_xpath(value, expr, variables, source_info, assert)? {
+        Some(value @ Value::Number(_)) => Ok(Some(value)),
+        Some(v) => {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                "XPath result {} is not a number",
+                v.repr()
+            ));
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(expr: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::XPathNumber {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: expr.to_string(),
+                        source: expr.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_xpath_number() {
+        let variables = VariableSet::new();
+        let xml = Value::String(
+            r#"<list><item id="1">Foo</item><item id="2">Bar</item><item id="3">Baz</item></list>"#
+                .to_string(),
+        );
+
+        let filter = new_filter("count(//item)");
+        assert_eq!(
+            eval_filter(&filter, &xml, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::from(3.0))
+        );
+
+        let filter = new_filter("string(//item)");
+        assert_eq!(
+            eval_filter(&filter, &xml, &variables, false)
+                .unwrap_err()
+                .kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "XPath result string <Foo> is not a number".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,263 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::SecondsFormat;
+use hurl_core::ast::SourceInfo;
+use yaml_rust2::yaml::Hash;
+use yaml_rust2::{Yaml, YamlEmitter, YamlLoader};
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Parses the YAML string `value` into a [`Value`].
+///
+/// Only the first document is parsed, an empty string is parsed to [`Value::Null`].
+pub fn eval_from_yaml(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::String(text) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let invalid_yaml = || {
+        let kind = RunnerErrorKind::FilterInvalidInput("value is not a valid YAML".to_string());
+        RunnerError::new(source_info, kind, assert)
+    };
+    let docs = YamlLoader::load_from_str(text).map_err(|_| invalid_yaml())?;
+    match docs.first() {
+        Some(doc) => from_yaml(doc).map(Some).ok_or_else(invalid_yaml),
+        None => Ok(Some(Value::Null)),
+    }
+}
+
+/// Converts a YAML node to a [`Value`], returns `None` if the node can't be converted.
+fn from_yaml(yaml: &Yaml) -> Option<Value> {
+    let value = match yaml {
+        Yaml::Real(_) => Value::Number(Number::Float(yaml.as_f64()?)),
+        Yaml::Integer(n) => Value::Number(Number::Integer(*n)),
+        Yaml::String(s) => Value::String(s.clone()),
+        Yaml::Boolean(b) => Value::Bool(*b),
+        Yaml::Array(values) => Value::List(values.iter().map(from_yaml).collect::<Option<_>>()?),
+        Yaml::Hash(entries) => {
+            let fields = entries
+                .iter()
+                .map(|(key, value)| Some((yaml_key(key)?, from_yaml(value)?)))
+                .collect::<Option<_>>()?;
+            Value::Object(fields)
+        }
+        Yaml::Null => Value::Null,
+        Yaml::Alias(_) | Yaml::BadValue => return None,
+    };
+    Some(value)
+}
+
+/// Returns the object key of a YAML mapping `key`, only scalar keys are supported.
+fn yaml_key(key: &Yaml) -> Option<String> {
+    match key {
+        Yaml::Real(s) | Yaml::String(s) => Some(s.clone()),
+        Yaml::Integer(n) => Some(n.to_string()),
+        Yaml::Boolean(b) => Some(b.to_string()),
+        Yaml::Null => Some("null".to_string()),
+        _ => None,
+    }
+}
+
+/// Serializes `value` to a YAML string, without the document start marker.
+pub fn eval_to_yaml(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Some(yaml) = to_yaml(value) else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let mut text = String::new();
+    let mut emitter = YamlEmitter::new(&mut text);
+    if emitter.dump(&yaml).is_err() {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    let text = text.strip_prefix("---").unwrap_or(&text).trim_start();
+    Ok(Some(Value::String(text.to_string())))
+}
+
+/// Converts a [`Value`] to a YAML node, returns `None` if the value has no YAML representation.
+fn to_yaml(value: &Value) -> Option<Yaml> {
+    let yaml = match value {
+        Value::Bool(b) => Yaml::Boolean(*b),
+        Value::Date(d) => Yaml::String(d.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+        Value::List(values) => Yaml::Array(values.iter().map(to_yaml).collect::<Option<_>>()?),
+        Value::Null => Yaml::Null,
+        Value::Number(Number::Integer(n)) => Yaml::Integer(*n),
+        Value::Number(Number::Float(f)) if f.is_nan() => Yaml::Real(".nan".to_string()),
+        Value::Number(Number::Float(f)) if f.is_infinite() => {
+            let sign = if *f < 0.0 { "-" } else { "" };
+            Yaml::Real(format!("{sign}.inf"))
+        }
+        Value::Number(n) => Yaml::Real(n.to_string()),
+        Value::Object(fields) => {
+            let mut entries = Hash::new();
+            for (key, value) in fields {
+                entries.insert(Yaml::String(key.clone()), to_yaml(value)?);
+            }
+            Yaml::Hash(entries)
+        }
+        Value::String(s) => Yaml::String(s.clone()),
+        Value::Bytes(_)
+        | Value::HttpResponse(_)
+        | Value::Nodeset(_)
+        | Value::Regex(_)
+        | Value::Unit => return None,
+    };
+    Some(yaml)
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn new_filter(value: FilterValue) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value,
+        }
+    }
+
+    /// Returns a nested object with a list, a float, a boolean and a null value.
+    fn nested_object() -> Value {
+        Value::Object(vec![
+            (
+                "server".to_string(),
+                Value::Object(vec![
+                    ("host".to_string(), Value::String("localhost".to_string())),
+                    (
+                        "ports".to_string(),
+                        Value::List(vec![
+                            Value::Number(Number::Integer(8080)),
+                            Value::Number(Number::Integer(8443)),
+                        ]),
+                    ),
+                ]),
+            ),
+            ("ratio".to_string(), Value::Number(Number::Float(0.5))),
+            ("debug".to_string(), Value::Bool(true)),
+            ("proxy".to_string(), Value::Null),
+        ])
+    }
+
+    #[test]
+    fn eval_filter_to_yaml() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::ToYaml);
+        assert_eq!(
+            eval_filter(&filter, &nested_object(), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String(
+                r#"server:
+  host: localhost
+  ports:
+    - 8080
+    - 8443
+ratio: 0.5
+debug: true
+proxy: ~"#
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("true".to_string()),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("\"true\"".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_yaml_round_trip() {
+        let variables = VariableSet::new();
+        let to_yaml = new_filter(FilterValue::ToYaml);
+        let from_yaml = new_filter(FilterValue::FromYaml);
+        let text = eval_filter(&to_yaml, &nested_object(), &variables, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            eval_filter(&from_yaml, &text, &variables, false)
+                .unwrap()
+                .unwrap(),
+            nested_object()
+        );
+    }
+
+    #[test]
+    fn eval_filter_from_yaml() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::FromYaml);
+        let text =
+            "server:\n  host: localhost\n  ports: [8080, 8443]\nratio: 0.5\ndebug: true\nproxy:\n";
+        assert_eq!(
+            eval_filter(&filter, &Value::String(text.to_string()), &variables, false)
+                .unwrap()
+                .unwrap(),
+            nested_object()
+        );
+        assert_eq!(
+            eval_filter(&filter, &Value::String(String::new()), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn eval_filter_yaml_error() {
+        let variables = VariableSet::new();
+        let filter = new_filter(FilterValue::FromYaml);
+        let err = eval_filter(
+            &filter,
+            &Value::String("server: [localhost\nport: 8080".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("value is not a valid YAML".to_string())
+        );
+
+        let filter = new_filter(FilterValue::ToYaml);
+        let err = eval_filter(&filter, &Value::Bytes(vec![1, 2]), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("bytes".to_string())
+        );
+    }
+}
@@ -26,7 +26,7 @@ use super::value::Value;
 pub fn eval(function: &Function) -> Result<Value, RunnerError> {
     match &function {
         Function::NewDate => {
-            let now = Utc::now();
+            let now = Utc::now().with_timezone(&chrono_tz::UTC);
             Ok(Value::Date(now))
         }
         Function::NewUuid => {
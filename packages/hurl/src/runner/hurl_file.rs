@@ -31,6 +31,7 @@ use crate::util::term::{Stderr, Stdout, WriteMode};
 
 use super::entry;
 use super::event::EventListener;
+use super::filter::{self, FilterContext};
 use super::options;
 use super::result::{EntryResult, HurlResult};
 use super::runner_options::RunnerOptions;
@@ -178,6 +179,22 @@ pub fn run_entries(
 
     log_run_info(entries, runner_options, &variables, logger);
 
+    let filter_context = FilterContext::new(runner_options.jsfilter_path.clone())
+        .with_seed(runner_options.seed)
+        .with_strict_arithmetic(runner_options.strict_arithmetic);
+
+    // The JavaScript settings are also used by inline `jseval` expressions, with or without a
+    // JavaScript filter file, and must not leak from a file previously run by this thread.
+    filter::set_js_shared_global(runner_options.jsfilter_shared_global);
+    filter::set_js_hurl_filename(filename.map(|f| f.to_string()));
+    filter::set_js_config(runner_options.jsfilter_config.clone());
+    filter::set_js_strict(runner_options.jsfilter_strict);
+    filter::set_js_tuple_results(runner_options.jsfilter_tuple_results);
+    filter::set_js_date_objects(runner_options.jsfilter_date_objects);
+    filter::set_js_filter_prefix(&runner_options.jsfilter_prefix);
+    filter::set_js_args_mode(runner_options.jsfilter_args_mode);
+    filter::set_js_undefined_unchanged(runner_options.jsfilter_undefined_unchanged);
+
     // Main loop processing each entry.
     // The `entry_index` is not always incremented of each loop tick: an entry can be retried upon
     // errors for instance. Each entry is executed with options that are computed from the global
@@ -266,6 +283,7 @@ pub fn run_entries(
             filename,
             &mut http_client,
             &options,
+            &filter_context,
             &mut variables,
             stdout,
             listener,
@@ -329,6 +347,7 @@ fn run_request(
     filename: Option<&Input>,
     http_client: &mut Client,
     options: &RunnerOptions,
+    filter_context: &FilterContext,
     variables: &mut VariableSet,
     stdout: &mut Stdout,
     listener: Option<&dyn EventListener>,
@@ -338,7 +357,15 @@ fn run_request(
     let mut retry_count = 0;
 
     loop {
-        let mut result = entry::run(entry, current, http_client, variables, options, logger);
+        let mut result = entry::run(
+            entry,
+            current,
+            http_client,
+            variables,
+            options,
+            filter_context,
+            logger,
+        );
 
         // Check if we need to retry.
         let mut has_error = !result.errors.is_empty();
@@ -0,0 +1,184 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Base64 helpers exposed to JavaScript filters.
+//!
+//! This module registers the `atob` and `btoa` globals (working on binary strings, as in
+//! browsers) and a `Hurl.base64` namespace working on `Uint8Array`:
+//!
+//! ```javascript
+//! btoa("hello");                     // "aGVsbG8="
+//! atob("aGVsbG8=");                  // "hello"
+//! Hurl.base64.encode(new Uint8Array([0xff, 0x00]));  // "/wA="
+//! Hurl.base64.decode("/wA=");        // Uint8Array [0xff, 0x00]
+//! ```
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use boa_engine::native_function::NativeFunction;
+use boa_engine::object::builtins::JsUint8Array;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
+use boa_engine::{js_string, Context, JsArgs, JsNativeError, JsResult, JsString, JsValue};
+
+use super::convert::js_to_bytes;
+
+/// Registers `atob`, `btoa` and the `Hurl.base64` namespace in the global object of `context`.
+pub fn register(context: &mut Context) -> JsResult<()> {
+    context.register_global_callable(js_string!("atob"), 1, NativeFunction::from_fn_ptr(atob))?;
+    context.register_global_callable(js_string!("btoa"), 1, NativeFunction::from_fn_ptr(btoa))?;
+
+    let base64 = ObjectInitializer::new(context)
+        .function(NativeFunction::from_fn_ptr(encode), js_string!("encode"), 1)
+        .function(NativeFunction::from_fn_ptr(decode), js_string!("decode"), 1)
+        .build();
+    let hurl = ObjectInitializer::new(context)
+        .property(js_string!("base64"), base64, Attribute::all())
+        .build();
+    context.register_global_property(js_string!("Hurl"), hurl, Attribute::all())?;
+    Ok(())
+}
+
+/// Encodes a binary string (each code unit being a byte) to base64.
+fn btoa(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let input = args.get_or_undefined(0).to_string(context)?;
+    let input = input.to_std_string().map_err(|_| invalid_character())?;
+    let bytes = input
+        .chars()
+        .map(|c| u8::try_from(c).map_err(|_| invalid_character()))
+        .collect::<JsResult<Vec<u8>>>()?;
+    Ok(JsValue::from(encode_bytes(&bytes)))
+}
+
+/// Decodes a base64 string to a binary string (each code unit being a byte).
+fn atob(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let input = args.get_or_undefined(0).to_string(context)?;
+    let bytes = decode_str(&input.to_std_string_escaped())?;
+    let decoded = bytes.into_iter().map(char::from).collect::<String>();
+    Ok(JsValue::from(JsString::from(decoded.as_str())))
+}
+
+/// Encodes a `Uint8Array` (or an UTF-8 string) to base64.
+fn encode(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let bytes = js_to_bytes(args.get_or_undefined(0), context)?;
+    Ok(JsValue::from(encode_bytes(&bytes)))
+}
+
+/// Encodes `bytes` to a base64 JavaScript string, with padding.
+pub fn encode_bytes(bytes: &[u8]) -> JsString {
+    let encoded = BASE64_STANDARD.encode(bytes);
+    JsString::from(encoded.as_str())
+}
+
+/// Decodes a base64 string to a `Uint8Array`.
+fn decode(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let input = args.get_or_undefined(0).to_string(context)?;
+    let bytes = decode_str(&input.to_std_string_escaped())?;
+    let array = JsUint8Array::from_iter(bytes, context)?;
+    Ok(array.into())
+}
+
+/// Decodes a base64 string, ignoring ASCII whitespaces and tolerating missing padding.
+fn decode_str(input: &str) -> JsResult<Vec<u8>> {
+    let mut input = input
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect::<String>();
+    while input.len() % 4 != 0 {
+        input.push('=');
+    }
+    BASE64_STANDARD
+        .decode(input)
+        .map_err(|_| invalid_character())
+}
+
+fn invalid_character() -> boa_engine::JsError {
+    JsNativeError::typ()
+        .with_message("invalid character")
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use boa_engine::{Context, Source};
+
+    use super::*;
+    use crate::runner::js::convert::js_to_value;
+    use crate::runner::Value;
+
+    fn eval(source: &str) -> Value {
+        let mut context = Context::default();
+        register(&mut context).unwrap();
+        let ret = context.eval(Source::from_bytes(source)).unwrap();
+        js_to_value(&ret, &mut context).unwrap()
+    }
+
+    #[test]
+    fn test_btoa_atob_ascii() {
+        assert_eq!(
+            eval(r#"btoa("Hello, World!")"#),
+            Value::String("SGVsbG8sIFdvcmxkIQ==".to_string())
+        );
+        assert_eq!(
+            eval(r#"atob("SGVsbG8sIFdvcmxkIQ==")"#),
+            Value::String("Hello, World!".to_string())
+        );
+        assert_eq!(
+            eval(r#"atob(btoa("Hello, World!"))"#),
+            Value::String("Hello, World!".to_string())
+        );
+    }
+
+    #[test]
+    fn test_btoa_atob_binary() {
+        assert_eq!(
+            eval(r#"btoa("\xff\xfe\x00")"#),
+            Value::String("//4A".to_string())
+        );
+        assert_eq!(
+            eval(
+                r#"
+                var s = "";
+                for (var i = 0; i < 256; i++) { s += String.fromCharCode(i); }
+                atob(btoa(s)) === s
+                "#
+            ),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval(r#"try { btoa("€"); "ok" } catch (e) { e.name }"#),
+            Value::String("TypeError".to_string())
+        );
+    }
+
+    #[test]
+    fn test_hurl_base64_bytes() {
+        assert_eq!(
+            eval(r#"Hurl.base64.encode(new Uint8Array([0xff, 0x00, 0x3c, 0x3f]))"#),
+            Value::String("/wA8Pw==".to_string())
+        );
+        assert_eq!(
+            eval(r#"Hurl.base64.decode("/wA8Pw==")"#),
+            Value::Bytes(vec![0xff, 0x00, 0x3c, 0x3f])
+        );
+        assert_eq!(
+            eval(r#"Hurl.base64.encode("café")"#),
+            Value::String("Y2Fmw6k=".to_string())
+        );
+    }
+}
@@ -21,22 +21,53 @@
 //! The `client` object stores session metadata that persists across requests.
 //! Similar to JetBrains HTTP Client's client object.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 
+use boa_engine::object::builtins::JsArray;
+use boa_engine::object::IntegrityLevel;
 use boa_engine::{js_string, Context, JsObject, JsResult, JsValue};
 
-use crate::runner::Value;
+use crate::runner::{Number, Value};
 
 use super::convert::value_to_js;
+use super::response::JsResponse;
+
+/// Default maximum number of responses kept in the client history.
+pub const DEFAULT_HISTORY_CAPACITY: usize = 10;
+
+/// Response kept in the client history.
+///
+/// Only the status and the URL of the response are kept, so a long history doesn't hold the
+/// response bodies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsHistoryEntry {
+    /// HTTP status code.
+    pub status: u32,
+    /// Response URL.
+    pub url: String,
+}
+
+/// Storage of a `client.global` object shared between runtimes, possibly running on different
+/// threads.
+pub type SharedGlobal = Arc<Mutex<HashMap<String, Value>>>;
 
 /// Client object that stores session metadata.
 ///
 /// This object persists throughout the Hurl session and can be used
 /// to store custom data that needs to be shared across requests.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct JsClient {
     /// Global storage for custom variables.
     pub global: HashMap<String, Value>,
+    /// Most recent responses, from the oldest to the newest.
+    ///
+    /// The history is exposed to JavaScript as the `client.history` array of `{ status, url }`
+    /// objects. This array is frozen: it's read-only within a filter, and it's only updated by the
+    /// runner.
+    pub history: VecDeque<JsHistoryEntry>,
+    /// Maximum number of responses kept in the history.
+    history_capacity: usize,
 }
 
 impl JsClient {
@@ -44,6 +75,58 @@ impl JsClient {
     pub fn new() -> Self {
         Self {
             global: HashMap::new(),
+            history: VecDeque::new(),
+            history_capacity: DEFAULT_HISTORY_CAPACITY,
+        }
+    }
+
+    /// Sets the maximum number of responses kept in the history, discarding the oldest responses
+    /// if needed.
+    pub fn set_history_capacity(&mut self, capacity: usize) {
+        self.history_capacity = capacity;
+        while self.history.len() > capacity {
+            self.history.pop_front();
+        }
+    }
+
+    /// Adds a `response` to the history, discarding the oldest response if the history is full.
+    pub fn push_history(&mut self, response: &JsResponse) {
+        if self.history_capacity == 0 {
+            return;
+        }
+        if self.history.len() == self.history_capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(JsHistoryEntry {
+            status: response.status,
+            url: response.url.clone(),
+        });
+    }
+
+    /// Returns the string stored in the global storage under `key`, or `None` if there is no such
+    /// value or if it's not a string.
+    pub fn get_string(&self, key: &str) -> Option<&str> {
+        match self.global.get(key) {
+            Some(Value::String(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the number stored in the global storage under `key`, or `None` if there is no such
+    /// value or if it's not a number.
+    pub fn get_number(&self, key: &str) -> Option<&Number> {
+        match self.global.get(key) {
+            Some(Value::Number(n)) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean stored in the global storage under `key`, or `None` if there is no such
+    /// value or if it's not a boolean.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.global.get(key) {
+            Some(Value::Bool(b)) => Some(*b),
+            _ => None,
         }
     }
 
@@ -59,6 +142,28 @@ impl JsClient {
         }
         obj.set(js_string!("global"), JsValue::from(global_obj), false, context)?;
 
+        // Create the read-only history array
+        let history = JsArray::new(context);
+        for response in &self.history {
+            let item = JsObject::with_null_proto();
+            item.set(
+                js_string!("status"),
+                JsValue::from(response.status as i32),
+                false,
+                context,
+            )?;
+            item.set(
+                js_string!("url"),
+                JsValue::from(js_string!(response.url.as_str())),
+                false,
+                context,
+            )?;
+            item.set_integrity_level(IntegrityLevel::Frozen, context)?;
+            history.push(JsValue::from(item), context)?;
+        }
+        history.set_integrity_level(IntegrityLevel::Frozen, context)?;
+        obj.set(js_string!("history"), JsValue::from(history), false, context)?;
+
         Ok(obj)
     }
 
@@ -85,10 +190,15 @@ impl JsClient {
     }
 }
 
+impl Default for JsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::Number;
 
     #[test]
     fn test_client_to_js_object() {
@@ -110,4 +220,76 @@ mod tests {
         let counter = global_obj.get(js_string!("counter"), &mut context).unwrap();
         assert_eq!(counter.as_number(), Some(42.0));
     }
+
+    fn client_with_globals() -> JsClient {
+        let mut client = JsClient::new();
+        client
+            .global
+            .insert("name".to_string(), Value::String("test".to_string()));
+        client
+            .global
+            .insert("counter".to_string(), Value::Number(Number::Integer(42)));
+        client
+            .global
+            .insert("ratio".to_string(), Value::Number(Number::Float(0.5)));
+        client
+            .global
+            .insert("enabled".to_string(), Value::Bool(true));
+        client
+    }
+
+    #[test]
+    fn test_client_get_string() {
+        let client = client_with_globals();
+        assert_eq!(client.get_string("name"), Some("test"));
+        assert_eq!(client.get_string("counter"), None);
+        assert_eq!(client.get_string("enabled"), None);
+        assert_eq!(client.get_string("unknown"), None);
+    }
+
+    #[test]
+    fn test_client_get_number() {
+        let client = client_with_globals();
+        assert_eq!(client.get_number("counter"), Some(&Number::Integer(42)));
+        assert_eq!(client.get_number("ratio"), Some(&Number::Float(0.5)));
+        assert_eq!(client.get_number("name"), None);
+        assert_eq!(client.get_number("enabled"), None);
+        assert_eq!(client.get_number("unknown"), None);
+    }
+
+    #[test]
+    fn test_client_get_bool() {
+        let client = client_with_globals();
+        assert_eq!(client.get_bool("enabled"), Some(true));
+        assert_eq!(client.get_bool("name"), None);
+        assert_eq!(client.get_bool("counter"), None);
+        assert_eq!(client.get_bool("unknown"), None);
+    }
+
+    fn response(status: u32) -> JsResponse {
+        JsResponse {
+            status,
+            url: format!("http://localhost/{status}"),
+            ..JsResponse::empty()
+        }
+    }
+
+    #[test]
+    fn test_client_history_capacity() {
+        let mut client = JsClient::new();
+        client.set_history_capacity(2);
+        client.push_history(&response(200));
+        client.push_history(&response(201));
+        client.push_history(&response(202));
+        let statuses = client.history.iter().map(|r| r.status).collect::<Vec<_>>();
+        assert_eq!(statuses, vec![201, 202]);
+
+        client.set_history_capacity(1);
+        let statuses = client.history.iter().map(|r| r.status).collect::<Vec<_>>();
+        assert_eq!(statuses, vec![202]);
+
+        client.set_history_capacity(0);
+        client.push_history(&response(203));
+        assert!(client.history.is_empty());
+    }
 }
@@ -17,16 +17,47 @@
  */
 
 //! Conversion between Hurl `Value` and JavaScript `JsValue`.
+//!
+//! Bytes are converted without loss in both directions: a `Value::Bytes` is passed to JavaScript
+//! as a `Uint8Array`, and any `Uint8Array` (or other view on an `ArrayBuffer`) returned by a filter
+//! is converted back to a `Value::Bytes` with the exact same bytes.
 
-use boa_engine::{js_string, Context, JsObject, JsResult, JsValue};
+use boa_engine::object::builtins::{
+    JsArrayBuffer, JsDataView, JsDate, JsRegExp, JsTypedArray, JsUint8Array,
+};
+use boa_engine::{js_string, Context, JsBigInt, JsNativeError, JsObject, JsResult, JsValue};
 
 use crate::runner::Number;
 use crate::runner::Value;
 
 use super::error::JsError;
 
-/// Converts a Hurl `Value` to a JavaScript `JsValue`.
+/// Options of the conversion of Hurl values to JavaScript values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ToJsOptions {
+    /// Converts dates to JavaScript `Date` objects instead of ISO 8601 strings.
+    pub date_objects: bool,
+}
+
+/// Options of the conversion of JavaScript values to Hurl values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FromJsOptions {
+    /// Rejects the values without Hurl counterpart (functions, symbols) instead of converting them
+    /// to a string or an object.
+    pub strict: bool,
+}
+
+/// Converts a Hurl `Value` to a JavaScript `JsValue`, with default options.
 pub fn value_to_js(value: &Value, context: &mut Context) -> JsResult<JsValue> {
+    value_to_js_with(value, ToJsOptions::default(), context)
+}
+
+/// Converts a Hurl `Value` to a JavaScript `JsValue`, using conversion `options`.
+pub fn value_to_js_with(
+    value: &Value,
+    options: ToJsOptions,
+    context: &mut Context,
+) -> JsResult<JsValue> {
     match value {
         Value::Bool(b) => Ok(JsValue::from(*b)),
         Value::Null => Ok(JsValue::null()),
@@ -35,7 +66,7 @@ pub fn value_to_js(value: &Value, context: &mut Context) -> JsResult<JsValue> {
         Value::List(items) => {
             let array = boa_engine::object::builtins::JsArray::new(context);
             for item in items {
-                let js_item = value_to_js(item, context)?;
+                let js_item = value_to_js_with(item, options, context)?;
                 array.push(js_item, context)?;
             }
             Ok(array.into())
@@ -43,7 +74,7 @@ pub fn value_to_js(value: &Value, context: &mut Context) -> JsResult<JsValue> {
         Value::Object(pairs) => {
             let obj = JsObject::with_null_proto();
             for (key, val) in pairs {
-                let js_val = value_to_js(val, context)?;
+                let js_val = value_to_js_with(val, options, context)?;
                 obj.set(js_string!(key.as_str()), js_val, false, context)?;
             }
             Ok(obj.into())
@@ -54,6 +85,17 @@ pub fn value_to_js(value: &Value, context: &mut Context) -> JsResult<JsValue> {
                 boa_engine::object::builtins::JsUint8Array::from_iter(bytes.iter().copied(), context)?;
             Ok(typed_array.into())
         }
+        Value::Date(dt) if options.date_objects => {
+            // Construct a `Date` from the epoch milliseconds
+            let constructor = context.global_object().get(js_string!("Date"), context)?;
+            let Some(constructor) = constructor.as_constructor() else {
+                return Err(JsNativeError::typ()
+                    .with_message("Date is not a constructor")
+                    .into());
+            };
+            let millis = JsValue::from(dt.timestamp_millis() as f64);
+            Ok(constructor.construct(&[millis], None, context)?.into())
+        }
         Value::Date(dt) => {
             // Convert to ISO string for JavaScript
             let iso_string = dt.to_rfc3339();
@@ -90,10 +132,21 @@ pub fn value_to_js(value: &Value, context: &mut Context) -> JsResult<JsValue> {
     }
 }
 
+/// Largest integer `n` such that `n` and `n + 1` are exactly represented as JavaScript numbers
+/// (`Number.MAX_SAFE_INTEGER`).
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
 /// Converts a Hurl `Number` to a JavaScript `JsValue`.
+///
+/// Integers in the safe range `[-(2^53 - 1), 2^53 - 1]` are converted to JavaScript numbers, the
+/// others to `BigInt`s so they're not rounded. Note that filters must not mix these `BigInt`s with
+/// numbers in arithmetic expressions (`input + 1n` and not `input + 1`).
 fn number_to_js(number: &Number) -> JsResult<JsValue> {
     match number {
-        Number::Integer(i) => Ok(JsValue::from(*i as f64)),
+        Number::Integer(i) if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(i) => {
+            Ok(JsValue::from(*i as f64))
+        }
+        Number::Integer(i) => Ok(JsValue::from(JsBigInt::from(*i))),
         Number::Float(f) => Ok(JsValue::from(*f)),
         Number::BigInteger(s) => {
             // Try to parse as f64, fall back to string if too large
@@ -107,7 +160,28 @@ fn number_to_js(number: &Number) -> JsResult<JsValue> {
 }
 
 /// Converts a JavaScript `JsValue` to a Hurl `Value`.
+///
+/// Plain objects are converted to `Value::Object` following the JavaScript own property keys
+/// order: integer-like keys (`"0"`, `"42"`) come first, in ascending numeric order, then the
+/// other string keys in insertion order. As with `JSON.stringify`, only own enumerable string-keyed
+/// properties are converted: symbol-keyed and non-enumerable properties are skipped. If a property
+/// can't be read (a getter throws for instance), the conversion fails with an error naming it.
+///
+/// `NaN`, `Infinity` and `-Infinity` have no Hurl counterpart (they can't be serialized to JSON
+/// or compared reliably): they're rejected with a [`JsError::ConversionError`].
 pub fn js_to_value(js_value: &JsValue, context: &mut Context) -> Result<Value, JsError> {
+    js_to_value_with(js_value, FromJsOptions::default(), context)
+}
+
+/// Converts a JavaScript `JsValue` to a Hurl `Value`, using conversion `options`.
+///
+/// In strict mode, functions and symbols are rejected with a [`JsError::ConversionError`] naming
+/// their type, instead of being converted to an object and a string.
+pub fn js_to_value_with(
+    js_value: &JsValue,
+    options: FromJsOptions,
+    context: &mut Context,
+) -> Result<Value, JsError> {
     if js_value.is_undefined() || js_value.is_null() {
         return Ok(Value::Null);
     }
@@ -117,6 +191,18 @@ pub fn js_to_value(js_value: &JsValue, context: &mut Context) -> Result<Value, J
     }
 
     if let Some(n) = js_value.as_number() {
+        if !n.is_finite() {
+            let n = if n.is_nan() {
+                "NaN"
+            } else if n > 0.0 {
+                "Infinity"
+            } else {
+                "-Infinity"
+            };
+            return Err(JsError::ConversionError {
+                message: format!("{n} can not be converted to a Hurl number"),
+            });
+        }
         // Check if it's an integer
         if n.fract() == 0.0 && n >= i64::MIN as f64 && n <= i64::MAX as f64 {
             return Ok(Value::Number(Number::Integer(n as i64)));
@@ -128,6 +214,14 @@ pub fn js_to_value(js_value: &JsValue, context: &mut Context) -> Result<Value, J
         return Ok(Value::String(s.to_std_string_escaped()));
     }
 
+    if let Some(b) = js_value.as_bigint() {
+        let s = b.to_string();
+        return match s.parse::<i64>() {
+            Ok(i) => Ok(Value::Number(Number::Integer(i))),
+            Err(_) => Ok(Value::Number(Number::BigInteger(s))),
+        };
+    }
+
     if let Some(obj) = js_value.as_object() {
         // Check if it's an array
         if obj.is_array() {
@@ -146,47 +240,54 @@ pub fn js_to_value(js_value: &JsValue, context: &mut Context) -> Result<Value, J
                 let item = obj.get(i, context).map_err(|e| JsError::ConversionError {
                     message: e.to_string(),
                 })?;
-                items.push(js_to_value(&item, context)?);
+                items.push(js_to_value_with(&item, options, context)?);
             }
             return Ok(Value::List(items));
         }
 
-        // Check if it's a Uint8Array (bytes)
-        if let Ok(typed_array) = boa_engine::object::builtins::JsUint8Array::from_object(obj.clone()) {
-            let length = typed_array.length(context).map_err(|e| JsError::ConversionError {
+        // Check if it's a Date
+        if let Ok(date) = JsDate::from_object(obj.clone()) {
+            return date_to_value(&date, context);
+        }
+
+        // Check if it's a RegExp
+        if JsRegExp::from_object(obj.clone()).is_ok() {
+            return regexp_to_value(obj, context);
+        }
+
+        // Check if it's an ArrayBuffer or a view on an ArrayBuffer (bytes)
+        if let Some(bytes) =
+            buffer_source_to_bytes(obj, context).map_err(|e| JsError::ConversionError {
                 message: e.to_string(),
-            })?;
-            let mut bytes = Vec::with_capacity(length as usize);
-            for i in 0..length {
-                let byte = typed_array.get(i, context).map_err(|e| JsError::ConversionError {
-                    message: e.to_string(),
-                })?;
-                if let Some(n) = byte.as_number() {
-                    bytes.push(n as u8);
-                }
-            }
+            })?
+        {
             return Ok(Value::Bytes(bytes));
         }
 
+        if options.strict && obj.is_callable() {
+            return Err(unconvertible_type(js_value));
+        }
+
         // Regular object
-        let keys = obj
-            .own_property_keys(context)
-            .map_err(|e| JsError::ConversionError {
-                message: e.to_string(),
-            })?;
+        let keys = enumerable_keys(obj, context).map_err(|e| JsError::ConversionError {
+            message: format!("object keys can not be read: {e}"),
+        })?;
         let mut pairs = Vec::with_capacity(keys.len());
         for key in keys {
-            let key_str = key.to_string();
             let val = obj
-                .get(key.clone(), context)
+                .get(js_string!(key.as_str()), context)
                 .map_err(|e| JsError::ConversionError {
-                    message: e.to_string(),
+                    message: format!("property <{key}> can not be read: {e}"),
                 })?;
-            pairs.push((key_str, js_to_value(&val, context)?));
+            pairs.push((key, js_to_value_with(&val, options, context)?));
         }
         return Ok(Value::Object(pairs));
     }
 
+    if options.strict {
+        return Err(unconvertible_type(js_value));
+    }
+
     // Fallback: convert to string
     let s = js_value.to_string(context).map_err(|e| JsError::ConversionError {
         message: e.to_string(),
@@ -194,6 +295,155 @@ pub fn js_to_value(js_value: &JsValue, context: &mut Context) -> Result<Value, J
     Ok(Value::String(s.to_std_string_escaped()))
 }
 
+/// Returns the error of a JavaScript value whose type can't be converted to a Hurl value.
+fn unconvertible_type(js_value: &JsValue) -> JsError {
+    JsError::ConversionError {
+        message: format!(
+            "{} can not be converted to a Hurl value",
+            js_value.type_of()
+        ),
+    }
+}
+
+/// Returns the own enumerable string keys of `obj`, as `Object.keys(obj)`.
+fn enumerable_keys(obj: &JsObject, context: &mut Context) -> JsResult<Vec<String>> {
+    let object = context.global_object().get(js_string!("Object"), context)?;
+    let keys = match object.as_object() {
+        Some(object) => object.get(js_string!("keys"), context)?,
+        None => JsValue::undefined(),
+    };
+    let Some(keys) = keys.as_callable() else {
+        return Err(JsNativeError::typ()
+            .with_message("Object.keys is not a function")
+            .into());
+    };
+    let keys = keys.call(&JsValue::undefined(), &[obj.clone().into()], context)?;
+    let Some(keys) = keys.as_object() else {
+        return Ok(vec![]);
+    };
+    let length = keys.get(js_string!("length"), context)?.to_u32(context)?;
+    let mut ret = Vec::with_capacity(length as usize);
+    for i in 0..length {
+        let key = keys.get(i, context)?.to_string(context)?;
+        ret.push(key.to_std_string_escaped());
+    }
+    Ok(ret)
+}
+
+/// Converts a JavaScript `Date` to a Hurl date, with a millisecond precision.
+fn date_to_value(date: &JsDate, context: &mut Context) -> Result<Value, JsError> {
+    let millis = date
+        .get_time(context)
+        .map_err(|e| JsError::ConversionError {
+            message: e.to_string(),
+        })?
+        .as_number()
+        .unwrap_or(f64::NAN);
+    if !millis.is_finite() {
+        return Err(JsError::ConversionError {
+            message: "invalid Date can not be converted".to_string(),
+        });
+    }
+    match chrono::DateTime::from_timestamp_millis(millis as i64) {
+        Some(dt) => Ok(Value::Date(dt.with_timezone(&chrono_tz::UTC))),
+        None => Err(JsError::ConversionError {
+            message: format!("Date <{millis}> is out of range"),
+        }),
+    }
+}
+
+/// Converts a JavaScript `RegExp` to a Hurl regex, using its `source` and `flags`.
+///
+/// The `i`, `m` and `s` flags are translated to inline flags, the other flags (`g`, `y`, `d`, `u`
+/// and `v`) don't apply to Hurl regexes and are ignored.
+fn regexp_to_value(obj: &JsObject, context: &mut Context) -> Result<Value, JsError> {
+    let mut get = |name| -> Result<String, JsError> {
+        obj.get(name, context)
+            .and_then(|v| v.to_string(context))
+            .map(|s| s.to_std_string_escaped())
+            .map_err(|e| JsError::ConversionError {
+                message: e.to_string(),
+            })
+    };
+    let source = get(js_string!("source"))?;
+    let flags = get(js_string!("flags"))?
+        .chars()
+        .filter(|c| matches!(c, 'i' | 'm' | 's'))
+        .collect::<String>();
+    let pattern = if flags.is_empty() {
+        source
+    } else {
+        format!("(?{flags}){source}")
+    };
+    match regex::Regex::new(&pattern) {
+        Ok(re) => Ok(Value::Regex(re)),
+        Err(e) => Err(JsError::ConversionError {
+            message: format!("RegExp </{pattern}/> can not be converted: {e}"),
+        }),
+    }
+}
+
+/// Converts a JavaScript string, `ArrayBuffer` or view on an `ArrayBuffer` (`Uint8Array` etc...)
+/// to raw bytes.
+///
+/// Strings are encoded as UTF-8, any other value is rejected with a `TypeError`.
+pub fn js_to_bytes(js_value: &JsValue, context: &mut Context) -> JsResult<Vec<u8>> {
+    if let Some(s) = js_value.as_string() {
+        return Ok(s.to_std_string_escaped().into_bytes());
+    }
+    if let Some(obj) = js_value.as_object() {
+        if let Some(bytes) = buffer_source_to_bytes(obj, context)? {
+            return Ok(bytes);
+        }
+    }
+    Err(JsNativeError::typ()
+        .with_message("expected a string or a Uint8Array")
+        .into())
+}
+
+/// Returns the bytes of an `ArrayBuffer`, or of the part of the `ArrayBuffer` seen by a typed
+/// array (`Uint8Array`, `Uint8ClampedArray`, `Int32Array` etc...) or a `DataView`.
+///
+/// Returns `None` if `obj` is neither an `ArrayBuffer` nor a view on an `ArrayBuffer`.
+fn buffer_source_to_bytes(obj: &JsObject, context: &mut Context) -> JsResult<Option<Vec<u8>>> {
+    let bytes = if let Ok(bytes) = JsUint8Array::from_object(obj.clone()) {
+        bytes
+    } else {
+        let (buffer, offset, length) = if JsArrayBuffer::from_object(obj.clone()).is_ok() {
+            let length = obj.get(js_string!("byteLength"), context)?;
+            (JsValue::from(obj.clone()), JsValue::from(0), length)
+        } else if JsTypedArray::from_object(obj.clone()).is_ok()
+            || JsDataView::from_object(obj.clone()).is_ok()
+        {
+            let buffer = obj.get(js_string!("buffer"), context)?;
+            let offset = obj.get(js_string!("byteOffset"), context)?;
+            let length = obj.get(js_string!("byteLength"), context)?;
+            (buffer, offset, length)
+        } else {
+            return Ok(None);
+        };
+        // Creates a byte view on the same memory.
+        let constructor = context
+            .global_object()
+            .get(js_string!("Uint8Array"), context)?;
+        let Some(constructor) = constructor.as_constructor() else {
+            return Err(JsNativeError::typ()
+                .with_message("Uint8Array is not a constructor")
+                .into());
+        };
+        let view = constructor.construct(&[buffer, offset, length], None, context)?;
+        JsUint8Array::from_object(view)?
+    };
+
+    let length = bytes.length(context)?;
+    let mut ret = Vec::with_capacity(length);
+    for i in 0..length {
+        let byte = bytes.get(i, context)?;
+        ret.push(byte.to_uint8(context)?);
+    }
+    Ok(Some(ret))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +476,223 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_integer_to_js_bigint() {
+        let mut context = Context::default();
+
+        // 2^53 - 1 is the largest safe integer.
+        for i in [MAX_SAFE_INTEGER, -MAX_SAFE_INTEGER] {
+            let value = Value::Number(Number::Integer(i));
+            let js = value_to_js(&value, &mut context).unwrap();
+            assert_eq!(js.as_number(), Some(i as f64));
+            assert_eq!(js_to_value(&js, &mut context).unwrap(), value);
+        }
+
+        for i in [1 << 53, (1 << 53) + 1, -(1 << 53) - 1, i64::MAX, i64::MIN] {
+            let value = Value::Number(Number::Integer(i));
+            let js = value_to_js(&value, &mut context).unwrap();
+            assert!(js.is_bigint(), "{i}");
+            assert_eq!(js_to_value(&js, &mut context).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_js_to_value_bigint() {
+        let mut context = Context::default();
+
+        let js = context
+            .eval(boa_engine::Source::from_bytes("9007199254740993n"))
+            .unwrap();
+        let val = js_to_value(&js, &mut context).unwrap();
+        assert_eq!(val, Value::Number(Number::Integer(9007199254740993)));
+
+        let js = context
+            .eval(boa_engine::Source::from_bytes("2n ** 64n"))
+            .unwrap();
+        let val = js_to_value(&js, &mut context).unwrap();
+        assert_eq!(
+            val,
+            Value::Number(Number::BigInteger("18446744073709551616".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_js_to_value_not_finite() {
+        let mut context = Context::default();
+
+        for (source, expected) in [
+            ("NaN", "NaN"),
+            ("0 / 0", "NaN"),
+            ("1 / 0", "Infinity"),
+            ("-1 / 0", "-Infinity"),
+            ("[1, Infinity]", "Infinity"),
+        ] {
+            let js = context
+                .eval(boa_engine::Source::from_bytes(source))
+                .unwrap();
+            let result = js_to_value(&js, &mut context);
+            assert!(
+                matches!(
+                    &result,
+                    Err(JsError::ConversionError { message })
+                        if message == &format!("{expected} can not be converted to a Hurl number")
+                ),
+                "{source}: {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_js_to_value_regexp() {
+        let mut context = Context::default();
+
+        for (source, expected) in [
+            (r"/\d+/", r"\d+"),
+            (r"/^hello$/gim", "(?im)^hello$"),
+            (r#"new RegExp("a.b", "s")"#, "(?s)a.b"),
+        ] {
+            let js = context
+                .eval(boa_engine::Source::from_bytes(source))
+                .unwrap();
+            let Value::Regex(re) = js_to_value(&js, &mut context).unwrap() else {
+                panic!("{source}: expected a regex");
+            };
+            assert_eq!(re.as_str(), expected, "{source}");
+        }
+
+        // Lookbehinds are not supported by Hurl regexes.
+        let js = context
+            .eval(boa_engine::Source::from_bytes(r"/(?<=a)b/"))
+            .unwrap();
+        assert!(matches!(
+            js_to_value(&js, &mut context),
+            Err(JsError::ConversionError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_js_to_value_strict() {
+        let mut context = Context::default();
+        let strict = FromJsOptions { strict: true };
+
+        let js = context
+            .eval(boa_engine::Source::from_bytes("(function (x) { x })"))
+            .unwrap();
+        assert_eq!(
+            js_to_value(&js, &mut context).unwrap(),
+            Value::Object(vec![])
+        );
+        assert!(matches!(
+            js_to_value_with(&js, strict, &mut context),
+            Err(JsError::ConversionError { message })
+                if message == "function can not be converted to a Hurl value"
+        ));
+
+        let js = context
+            .eval(boa_engine::Source::from_bytes("[1, Symbol('s')]"))
+            .unwrap();
+        assert!(matches!(
+            js_to_value_with(&js, strict, &mut context),
+            Err(JsError::ConversionError { message })
+                if message == "symbol can not be converted to a Hurl value"
+        ));
+
+        // Other values are converted the same way in both modes.
+        let js = context
+            .eval(boa_engine::Source::from_bytes("({ a: [1, 'b'] })"))
+            .unwrap();
+        assert_eq!(
+            js_to_value_with(&js, strict, &mut context).unwrap(),
+            js_to_value(&js, &mut context).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_js_to_value_object_skipped_keys() {
+        let mut context = Context::default();
+
+        let source = r#"
+            const obj = { a: 1 };
+            Object.defineProperty(obj, "hidden", { value: 2, enumerable: false });
+            obj[Symbol("s")] = 3;
+            obj.b = 4;
+            obj
+        "#;
+        let js = context
+            .eval(boa_engine::Source::from_bytes(source))
+            .unwrap();
+        let val = js_to_value(&js, &mut context).unwrap();
+        assert_eq!(
+            val,
+            Value::Object(vec![
+                ("a".to_string(), Value::Number(Number::Integer(1))),
+                ("b".to_string(), Value::Number(Number::Integer(4))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_js_to_value_object_throwing_getter() {
+        let mut context = Context::default();
+
+        let source = r#"
+            ({
+                a: 1,
+                get b() { throw new Error("boom"); },
+                c: 3,
+            })
+        "#;
+        let js = context
+            .eval(boa_engine::Source::from_bytes(source))
+            .unwrap();
+        let result = js_to_value(&js, &mut context);
+        assert!(
+            matches!(
+                &result,
+                Err(JsError::ConversionError { message })
+                    if message == "property <b> can not be read: Error: boom"
+            ),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn test_js_to_value_bytes() {
+        let mut context = Context::default();
+
+        let sources = [
+            "new Uint8Array([1, 2, 255])",
+            "new Uint8ClampedArray([1, 2, 300])",
+            "new Uint8Array([0, 1, 2, 255, 4]).buffer.slice(1, 4)",
+            "new Uint8Array([0, 1, 2, 255, 4]).subarray(1, 4)",
+            "new DataView(new Uint8Array([0, 1, 2, 255, 4]).buffer, 1, 3)",
+        ];
+        for source in sources {
+            let js = context
+                .eval(boa_engine::Source::from_bytes(source))
+                .unwrap();
+            let val = js_to_value(&js, &mut context).unwrap();
+            assert_eq!(val, Value::Bytes(vec![1, 2, 255]), "{source}");
+        }
+    }
+
+    #[test]
+    fn test_js_to_value_object_key_order() {
+        let mut context = Context::default();
+
+        let source = r#"({ "b": 1, "10": 2, "a": 3, "2": 4, "-1": 5, "1.5": 6, "01": 7 })"#;
+        let js = context
+            .eval(boa_engine::Source::from_bytes(source))
+            .unwrap();
+        let val = js_to_value(&js, &mut context).unwrap();
+        let keys = match val {
+            Value::Object(pairs) => pairs.into_iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            _ => panic!("expected an object"),
+        };
+        // Integer-like keys first in ascending order, then the others in insertion order.
+        assert_eq!(keys, vec!["2", "10", "b", "a", "-1", "1.5", "01"]);
+    }
+
     #[test]
     fn test_js_to_value_primitives() {
         let mut context = Context::default();
@@ -0,0 +1,139 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Hashing helpers exposed to JavaScript filters.
+//!
+//! This module registers a `crypto` global with digest functions. Inputs can be strings
+//! (hashed as UTF-8) or `Uint8Array`, outputs are lowercase hexadecimal strings:
+//!
+//! ```javascript
+//! crypto.md5("hello");                 // "5d41402abc4b2a76b9719d911017c592"
+//! crypto.sha1("hello");                // "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d"
+//! crypto.sha256("hello");              // "2cf24dba5fb0a30e26e83b2ac5b9e29e..."
+//! crypto.hmacSha256("key", "hello");   // "9307b3b915efb5171ff14d8cb55fbcc7..."
+//! ```
+
+use boa_engine::native_function::NativeFunction;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
+use boa_engine::{js_string, Context, JsArgs, JsResult, JsString, JsValue};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use super::convert::js_to_bytes;
+use crate::runner::hex;
+
+/// Registers the `crypto` global in the global object of `context`.
+pub fn register(context: &mut Context) -> JsResult<()> {
+    let crypto = ObjectInitializer::new(context)
+        .function(NativeFunction::from_fn_ptr(md5), js_string!("md5"), 1)
+        .function(NativeFunction::from_fn_ptr(sha1), js_string!("sha1"), 1)
+        .function(NativeFunction::from_fn_ptr(sha256), js_string!("sha256"), 1)
+        .function(
+            NativeFunction::from_fn_ptr(hmac_sha256),
+            js_string!("hmacSha256"),
+            2,
+        )
+        .build();
+    context.register_global_property(js_string!("crypto"), crypto, Attribute::all())?;
+    Ok(())
+}
+
+fn md5(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let data = js_to_bytes(args.get_or_undefined(0), context)?;
+    let digest = md5::compute(data).to_vec();
+    Ok(hex_digest(&digest))
+}
+
+fn sha1(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let data = js_to_bytes(args.get_or_undefined(0), context)?;
+    let digest = Sha1::digest(data);
+    Ok(hex_digest(&digest))
+}
+
+fn sha256(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let data = js_to_bytes(args.get_or_undefined(0), context)?;
+    let digest = Sha256::digest(data);
+    Ok(hex_digest(&digest))
+}
+
+fn hmac_sha256(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let key = js_to_bytes(args.get_or_undefined(0), context)?;
+    let data = js_to_bytes(args.get_or_undefined(1), context)?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(&key).expect("HMAC can take key of any size");
+    mac.update(&data);
+    let digest = mac.finalize().into_bytes();
+    Ok(hex_digest(&digest))
+}
+
+fn hex_digest(digest: &[u8]) -> JsValue {
+    JsValue::from(JsString::from(hex::encode(digest).as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use boa_engine::{Context, Source};
+
+    use super::*;
+    use crate::runner::js::convert::js_to_value;
+    use crate::runner::Value;
+
+    fn eval(source: &str) -> Value {
+        let mut context = Context::default();
+        register(&mut context).unwrap();
+        let ret = context.eval(Source::from_bytes(source)).unwrap();
+        js_to_value(&ret, &mut context).unwrap()
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            eval(r#"crypto.sha256("")"#),
+            Value::String(
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string()
+            )
+        );
+        assert_eq!(
+            eval(r#"crypto.sha256(new Uint8Array([0x68, 0x65, 0x6c, 0x6c, 0x6f]))"#),
+            eval(r#"crypto.sha256("hello")"#),
+        );
+    }
+
+    #[test]
+    fn test_md5_sha1() {
+        assert_eq!(
+            eval(r#"crypto.md5("")"#),
+            Value::String("d41d8cd98f00b204e9800998ecf8427e".to_string())
+        );
+        assert_eq!(
+            eval(r#"crypto.sha1("")"#),
+            Value::String("da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string())
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256() {
+        assert_eq!(
+            eval(r#"crypto.hmacSha256("key", "The quick brown fox jumps over the lazy dog")"#),
+            Value::String(
+                "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8".to_string()
+            )
+        );
+    }
+}
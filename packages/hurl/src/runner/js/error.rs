@@ -18,19 +18,35 @@
 
 //! JavaScript runtime error types.
 
-use std::fmt;
+use std::{fmt, io};
 
 /// Errors that can occur during JavaScript execution.
 #[derive(Clone, Debug)]
 pub enum JsError {
-    /// Error loading the JavaScript file.
-    FileLoadError { path: String, message: String },
+    /// Error loading the JavaScript file, `kind` being the cause of the error.
+    FileLoadError {
+        path: String,
+        kind: FileLoadErrorKind,
+        message: String,
+    },
 
     /// Error parsing the JavaScript code.
     ParseError { message: String },
 
-    /// The requested filter function was not found.
-    FunctionNotFound { name: String },
+    /// The requested filter function (named with the filter prefix) was not found, `available`
+    /// being the defined filters.
+    FunctionNotFound {
+        function: String,
+        available: Vec<String>,
+    },
+
+    /// The filter function declares more parameters than the supplied arguments (including the
+    /// input). Extra arguments are allowed, as they're simply ignored by JavaScript.
+    ArityMismatch {
+        function: String,
+        expected: usize,
+        got: usize,
+    },
 
     /// Runtime error during JavaScript execution.
     RuntimeError { message: String },
@@ -42,14 +58,31 @@ pub enum JsError {
 impl fmt::Display for JsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            JsError::FileLoadError { path, message } => {
+            JsError::FileLoadError { path, message, .. } => {
                 write!(f, "Failed to load JavaScript file '{path}': {message}")
             }
             JsError::ParseError { message } => {
                 write!(f, "JavaScript parse error: {message}")
             }
-            JsError::FunctionNotFound { name } => {
-                write!(f, "JavaScript filter function 'filter_{name}' not found")
+            JsError::FunctionNotFound {
+                function,
+                available,
+            } => {
+                write!(f, "JavaScript filter function '{function}' not found")?;
+                if !available.is_empty() {
+                    write!(f, " (available filters: {})", available.join(", "))?;
+                }
+                Ok(())
+            }
+            JsError::ArityMismatch {
+                function,
+                expected,
+                got,
+            } => {
+                write!(
+                    f,
+                    "JavaScript filter function '{function}' expects {expected} arguments (including input), got {got}"
+                )
             }
             JsError::RuntimeError { message } => {
                 write!(f, "JavaScript runtime error: {message}")
@@ -62,3 +95,24 @@ impl fmt::Display for JsError {
 }
 
 impl std::error::Error for JsError {}
+
+/// Cause of a JavaScript file loading error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileLoadErrorKind {
+    /// The file doesn't exist.
+    NotFound,
+    /// The file can't be read with the current permissions.
+    PermissionDenied,
+    /// Any other I/O error.
+    Other,
+}
+
+impl From<io::ErrorKind> for FileLoadErrorKind {
+    fn from(kind: io::ErrorKind) -> Self {
+        match kind {
+            io::ErrorKind::NotFound => FileLoadErrorKind::NotFound,
+            io::ErrorKind::PermissionDenied => FileLoadErrorKind::PermissionDenied,
+            _ => FileLoadErrorKind::Other,
+        }
+    }
+}
@@ -21,13 +21,15 @@
 //! This module provides the ability to extend Hurl's filter capabilities
 //! using JavaScript code loaded via the `--jsfilter` CLI option.
 
+mod base64;
 mod client;
 mod convert;
+mod crypto;
 mod error;
 mod response;
 mod runtime;
 
-pub use client::JsClient;
-pub use error::JsError;
+pub use client::{JsClient, SharedGlobal};
+pub use error::{FileLoadErrorKind, JsError};
 pub use response::JsResponse;
-pub use runtime::JsRuntime;
+pub use runtime::{ArgsMode, JsInvocation, JsRuntime, DEFAULT_FILTER_PREFIX};
@@ -21,9 +21,15 @@
 //! The `response` object provides read-only access to the current HTTP response.
 //! Similar to JetBrains HTTP Client's response object.
 
-use boa_engine::{js_string, Context, JsObject, JsResult, JsValue};
+use std::rc::Rc;
 
-use crate::http::Response;
+use boa_engine::native_function::NativeFunction;
+use boa_engine::property::PropertyDescriptor;
+use boa_engine::{js_string, Context, JsNativeError, JsObject, JsResult, JsValue};
+
+use crate::http::{Response, ResponseCookie};
+
+use super::base64;
 
 /// Response object that provides access to HTTP response data.
 ///
@@ -32,10 +38,12 @@ use crate::http::Response;
 pub struct JsResponse {
     /// HTTP status code.
     pub status: u32,
+    /// HTTP reason phrase (e.g. `Not Found`), empty if unknown.
+    pub status_text: String,
     /// Response headers.
     pub headers: Vec<(String, String)>,
-    /// Response body as bytes.
-    pub body: Vec<u8>,
+    /// Response body as bytes, shared with the JavaScript objects built from this response.
+    pub body: Rc<[u8]>,
     /// Content-Type header value.
     pub content_type: Option<String>,
     /// Response URL.
@@ -58,8 +66,9 @@ impl JsResponse {
 
         Self {
             status: response.status,
+            status_text: status_text(response.status).to_string(),
             headers,
-            body: response.body.clone(),
+            body: Rc::from(response.body.as_slice()),
             content_type,
             url: response.url.to_string(),
         }
@@ -69,14 +78,21 @@ impl JsResponse {
     pub fn empty() -> Self {
         Self {
             status: 0,
+            status_text: String::new(),
             headers: Vec::new(),
-            body: Vec::new(),
+            body: Rc::from([]),
             content_type: None,
             url: String::new(),
         }
     }
 
     /// Converts the response to a JavaScript object.
+    ///
+    /// The `body` and `bodyBase64` properties are getters: the body string is only built when a
+    /// filter accesses it, so filters that don't use the body don't pay for large responses.
+    /// Likewise, the body is only parsed by the first call to `json()`, which returns the same
+    /// object on the following calls. These three functions share the body of this response,
+    /// which is never copied.
     pub fn to_js_object(&self, context: &mut Context) -> JsResult<JsObject> {
         let obj = JsObject::with_null_proto();
 
@@ -88,6 +104,14 @@ impl JsResponse {
             context,
         )?;
 
+        // statusText (string)
+        obj.set(
+            js_string!("statusText"),
+            JsValue::from(js_string!(self.status_text.as_str())),
+            false,
+            context,
+        )?;
+
         // url (string)
         obj.set(
             js_string!("url"),
@@ -101,7 +125,12 @@ impl JsResponse {
             Some(ct) => JsValue::from(js_string!(ct.as_str())),
             None => JsValue::null(),
         };
-        obj.set(js_string!("contentType"), content_type_value, false, context)?;
+        obj.set(
+            js_string!("contentType"),
+            content_type_value,
+            false,
+            context,
+        )?;
 
         // headers (object with arrays for multi-value headers)
         let headers_obj = JsObject::with_null_proto();
@@ -111,30 +140,244 @@ impl JsResponse {
                 // First occurrence - create array
                 let arr = boa_engine::object::builtins::JsArray::new(context);
                 arr.push(JsValue::from(js_string!(value.as_str())), context)?;
-                headers_obj.set(js_string!(name.as_str()), JsValue::from(arr), false, context)?;
+                headers_obj.set(
+                    js_string!(name.as_str()),
+                    JsValue::from(arr),
+                    false,
+                    context,
+                )?;
             } else if let Some(arr_obj) = existing.as_object() {
                 // Subsequent occurrence - push to array
                 let arr = boa_engine::object::builtins::JsArray::from_object(arr_obj.clone())?;
                 arr.push(JsValue::from(js_string!(value.as_str())), context)?;
             }
         }
-        obj.set(js_string!("headers"), JsValue::from(headers_obj), false, context)?;
+        obj.set(
+            js_string!("headers"),
+            JsValue::from(headers_obj),
+            false,
+            context,
+        )?;
 
-        // body (string, attempting UTF-8 decode)
-        let body_str = String::from_utf8_lossy(&self.body);
+        // cookies (array of the cookies of the Set-Cookie headers)
+        let cookies = boa_engine::object::builtins::JsArray::new(context);
+        for (name, value) in &self.headers {
+            if !name.eq_ignore_ascii_case("Set-Cookie") {
+                continue;
+            }
+            if let Some(cookie) = ResponseCookie::parse(value) {
+                cookies.push(cookie_to_js_object(&cookie, context)?, context)?;
+            }
+        }
         obj.set(
-            js_string!("body"),
-            JsValue::from(js_string!(body_str.as_ref())),
+            js_string!("cookies"),
+            JsValue::from(cookies),
             false,
             context,
         )?;
 
+        // body (string, attempting UTF-8 decode, built on access)
+        let body = Rc::clone(&self.body);
+        // SAFETY: the closure only captures the body bytes, which contain no traceable types.
+        let getter = unsafe {
+            NativeFunction::from_closure(move |_, _, _| {
+                let body = String::from_utf8_lossy(&body);
+                Ok(JsValue::from(js_string!(body.as_ref())))
+            })
+        }
+        .to_js_function(context.realm());
+        obj.define_property_or_throw(
+            js_string!("body"),
+            PropertyDescriptor::builder()
+                .get(getter)
+                .enumerable(true)
+                .configurable(false),
+            context,
+        )?;
+
+        // bodyBase64 (base64 of the raw body, for binary responses, built on access)
+        let body = Rc::clone(&self.body);
+        // SAFETY: the closure only captures the body bytes, which contain no traceable types.
+        let getter = unsafe {
+            NativeFunction::from_closure(move |_, _, _| {
+                Ok(JsValue::from(base64::encode_bytes(&body)))
+            })
+        }
+        .to_js_function(context.realm());
+        obj.define_property_or_throw(
+            js_string!("bodyBase64"),
+            PropertyDescriptor::builder()
+                .get(getter)
+                .enumerable(true)
+                .configurable(false),
+            context,
+        )?;
+
+        // json() (body parsed as JSON on the first call, then cached)
+        let body = Rc::clone(&self.body);
+        // SAFETY: the closure only captures the body bytes, which contain no traceable types, the
+        // cache object being traced as a capture.
+        let json = unsafe {
+            NativeFunction::from_closure_with_captures(
+                move |_, _, cache: &JsObject, context| {
+                    let cached = cache.get(js_string!("value"), context)?;
+                    if !cached.is_undefined() {
+                        return Ok(cached);
+                    }
+                    let parse = context
+                        .global_object()
+                        .get(js_string!("JSON"), context)?
+                        .to_object(context)?
+                        .get(js_string!("parse"), context)?;
+                    let Some(parse) = parse.as_callable() else {
+                        return Err(JsNativeError::typ()
+                            .with_message("JSON.parse is not a function")
+                            .into());
+                    };
+                    let body = String::from_utf8_lossy(&body);
+                    let value = parse.call(
+                        &JsValue::undefined(),
+                        &[JsValue::from(js_string!(body.as_ref()))],
+                        context,
+                    )?;
+                    cache.set(js_string!("value"), value.clone(), false, context)?;
+                    Ok(value)
+                },
+                JsObject::with_null_proto(),
+            )
+        }
+        .to_js_function(context.realm());
+        obj.set(js_string!("json"), JsValue::from(json), false, context)?;
+
         Ok(obj)
     }
 }
 
+/// Converts a `cookie` to a JavaScript object `{ name, value, domain, path, expires, secure,
+/// httpOnly }`, missing attributes being `null`.
+fn cookie_to_js_object(cookie: &ResponseCookie, context: &mut Context) -> JsResult<JsValue> {
+    let string_or_null = |value: Option<String>| match value {
+        Some(value) => JsValue::from(js_string!(value.as_str())),
+        None => JsValue::null(),
+    };
+    let obj = JsObject::with_null_proto();
+    obj.set(
+        js_string!("name"),
+        js_string!(cookie.name.as_str()),
+        false,
+        context,
+    )?;
+    obj.set(
+        js_string!("value"),
+        js_string!(cookie.value.as_str()),
+        false,
+        context,
+    )?;
+    obj.set(
+        js_string!("domain"),
+        string_or_null(cookie.domain()),
+        false,
+        context,
+    )?;
+    obj.set(
+        js_string!("path"),
+        string_or_null(cookie.path()),
+        false,
+        context,
+    )?;
+    obj.set(
+        js_string!("expires"),
+        string_or_null(cookie.expires()),
+        false,
+        context,
+    )?;
+    obj.set(js_string!("secure"), cookie.has_secure(), false, context)?;
+    obj.set(
+        js_string!("httpOnly"),
+        cookie.has_httponly(),
+        false,
+        context,
+    )?;
+    Ok(obj.into())
+}
+
+/// Returns the standard reason phrase of an HTTP `status` code, or an empty string if unknown.
+///
+/// HTTP/2 and HTTP/3 don't transmit reason phrases, so the phrase is derived from the status code
+/// for every HTTP version.
+fn status_text(status: u32) -> &'static str {
+    match status {
+        100 => "Continue",
+        101 => "Switching Protocols",
+        102 => "Processing",
+        103 => "Early Hints",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        203 => "Non-Authoritative Information",
+        204 => "No Content",
+        205 => "Reset Content",
+        206 => "Partial Content",
+        207 => "Multi-Status",
+        208 => "Already Reported",
+        226 => "IM Used",
+        300 => "Multiple Choices",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        305 => "Use Proxy",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        402 => "Payment Required",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        406 => "Not Acceptable",
+        407 => "Proxy Authentication Required",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        410 => "Gone",
+        411 => "Length Required",
+        412 => "Precondition Failed",
+        413 => "Content Too Large",
+        414 => "URI Too Long",
+        415 => "Unsupported Media Type",
+        416 => "Range Not Satisfiable",
+        417 => "Expectation Failed",
+        418 => "I'm a teapot",
+        421 => "Misdirected Request",
+        422 => "Unprocessable Content",
+        423 => "Locked",
+        424 => "Failed Dependency",
+        425 => "Too Early",
+        426 => "Upgrade Required",
+        428 => "Precondition Required",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        451 => "Unavailable For Legal Reasons",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        505 => "HTTP Version Not Supported",
+        506 => "Variant Also Negotiates",
+        507 => "Insufficient Storage",
+        508 => "Loop Detected",
+        510 => "Not Extended",
+        511 => "Network Authentication Required",
+        _ => "",
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use boa_engine::property::Attribute;
+    use boa_engine::Source;
+
     use super::*;
 
     #[test]
@@ -142,12 +385,13 @@ mod tests {
         let mut context = Context::default();
         let response = JsResponse {
             status: 200,
+            status_text: "OK".to_string(),
             headers: vec![
                 ("Content-Type".to_string(), "application/json".to_string()),
                 ("X-Custom".to_string(), "value1".to_string()),
                 ("X-Custom".to_string(), "value2".to_string()),
             ],
-            body: b"hello".to_vec(),
+            body: Rc::from(b"hello".as_slice()),
             content_type: Some("application/json".to_string()),
             url: "http://example.com".to_string(),
         };
@@ -172,4 +416,64 @@ mod tests {
             Some("hello".to_string())
         );
     }
+
+    #[test]
+    fn test_response_cookies() {
+        let mut context = Context::default();
+        let response = JsResponse {
+            headers: vec![
+                (
+                    "Set-Cookie".to_string(),
+                    "session=abc123; Domain=example.com; Path=/; Secure; HttpOnly".to_string(),
+                ),
+                (
+                    "set-cookie".to_string(),
+                    "theme=dark; Expires=Wed, 21 Oct 2026 07:28:00 GMT".to_string(),
+                ),
+            ],
+            ..JsResponse::empty()
+        };
+        let js_obj = response.to_js_object(&mut context).unwrap();
+        context
+            .register_global_property(js_string!("response"), js_obj, Attribute::all())
+            .unwrap();
+
+        let source = r#"
+            response.cookies.map(c =>
+                [c.name, c.value, c.domain, c.path, c.expires, c.secure, c.httpOnly].join("|")
+            ).join("\n")
+        "#;
+        let result = context.eval(Source::from_bytes(source)).unwrap();
+        assert_eq!(
+            result.as_string().map(|s| s.to_std_string_escaped()),
+            Some(
+                "session|abc123|example.com|/||true|true\n\
+                 theme|dark|||Wed, 21 Oct 2026 07:28:00 GMT|false|false"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_status_text() {
+        assert_eq!(status_text(200), "OK");
+        assert_eq!(status_text(404), "Not Found");
+        assert_eq!(status_text(599), "");
+    }
+
+    #[test]
+    fn test_response_body_base64() {
+        let mut context = Context::default();
+        let response = JsResponse {
+            body: Rc::from([0x89, b'P', b'N', b'G', 0xff, 0x00]),
+            ..JsResponse::empty()
+        };
+
+        let js_obj = response.to_js_object(&mut context).unwrap();
+        let body = js_obj.get(js_string!("bodyBase64"), &mut context).unwrap();
+        assert_eq!(
+            body.as_string().map(|s| s.to_std_string_escaped()),
+            Some("iVBOR/8A".to_string())
+        );
+    }
 }
@@ -18,16 +18,56 @@
 
 //! JavaScript runtime for executing custom filters.
 
+use std::collections::HashMap;
+use std::fmt;
 use std::path::Path;
+use std::rc::Rc;
 
-use boa_engine::{js_string, Context, JsValue, Source};
+use boa_engine::builtins::promise::PromiseState;
+use boa_engine::module::SimpleModuleLoader;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::object::IntegrityLevel;
+use boa_engine::property::PropertyKey;
+use boa_engine::{js_string, Context, JsObject, JsValue, Module, Script, Source};
 
 use crate::runner::Value;
 
-use super::client::JsClient;
-use super::convert::{js_to_value, value_to_js};
-use super::error::JsError;
+use super::client::{JsClient, SharedGlobal};
+use super::convert::{js_to_value_with, value_to_js_with, FromJsOptions, ToJsOptions};
+use super::error::{FileLoadErrorKind, JsError};
 use super::response::JsResponse;
+use super::{base64, crypto};
+
+/// Default prefix of the names of the filter functions.
+pub const DEFAULT_FILTER_PREFIX: &str = "filter_";
+
+/// Position of a `jsfilter` invocation in a Hurl file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsInvocation {
+    /// Name of the Hurl file, if any.
+    pub filename: Option<String>,
+    /// Line of the filter in the Hurl file.
+    pub line: usize,
+}
+
+impl fmt::Display for JsInvocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.filename {
+            Some(filename) => write!(f, "{filename}:{}", self.line),
+            None => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+/// Calling convention of the filter functions, see [`JsRuntime::set_args_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArgsMode {
+    /// Filter arguments are passed as positional parameters: `filter_foo(input, arg1, arg2)`.
+    #[default]
+    Positional,
+    /// Filter arguments are passed as a single array parameter: `filter_foo(input, [arg1, arg2])`.
+    Array,
+}
 
 /// JavaScript runtime for executing custom filter functions.
 ///
@@ -39,19 +79,90 @@ pub struct JsRuntime {
     context: Context,
     client: JsClient,
     response: JsResponse,
+    /// JavaScript object of `response`, built on the first filter call following a new response.
+    response_obj: Option<JsObject>,
+    /// Storage backing `client.global` when it's shared with other runtimes.
+    shared_global: Option<SharedGlobal>,
+    /// Position of the filter being called, added to the errors thrown by the filter.
+    invocation: Option<JsInvocation>,
+    /// Static configuration, exposed as the read-only `config` global object.
+    config: HashMap<String, Value>,
+    /// Whether filters return `[result, error]` tuples, see [`JsRuntime::set_tuple_results`].
+    tuple_results: bool,
+    /// Whether filters returning `undefined` leave their input unchanged, see
+    /// [`JsRuntime::set_undefined_unchanged`].
+    undefined_unchanged: bool,
+    /// Options of the conversion of the filters input and arguments.
+    to_js_options: ToJsOptions,
+    /// Options of the conversion of the values returned by filters.
+    from_js_options: FromJsOptions,
+    /// Prefix of the names of the filter functions.
+    filter_prefix: String,
+    /// Calling convention of the filter functions.
+    args_mode: ArgsMode,
 }
 
 impl JsRuntime {
     /// Creates a new JavaScript runtime.
+    ///
+    /// ES module imports are disabled in this runtime, see [`JsRuntime::with_module_root`].
     pub fn new() -> Self {
-        let context = Context::default();
+        Self::from_context(Context::default())
+    }
+
+    /// Creates a new JavaScript runtime, resolving ES module imports relative to `root`.
+    pub fn with_module_root(root: &Path) -> Result<Self, JsError> {
+        let loader = SimpleModuleLoader::new(root).map_err(|e| JsError::FileLoadError {
+            path: root.display().to_string(),
+            kind: FileLoadErrorKind::Other,
+            message: e.to_string(),
+        })?;
+        let context = Context::builder()
+            .module_loader(Rc::new(loader))
+            .build()
+            .map_err(|e| JsError::RuntimeError {
+                message: e.to_string(),
+            })?;
+        Ok(Self::from_context(context))
+    }
+
+    /// Creates a new JavaScript runtime from a `context`.
+    ///
+    /// Helper globals (`atob`, `btoa`, `Hurl.base64`, `crypto`) are registered once here, so they're
+    /// also available to the top-level code of loaded scripts.
+    fn from_context(mut context: Context) -> Self {
+        base64::register(&mut context).expect("base64 globals should be registered");
+        crypto::register(&mut context).expect("crypto global should be registered");
         Self {
             context,
             client: JsClient::new(),
             response: JsResponse::empty(),
+            response_obj: None,
+            shared_global: None,
+            invocation: None,
+            config: HashMap::new(),
+            tuple_results: false,
+            undefined_unchanged: false,
+            to_js_options: ToJsOptions::default(),
+            from_js_options: FromJsOptions::default(),
+            filter_prefix: DEFAULT_FILTER_PREFIX.to_string(),
+            args_mode: ArgsMode::default(),
         }
     }
 
+    /// Creates a new JavaScript runtime and loads the JavaScript file `path`.
+    ///
+    /// ES module imports are resolved relative to the directory of `path`.
+    pub fn from_file(path: &Path) -> Result<Self, JsError> {
+        let root = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let mut runtime = JsRuntime::with_module_root(root)?;
+        runtime.load_file(path)?;
+        Ok(runtime)
+    }
+
     /// Loads and executes a JavaScript file.
     ///
     /// The file should contain filter function definitions in the form:
@@ -60,24 +171,116 @@ impl JsRuntime {
     ///     return transformedValue;
     /// }
     /// ```
+    ///
+    /// The file can also be an ES module, importing helpers from sibling files with
+    /// `import { helper } from './util.js'` and exporting its filter functions.
     pub fn load_file(&mut self, path: &Path) -> Result<(), JsError> {
         let source = std::fs::read_to_string(path).map_err(|e| JsError::FileLoadError {
             path: path.display().to_string(),
+            kind: e.kind().into(),
             message: e.to_string(),
         })?;
 
-        self.context
-            .eval(Source::from_bytes(&source))
-            .map_err(|e| JsError::ParseError {
-                message: e.to_string(),
-            })?;
+        self.load_source(&source, &path.display().to_string())
+    }
 
+    /// Loads and executes an in-memory JavaScript source.
+    ///
+    /// `name` is a logical name for this source (a file path, `<inline>` etc...), used in
+    /// error messages.
+    ///
+    /// The source is first evaluated as a classic script. If it can't be parsed as a script
+    /// (because it uses `import` or `export` declarations for instance), it's evaluated as an
+    /// ES module, and the functions it exports are made available as globals.
+    pub fn load_source(&mut self, source: &str, name: &str) -> Result<(), JsError> {
+        let to_error = |e: boa_engine::JsError| JsError::ParseError {
+            message: format!("{name}: {e}"),
+        };
+
+        if let Ok(script) = Script::parse(Source::from_bytes(source), None, &mut self.context) {
+            script.evaluate(&mut self.context).map_err(to_error)?;
+            return Ok(());
+        }
+
+        let module =
+            Module::parse(Source::from_bytes(source), None, &mut self.context).map_err(to_error)?;
+        let promise = module.load_link_evaluate(&mut self.context);
+        self.context.run_jobs();
+        if let PromiseState::Rejected(reason) = promise.state() {
+            return Err(to_error(boa_engine::JsError::from_opaque(reason)));
+        }
+
+        // Exports of the module are copied to the global object, so filter functions can be
+        // looked up the same way as in a classic script.
+        let namespace = module.namespace(&mut self.context);
+        let global = self.context.global_object();
+        let keys = namespace
+            .own_property_keys(&mut self.context)
+            .map_err(to_error)?;
+        for key in keys {
+            if matches!(key, PropertyKey::Symbol(_)) {
+                continue;
+            }
+            let value = namespace
+                .get(key.clone(), &mut self.context)
+                .map_err(to_error)?;
+            global
+                .set(key, value, false, &mut self.context)
+                .map_err(to_error)?;
+        }
         Ok(())
     }
 
+    /// Returns the names of the filters defined in this runtime.
+    ///
+    /// Filters are global functions whose name begins with the filter prefix (`filter_` by
+    /// default), the returned names are stripped of this prefix.
+    pub fn list_filters(&mut self) -> Vec<String> {
+        let global = self.context.global_object();
+        let Ok(keys) = global.own_property_keys(&mut self.context) else {
+            return vec![];
+        };
+        let mut names = vec![];
+        for key in keys {
+            let PropertyKey::String(key) = key else {
+                continue;
+            };
+            let key = key.to_std_string_escaped();
+            let Some(name) = key.strip_prefix(self.filter_prefix.as_str()) else {
+                continue;
+            };
+            let is_callable = global
+                .get(js_string!(key.as_str()), &mut self.context)
+                .is_ok_and(|value| value.is_callable());
+            if is_callable {
+                names.push(name.to_string());
+            }
+        }
+        names
+    }
+
+    /// Returns `true` if a filter function `name` is defined (a function named `filter_<name>`
+    /// with the default prefix).
+    pub fn has_filter(&mut self, name: &str) -> bool {
+        let function_name = format!("{}{name}", self.filter_prefix);
+        self.context
+            .global_object()
+            .get(js_string!(function_name.as_str()), &mut self.context)
+            .is_ok_and(|value| value.is_callable())
+    }
+
     /// Updates the `response` global object with new response data.
     pub fn set_response(&mut self, response: JsResponse) {
         self.response = response;
+        self.response_obj = None;
+    }
+
+    /// Records a new `response`: it becomes the `response` global object and it's added to the
+    /// `client.history` array.
+    pub fn push_response(&mut self, response: JsResponse) {
+        self.client.push_history(&response);
+        self.response = response;
+        self.response_obj = None;
     }
 
     /// Returns a reference to the client object.
@@ -90,9 +293,81 @@ impl JsRuntime {
         &mut self.client
     }
 
+    /// Backs the `client.global` object of this runtime with a `shared_global` storage.
+    ///
+    /// Runtimes sharing the same storage, even on different threads, see each other updates of
+    /// `client.global`. The storage is locked for the whole execution of a filter so concurrent
+    /// updates are never lost: the trade-off is that filters calls of all these runtimes are
+    /// serialized.
+    pub fn set_shared_global(&mut self, shared_global: Option<SharedGlobal>) {
+        self.shared_global = shared_global;
+    }
+
+    /// Sets the static configuration of the filters, exposed as the `config` global object.
+    ///
+    /// Contrary to `client.global`, `config` is frozen: filters can't modify it.
+    pub fn set_config(&mut self, config: HashMap<String, Value>) {
+        self.config = config;
+    }
+
+    /// Enables the `[result, error]` convention for the values returned by filters.
+    ///
+    /// When enabled, a filter returning a two-element array whose second element is a string
+    /// fails with this string as error message. Any other two-element array is a success, the
+    /// filter result being the first element. Other values are returned as is. This convention
+    /// is opt-in as it changes the meaning of filters returning a pair.
+    pub fn set_tuple_results(&mut self, tuple_results: bool) {
+        self.tuple_results = tuple_results;
+    }
+
+    /// Makes filters returning `undefined` leave their input unchanged.
+    ///
+    /// By default, `undefined` is converted to `null`, like `null` itself. When enabled, a filter
+    /// can return `undefined` (or simply not return anything) to pass its input through, while
+    /// returning `null` still sets the value to null.
+    pub fn set_undefined_unchanged(&mut self, undefined_unchanged: bool) {
+        self.undefined_unchanged = undefined_unchanged;
+    }
+
+    /// Passes dates to filters as JavaScript `Date` objects, instead of ISO 8601 strings.
+    ///
+    /// `Date` objects returned by filters are always converted back to Hurl dates.
+    pub fn set_date_objects(&mut self, date_objects: bool) {
+        self.to_js_options.date_objects = date_objects;
+    }
+
+    /// Rejects the values returned by filters that have no Hurl counterpart (functions, symbols),
+    /// instead of converting them to an object or a string.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.from_js_options.strict = strict;
+    }
+
+    /// Sets the prefix of the names of the filter functions, `filter_` by default.
+    ///
+    /// For instance, with a `hurl_` prefix, `jsfilter add` calls the function `hurl_add`.
+    pub fn set_filter_prefix(&mut self, prefix: &str) {
+        self.filter_prefix = prefix.to_string();
+    }
+
+    /// Sets the calling convention of the filter functions, [`ArgsMode::Positional`] by default.
+    ///
+    /// With [`ArgsMode::Array`], filters always have two parameters, the input and the array of
+    /// the arguments, which is convenient for filters with a variable number of arguments.
+    pub fn set_args_mode(&mut self, args_mode: ArgsMode) {
+        self.args_mode = args_mode;
+    }
+
+    /// Sets the position in the Hurl file of the next filter calls.
+    ///
+    /// When a filter throws, this position is appended to the error message, as in
+    /// `(invoked from tests/api.hurl:12)`.
+    pub fn set_invocation(&mut self, invocation: Option<JsInvocation>) {
+        self.invocation = invocation;
+    }
+
     /// Calls a filter function with the given input and arguments.
     ///
-    /// The function is looked up by name with the `filter_` prefix.
+    /// The function is looked up by name with the filter prefix (`filter_` by default).
     /// For example, calling `call_filter("add", ...)` will look for a function
     /// named `filter_add`.
     pub fn call_filter(
@@ -101,7 +376,44 @@ impl JsRuntime {
         input: &Value,
         args: &[Value],
     ) -> Result<Value, JsError> {
-        let function_name = format!("filter_{name}");
+        self.with_shared_global(|runtime| runtime.call_filter_local(name, input, args))
+    }
+
+    /// Evaluates a bare JavaScript expression `expr`, `input` being bound to the `input` variable.
+    ///
+    /// The expression sees the same globals as the filter functions (`client`, `response`,
+    /// `config`...), without having to define a named function. For example, `input * 2 +
+    /// response.status` evaluates to 242 with an input of 21 and a 200 response.
+    pub fn eval_expression(&mut self, expr: &str, input: &Value) -> Result<Value, JsError> {
+        self.with_shared_global(|runtime| runtime.eval_expression_local(expr, input))
+    }
+
+    /// Runs `f` on this runtime, synchronizing `client.global` with the shared storage if any.
+    fn with_shared_global(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<Value, JsError>,
+    ) -> Result<Value, JsError> {
+        let Some(shared_global) = self.shared_global.clone() else {
+            return f(self);
+        };
+        // A poisoned lock only means that another thread has panicked while running a filter: the
+        // storage itself is still consistent.
+        let mut global = shared_global.lock().unwrap_or_else(|e| e.into_inner());
+        self.client.global = global.clone();
+        let result = f(self);
+        *global = self.client.global.clone();
+        result
+    }
+
+    /// Calls a filter function with the given input and arguments, using the `client.global`
+    /// object of this runtime.
+    fn call_filter_local(
+        &mut self,
+        name: &str,
+        input: &Value,
+        args: &[Value],
+    ) -> Result<Value, JsError> {
+        let function_name = format!("{}{name}", self.filter_prefix);
 
         // Set up global objects
         self.setup_globals()?;
@@ -114,45 +426,132 @@ impl JsRuntime {
                 message: e.to_string(),
             })?;
 
-        if !func_value.is_callable() {
+        let Some(func) = func_value.as_callable() else {
             return Err(JsError::FunctionNotFound {
-                name: name.to_string(),
+                function: function_name,
+                available: self.list_filters(),
             });
-        }
+        };
 
-        let func = func_value
-            .as_callable()
-            .ok_or_else(|| JsError::FunctionNotFound {
-                name: name.to_string(),
-            })?;
+        // Check that the function doesn't expect more parameters than supplied. The `length` of a
+        // function is its count of parameters before the first one with a default value or a rest
+        // parameter. Extra arguments are allowed, JavaScript simply ignores them.
+        let expected = func
+            .get(js_string!("length"), &mut self.context)
+            .ok()
+            .and_then(|length| length.as_number())
+            .unwrap_or(0.0) as usize;
+        let got = match self.args_mode {
+            ArgsMode::Positional => args.len() + 1,
+            ArgsMode::Array => 2,
+        };
+        if got < expected {
+            return Err(JsError::ArityMismatch {
+                function: function_name,
+                expected,
+                got,
+            });
+        }
 
         // Convert input and arguments to JavaScript values
-        let js_input =
-            value_to_js(input, &mut self.context).map_err(|e| JsError::ConversionError {
+        let options = self.to_js_options;
+        let js_input = value_to_js_with(input, options, &mut self.context).map_err(|e| {
+            JsError::ConversionError {
                 message: e.to_string(),
-            })?;
+            }
+        })?;
 
         let mut js_args = vec![js_input];
         for arg in args {
-            let js_arg =
-                value_to_js(arg, &mut self.context).map_err(|e| JsError::ConversionError {
+            let js_arg = value_to_js_with(arg, options, &mut self.context).map_err(|e| {
+                JsError::ConversionError {
                     message: e.to_string(),
-                })?;
+                }
+            })?;
             js_args.push(js_arg);
         }
+        if self.args_mode == ArgsMode::Array {
+            let array = JsArray::from_iter(js_args.drain(1..), &mut self.context);
+            js_args.push(array.into());
+        }
 
         // Call the function
         let result = func
             .call(&JsValue::undefined(), &js_args, &mut self.context)
-            .map_err(|e| JsError::RuntimeError {
-                message: e.to_string(),
+            .map_err(|e| {
+                let message = match &self.invocation {
+                    Some(invocation) => format!("{e} (invoked from {invocation})"),
+                    None => e.to_string(),
+                };
+                JsError::RuntimeError { message }
             })?;
 
         // Update client from any changes made in JavaScript
         self.update_client_from_js()?;
 
+        if self.undefined_unchanged && result.is_undefined() {
+            return Ok(input.clone());
+        }
+
         // Convert result back to Hurl value
-        js_to_value(&result, &mut self.context)
+        let value = js_to_value_with(&result, self.from_js_options, &mut self.context)?;
+        if !self.tuple_results {
+            return Ok(value);
+        }
+        match value {
+            Value::List(mut items) if items.len() == 2 => match items.pop() {
+                Some(Value::String(message)) => Err(JsError::RuntimeError { message }),
+                _ => Ok(items.pop().unwrap()),
+            },
+            value => Ok(value),
+        }
+    }
+
+    /// Evaluates a JavaScript expression `expr` with an `input` parameter, using the `client.global`
+    /// object of this runtime.
+    ///
+    /// The expression is the body of an anonymous function taking `input` as its only parameter,
+    /// so no global is touched and `expr` can't be a statement.
+    fn eval_expression_local(&mut self, expr: &str, input: &Value) -> Result<Value, JsError> {
+        // The expression is on its own lines so that a trailing comment doesn't hide the end of
+        // the function.
+        let source = format!("(function (input) {{ return (\n{expr}\n); }})");
+        let script =
+            Script::parse(Source::from_bytes(&source), None, &mut self.context).map_err(|e| {
+                JsError::ParseError {
+                    message: e.to_string(),
+                }
+            })?;
+        let func = script
+            .evaluate(&mut self.context)
+            .map_err(|e| JsError::RuntimeError {
+                message: e.to_string(),
+            })?;
+        let Some(func) = func.as_callable() else {
+            return Err(JsError::ParseError {
+                message: format!("invalid expression {expr}"),
+            });
+        };
+
+        self.setup_globals()?;
+        let js_input =
+            value_to_js_with(input, self.to_js_options, &mut self.context).map_err(|e| {
+                JsError::ConversionError {
+                    message: e.to_string(),
+                }
+            })?;
+        let result = func
+            .call(&JsValue::undefined(), &[js_input], &mut self.context)
+            .map_err(|e| {
+                let message = match &self.invocation {
+                    Some(invocation) => format!("{e} (invoked from {invocation})"),
+                    None => e.to_string(),
+                };
+                JsError::RuntimeError { message }
+            })?;
+
+        self.update_client_from_js()?;
+        js_to_value_with(&result, self.from_js_options, &mut self.context)
     }
 
     /// Sets up the `client` and `response` global objects.
@@ -177,13 +576,40 @@ impl JsRuntime {
                 message: e.to_string(),
             })?;
 
-        // Set up response global
-        let response_obj = self
-            .response
-            .to_js_object(&mut self.context)
+        // Set up config global
+        let config_obj = self.config_to_js().map_err(|e| JsError::ConversionError {
+            message: e.to_string(),
+        })?;
+        global
+            .set(
+                js_string!("config"),
+                JsValue::from(config_obj),
+                false,
+                &mut self.context,
+            )
             .map_err(|e| JsError::RuntimeError {
                 message: e.to_string(),
             })?;
+
+        // Set up response global, reusing the object of the previous calls if the response
+        // hasn't changed. As it's shared between calls, the object is frozen.
+        let response_obj = match &self.response_obj {
+            Some(response_obj) => response_obj.clone(),
+            None => {
+                let response_obj = self
+                    .response
+                    .to_js_object(&mut self.context)
+                    .and_then(|obj| {
+                        obj.set_integrity_level(IntegrityLevel::Frozen, &mut self.context)?;
+                        Ok(obj)
+                    })
+                    .map_err(|e| JsError::RuntimeError {
+                        message: e.to_string(),
+                    })?;
+                self.response_obj = Some(response_obj.clone());
+                response_obj
+            }
+        };
         global
             .set(
                 js_string!("response"),
@@ -198,6 +624,17 @@ impl JsRuntime {
         Ok(())
     }
 
+    /// Converts the static configuration to a frozen JavaScript object.
+    fn config_to_js(&mut self) -> boa_engine::JsResult<JsObject> {
+        let obj = JsObject::with_null_proto();
+        for (key, value) in &self.config {
+            let js_value = value_to_js_with(value, self.to_js_options, &mut self.context)?;
+            obj.set(js_string!(key.as_str()), js_value, false, &mut self.context)?;
+        }
+        obj.set_integrity_level(IntegrityLevel::Frozen, &mut self.context)?;
+        Ok(obj)
+    }
+
     /// Updates the internal client state from the JavaScript global.
     fn update_client_from_js(&mut self) -> Result<(), JsError> {
         let global = self.context.global_object();
@@ -228,6 +665,7 @@ impl Default for JsRuntime {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::runner::filter::write_js_filters;
     use crate::runner::Number;
 
     #[test]
@@ -277,6 +715,240 @@ mod tests {
         assert_eq!(result, Value::String("HELLO".to_string()));
     }
 
+    #[test]
+    fn test_load_source() {
+        let mut runtime = JsRuntime::new();
+
+        let source = r#"
+            function filter_double(input) {
+                return input * 2;
+            }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+
+        let result = runtime
+            .call_filter("double", &Value::Number(Number::Integer(21)), &[])
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(42)));
+
+        let result = runtime.load_source("function filter_bad( {", "<inline>");
+        assert!(
+            matches!(result, Err(JsError::ParseError { message }) if message.starts_with("<inline>: "))
+        );
+    }
+
+    #[test]
+    fn test_load_module_file() {
+        let main = write_js_filters(
+            r#"
+            import { shout } from './util.js';
+            export function filter_shout(input) {
+                return shout(input);
+            }
+            "#,
+        );
+        let dir = main.parent().unwrap();
+        std::fs::write(
+            dir.join("util.js"),
+            "export function shout(s) { return s.toUpperCase() + '!'; }",
+        )
+        .unwrap();
+
+        let mut runtime = JsRuntime::with_module_root(dir).unwrap();
+        runtime.load_file(&main).unwrap();
+
+        let result = runtime
+            .call_filter("shout", &Value::String("hello".to_string()), &[])
+            .unwrap();
+        assert_eq!(result, Value::String("HELLO!".to_string()));
+    }
+
+    #[test]
+    fn test_load_file_not_found() {
+        let path = std::env::temp_dir().join("hurl_js_missing/filters.js");
+        let mut runtime = JsRuntime::new();
+
+        let result = runtime.load_file(&path);
+        assert!(matches!(
+            result,
+            Err(JsError::FileLoadError {
+                kind: FileLoadErrorKind::NotFound,
+                ..
+            })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_file_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = write_js_filters("function filter_id(input) { return input; }");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o000)).unwrap();
+
+        // A privileged user (like root) can still read the file: there is nothing to check then.
+        if std::fs::read(&path).is_ok() {
+            return;
+        }
+        let mut runtime = JsRuntime::new();
+        let result = runtime.load_file(&path);
+        assert!(matches!(
+            result,
+            Err(JsError::FileLoadError {
+                kind: FileLoadErrorKind::PermissionDenied,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_list_filters() {
+        let mut runtime = JsRuntime::new();
+
+        let source = r#"
+            function filter_add(input, n) { return input + n; }
+            function filter_upper(input) { return input.toUpperCase(); }
+            var filter_twice = function (input) { return input + input; };
+            var filter_not_a_function = 42;
+            function helper(input) { return input; }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+
+        assert_eq!(
+            runtime.list_filters(),
+            vec!["add".to_string(), "upper".to_string(), "twice".to_string()]
+        );
+
+        let result = runtime.call_filter("lower", &Value::String("A".to_string()), &[]);
+        assert!(
+            matches!(result, Err(JsError::FunctionNotFound { function, available }) if function == "filter_lower" && available.len() == 3)
+        );
+    }
+
+    #[test]
+    fn test_has_filter() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_add(input, n) { return input + n; }
+            var filter_not_a_function = 42;
+            function helper(input) { return input; }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+
+        assert!(runtime.has_filter("add"));
+        assert!(!runtime.has_filter("not_a_function"));
+        assert!(!runtime.has_filter("helper"));
+        assert!(!runtime.has_filter("unknown"));
+    }
+
+    #[test]
+    fn test_filter_prefix() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function hurl_add(input, n) { return input + n; }
+            function filter_upper(input) { return input.toUpperCase(); }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+
+        runtime.set_filter_prefix("hurl_");
+        assert_eq!(runtime.list_filters(), vec!["add".to_string()]);
+        assert!(runtime.has_filter("add"));
+        assert!(!runtime.has_filter("upper"));
+        let result = runtime
+            .call_filter(
+                "add",
+                &Value::Number(Number::Integer(1)),
+                &[Value::Number(Number::Integer(2))],
+            )
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(3)));
+        let result = runtime.call_filter("upper", &Value::String("a".to_string()), &[]);
+        assert!(
+            matches!(result, Err(JsError::FunctionNotFound { function, .. }) if function == "hurl_upper")
+        );
+    }
+
+    #[test]
+    fn test_args_mode() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_between(input, min, max) {
+                return input >= min && input <= max;
+            }
+            function filter_between_array(input, args) {
+                return Array.isArray(args) && input >= args[0] && input <= args[1];
+            }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+        let input = Value::Number(Number::Integer(5));
+        let args = [
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(10)),
+        ];
+
+        let result = runtime.call_filter("between", &input, &args).unwrap();
+        assert_eq!(result, Value::Bool(true));
+        let result = runtime.call_filter("between_array", &input, &args).unwrap();
+        assert_eq!(result, Value::Bool(false));
+
+        runtime.set_args_mode(ArgsMode::Array);
+        let result = runtime.call_filter("between_array", &input, &args).unwrap();
+        assert_eq!(result, Value::Bool(true));
+        let result = runtime.call_filter("between", &input, &args);
+        assert!(matches!(
+            result,
+            Err(JsError::ArityMismatch {
+                expected: 3,
+                got: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_arity_mismatch() {
+        let mut runtime = JsRuntime::new();
+
+        let source = r#"
+            function filter_add(input, n) {
+                return input + n;
+            }
+            function filter_add_default(input, n = 1) {
+                return input + n;
+            }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+
+        let result = runtime.call_filter("add", &Value::Number(Number::Integer(10)), &[]);
+        assert!(matches!(
+            result,
+            Err(JsError::ArityMismatch {
+                expected: 2,
+                got: 1,
+                ..
+            })
+        ));
+
+        // Extra arguments are ignored.
+        let result = runtime
+            .call_filter(
+                "add",
+                &Value::Number(Number::Integer(10)),
+                &[
+                    Value::Number(Number::Integer(5)),
+                    Value::Number(Number::Integer(6)),
+                ],
+            )
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(15)));
+
+        // Parameters with default values are optional.
+        let result = runtime
+            .call_filter("add_default", &Value::Number(Number::Integer(10)), &[])
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(11)));
+    }
+
     #[test]
     fn test_filter_not_found() {
         let mut runtime = JsRuntime::new();
@@ -321,22 +993,251 @@ mod tests {
         assert_eq!(result2, Value::Number(Number::Integer(102)));
     }
 
+    #[test]
+    fn test_client_shared_global() {
+        let shared_global = SharedGlobal::default();
+        let source = r#"
+            function filter_increment(input) {
+                if (!client.global.counter) {
+                    client.global.counter = 0;
+                }
+                client.global.counter++;
+                return input;
+            }
+        "#;
+
+        let threads = (0..2)
+            .map(|_| {
+                let shared_global = shared_global.clone();
+                std::thread::spawn(move || {
+                    let mut runtime = JsRuntime::new();
+                    runtime.set_shared_global(Some(shared_global));
+                    runtime.load_source(source, "increment.js").unwrap();
+                    for _ in 0..50 {
+                        runtime
+                            .call_filter("increment", &Value::Number(Number::Integer(0)), &[])
+                            .unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let global = shared_global.lock().unwrap();
+        assert_eq!(
+            global.get("counter"),
+            Some(&Value::Number(Number::Integer(100)))
+        );
+    }
+
+    #[test]
+    fn test_invocation_in_error() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_fail(input) {
+                throw new Error("invalid input");
+            }
+        "#;
+        runtime.load_source(source, "fail.js").unwrap();
+
+        runtime.set_invocation(Some(JsInvocation {
+            filename: Some("tests/api.hurl".to_string()),
+            line: 12,
+        }));
+        let result = runtime.call_filter("fail", &Value::Null, &[]);
+        assert!(matches!(
+            result,
+            Err(JsError::RuntimeError { message })
+                if message == "Error: invalid input (invoked from tests/api.hurl:12)"
+        ));
+
+        runtime.set_invocation(None);
+        let result = runtime.call_filter("fail", &Value::Null, &[]);
+        assert!(matches!(
+            result,
+            Err(JsError::RuntimeError { message }) if message == "Error: invalid input"
+        ));
+    }
+
+    #[test]
+    fn test_config_access() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_url(input) {
+                return config.baseUrl + input;
+            }
+            function filter_set_url(input) {
+                "use strict";
+                config.baseUrl = input;
+                return input;
+            }
+        "#;
+        runtime.load_source(source, "config.js").unwrap();
+
+        let config = HashMap::from([(
+            "baseUrl".to_string(),
+            Value::String("https://example.org".to_string()),
+        )]);
+        runtime.set_config(config);
+        let result = runtime
+            .call_filter("url", &Value::String("/api".to_string()), &[])
+            .unwrap();
+        assert_eq!(result, Value::String("https://example.org/api".to_string()));
+
+        // config is read-only.
+        let result = runtime.call_filter("set_url", &Value::String("/".to_string()), &[]);
+        assert!(matches!(result, Err(JsError::RuntimeError { .. })));
+    }
+
+    #[test]
+    fn test_tuple_results() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_parse_int(input) {
+                const n = parseInt(input);
+                return isNaN(n) ? [null, `<${input}> is not an integer`] : [n, null];
+            }
+        "#;
+        runtime.load_source(source, "tuple.js").unwrap();
+
+        // Without the tuple convention, the array is returned as is.
+        let result = runtime
+            .call_filter("parse_int", &Value::String("42".to_string()), &[])
+            .unwrap();
+        assert_eq!(
+            result,
+            Value::List(vec![Value::Number(Number::Integer(42)), Value::Null])
+        );
+
+        runtime.set_tuple_results(true);
+        let result = runtime
+            .call_filter("parse_int", &Value::String("42".to_string()), &[])
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(42)));
+
+        let result = runtime.call_filter("parse_int", &Value::String("abc".to_string()), &[]);
+        assert!(matches!(
+            result,
+            Err(JsError::RuntimeError { message }) if message == "<abc> is not an integer"
+        ));
+    }
+
+    #[test]
+    fn test_undefined_unchanged() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_positive(input) {
+                if (input < 0) {
+                    return null;
+                }
+            }
+        "#;
+        runtime.load_source(source, "undefined.js").unwrap();
+        let positive = Value::Number(Number::Integer(1));
+        let negative = Value::Number(Number::Integer(-1));
+
+        // By default, `undefined` is converted to null.
+        let result = runtime.call_filter("positive", &positive, &[]).unwrap();
+        assert_eq!(result, Value::Null);
+
+        runtime.set_undefined_unchanged(true);
+        let result = runtime.call_filter("positive", &positive, &[]).unwrap();
+        assert_eq!(result, positive);
+        let result = runtime.call_filter("positive", &negative, &[]).unwrap();
+        assert_eq!(result, Value::Null);
+    }
+
+    #[test]
+    fn test_date_objects() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_next_day(input) {
+                return new Date(input.getTime() + 86400000);
+            }
+            function filter_type(input) {
+                return typeof input;
+            }
+        "#;
+        runtime.load_source(source, "date.js").unwrap();
+        let date = chrono::DateTime::parse_from_rfc3339("2026-02-28T10:30:00.250Z")
+            .unwrap()
+            .with_timezone(&chrono_tz::UTC);
+
+        // By default, dates are passed as strings.
+        let result = runtime
+            .call_filter("type", &Value::Date(date), &[])
+            .unwrap();
+        assert_eq!(result, Value::String("string".to_string()));
+
+        runtime.set_date_objects(true);
+        let result = runtime
+            .call_filter("next_day", &Value::Date(date), &[])
+            .unwrap();
+        assert_eq!(result, Value::Date(date + chrono::Duration::days(1)));
+    }
+
+    #[test]
+    fn test_strict() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_callback(input) {
+                return function () { return input; };
+            }
+        "#;
+        runtime.load_source(source, "strict.js").unwrap();
+
+        // By default, a function is converted to an empty object.
+        let result = runtime.call_filter("callback", &Value::Null, &[]).unwrap();
+        assert_eq!(result, Value::Object(vec![]));
+
+        runtime.set_strict(true);
+        let result = runtime.call_filter("callback", &Value::Null, &[]);
+        assert!(matches!(
+            result,
+            Err(JsError::ConversionError { message })
+                if message == "function can not be converted to a Hurl value"
+        ));
+    }
+
+    #[test]
+    fn test_regex_round_trip() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_anchor(input) {
+                return new RegExp("^" + input + "$");
+            }
+        "#;
+        runtime.load_source(source, "regex.js").unwrap();
+
+        let regex = regex::Regex::new(r"\d{3}").unwrap();
+        let result = runtime
+            .call_filter("anchor", &Value::Regex(regex), &[])
+            .unwrap();
+        let Value::Regex(regex) = result else {
+            panic!("expected a regex");
+        };
+        assert_eq!(regex.as_str(), r"^\d{3}$");
+    }
+
     #[test]
     fn test_response_access() {
         let mut runtime = JsRuntime::new();
 
         // Set response
         runtime.set_response(JsResponse {
-            status: 200,
+            status: 404,
+            status_text: "Not Found".to_string(),
             headers: vec![("Content-Type".to_string(), "application/json".to_string())],
-            body: b"test body".to_vec(),
+            body: Rc::from(b"test body".as_slice()),
             content_type: Some("application/json".to_string()),
             url: "http://example.com".to_string(),
         });
 
         let source = r#"
             function filter_with_status(input) {
-                return input + " (status=" + response.status + ")";
+                return input + " (status=" + response.status + " " + response.statusText + ")";
             }
         "#;
         runtime
@@ -350,7 +1251,239 @@ mod tests {
 
         assert_eq!(
             result,
-            Value::String("result (status=200)".to_string())
+            Value::String("result (status=404 Not Found)".to_string())
         );
     }
+
+    #[test]
+    fn test_eval_expression() {
+        let mut runtime = JsRuntime::new();
+        runtime.set_response(JsResponse {
+            status: 200,
+            status_text: "OK".to_string(),
+            headers: vec![],
+            body: Rc::from([]),
+            content_type: None,
+            url: "http://example.com".to_string(),
+        });
+
+        let result = runtime
+            .eval_expression(
+                "input * 2 + response.status",
+                &Value::Number(Number::Integer(21)),
+            )
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(242)));
+
+        let result = runtime
+            .eval_expression("input.toUpperCase()", &Value::String("hello".to_string()))
+            .unwrap();
+        assert_eq!(result, Value::String("HELLO".to_string()));
+
+        // `input` is not left in the global scope.
+        let result = runtime
+            .eval_expression("typeof input", &Value::Null)
+            .unwrap();
+        assert_eq!(result, Value::String("object".to_string()));
+        let result = runtime
+            .context
+            .eval(Source::from_bytes("typeof input"))
+            .unwrap();
+        assert_eq!(
+            result.as_string().unwrap().to_std_string_escaped(),
+            "undefined"
+        );
+
+        let result = runtime.eval_expression("input +", &Value::Null);
+        assert!(matches!(result, Err(JsError::ParseError { .. })));
+
+        let result = runtime.eval_expression("input.foo.bar", &Value::Null);
+        assert!(matches!(result, Err(JsError::RuntimeError { .. })));
+
+        // Statements are not expressions.
+        let result = runtime.eval_expression("let x = 1", &Value::Null);
+        assert!(matches!(result, Err(JsError::ParseError { .. })));
+        let result = runtime.eval_expression("input; 42", &Value::Null);
+        assert!(matches!(result, Err(JsError::ParseError { .. })));
+
+        // A trailing comment is allowed.
+        let result = runtime
+            .eval_expression("input + 1 // next", &Value::Number(Number::Integer(1)))
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(2)));
+    }
+
+    #[test]
+    fn test_eval_expression_input_global() {
+        // The `input` parameter shadows globals of the same name, which are left unchanged.
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            var input = "global";
+            function filter_input(value) {
+                return input;
+            }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+        let result = runtime
+            .eval_expression("input", &Value::Number(Number::Integer(1)))
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(1)));
+        let result = runtime.call_filter("input", &Value::Null, &[]).unwrap();
+        assert_eq!(result, Value::String("global".to_string()));
+
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function input() {
+                return "function";
+            }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+        let result = runtime
+            .eval_expression("input", &Value::Number(Number::Integer(1)))
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(1)));
+        let result = runtime
+            .eval_expression("typeof input", &Value::Null)
+            .unwrap();
+        assert_eq!(result, Value::String("object".to_string()));
+        let result = runtime.context.eval(Source::from_bytes("input()")).unwrap();
+        assert_eq!(
+            result.as_string().unwrap().to_std_string_escaped(),
+            "function"
+        );
+    }
+
+    #[test]
+    fn test_response_json() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            function filter_user_id(input) {
+                return response.json().user.id;
+            }
+            function filter_json_cached(input) {
+                return response.json() === response.json();
+            }
+        "#;
+        runtime.load_source(source, "json.js").unwrap();
+
+        runtime.set_response(JsResponse {
+            body: Rc::from(br#"{"user": {"id": 42}}"#.as_slice()),
+            ..JsResponse::empty()
+        });
+        let result = runtime.call_filter("user_id", &Value::Null, &[]).unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(42)));
+        let result = runtime
+            .call_filter("json_cached", &Value::Null, &[])
+            .unwrap();
+        assert_eq!(result, Value::Bool(true));
+
+        runtime.set_response(JsResponse {
+            body: Rc::from(b"<html></html>".as_slice()),
+            ..JsResponse::empty()
+        });
+        let result = runtime.call_filter("user_id", &Value::Null, &[]);
+        assert!(matches!(
+            result,
+            Err(JsError::RuntimeError { message }) if message.starts_with("SyntaxError")
+        ));
+    }
+
+    #[test]
+    fn test_response_large_body() {
+        let mut runtime = JsRuntime::new();
+        let source = r#"
+            var last_response;
+            function filter_same_response(input) {
+                const same = last_response === response;
+                last_response = response;
+                return same;
+            }
+            function filter_body_is_getter(input) {
+                const descriptor = Object.getOwnPropertyDescriptor(response, "body");
+                return typeof descriptor.get === "function" && !("value" in descriptor);
+            }
+            function filter_body_length(input) {
+                return response.body.length;
+            }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+        let body: Rc<[u8]> = Rc::from(vec![b'a'; 4 * 1024 * 1024]);
+        let response = JsResponse {
+            status: 200,
+            body: Rc::clone(&body),
+            ..JsResponse::empty()
+        };
+        runtime.set_response(response);
+
+        // The response object is built once, without the body string, and reused between calls.
+        let result = runtime
+            .call_filter("same_response", &Value::Null, &[])
+            .unwrap();
+        assert_eq!(result, Value::Bool(false));
+        for _ in 0..100 {
+            let result = runtime
+                .call_filter("same_response", &Value::Null, &[])
+                .unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+        let result = runtime
+            .call_filter("body_is_getter", &Value::Null, &[])
+            .unwrap();
+        assert_eq!(result, Value::Bool(true));
+
+        // The body isn't copied: it's shared between this test, the runtime and the `body`,
+        // `bodyBase64` and `json()` functions of the response object.
+        assert_eq!(Rc::strong_count(&body), 5);
+
+        // The body is built on access.
+        let result = runtime
+            .call_filter("body_length", &Value::Null, &[])
+            .unwrap();
+        assert_eq!(result, Value::Number(Number::Integer(4 * 1024 * 1024)));
+
+        // A new response gives a new object.
+        runtime.set_response(JsResponse::empty());
+        let result = runtime
+            .call_filter("same_response", &Value::Null, &[])
+            .unwrap();
+        assert_eq!(result, Value::Bool(false));
+    }
+
+    #[test]
+    fn test_client_history() {
+        let mut runtime = JsRuntime::new();
+        runtime.push_response(JsResponse {
+            status: 301,
+            url: "http://example.com/old".to_string(),
+            ..JsResponse::empty()
+        });
+        runtime.push_response(JsResponse {
+            status: 200,
+            url: "http://example.com/new".to_string(),
+            ..JsResponse::empty()
+        });
+
+        let source = r#"
+            function filter_history(input) {
+                // History items are read-only, assignments are ignored.
+                client.history[0].status = 500;
+                const first = client.history[0];
+                return client.history.length + " " + first.status + " " + first.url;
+            }
+            function filter_history_push(input) {
+                client.history.push({ status: 500 });
+                return input;
+            }
+        "#;
+        runtime.load_source(source, "<inline>").unwrap();
+
+        let result = runtime.call_filter("history", &Value::Null, &[]).unwrap();
+        assert_eq!(
+            result,
+            Value::String("2 301 http://example.com/old".to_string())
+        );
+
+        let result = runtime.call_filter("history_push", &Value::Null, &[]);
+        assert!(matches!(result, Err(JsError::RuntimeError { .. })));
+    }
 }
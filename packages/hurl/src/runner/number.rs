@@ -83,6 +83,15 @@ impl Number {
             (n1, n2) => compare_number_string(&n1.to_string(), &n2.to_string()),
         }
     }
+
+    /// Converts this number to a float, big integers being approximated.
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            Number::Integer(i) => *i as f64,
+            Number::Float(f) => *f,
+            Number::BigInteger(s) => s.parse::<f64>().unwrap_or(f64::NAN),
+        }
+    }
 }
 
 fn compare_float(f1: f64, f2: f64) -> Ordering {
@@ -149,6 +158,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(Number::from(2).to_f64(), 2.0);
+        assert_eq!(Number::from(2.5).to_f64(), 2.5);
+        assert_eq!(
+            Number::BigInteger("100000000000000000000".to_string()).to_f64(),
+            1e20
+        );
+        assert!(Number::BigInteger("x".to_string()).to_f64().is_nan());
+    }
+
     #[test]
     fn test_cmp_value() {
         let integer_zero = Number::from(0);
@@ -1384,7 +1384,7 @@ mod tests {
         // predicate: `isDate`
         // value: 2002-06-16T10:10:10
         let value = Value::Date(
-            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2002, 6, 16, 10, 10, 10).unwrap(),
+            chrono::TimeZone::with_ymd_and_hms(&chrono_tz::UTC, 2002, 6, 16, 10, 10, 10).unwrap(),
         );
         let result = eval_is_date(&value).unwrap();
         assert!(result.success);
@@ -15,7 +15,6 @@
  * limitations under the License.
  *
  */
-use chrono::Utc;
 use hurl_core::ast::{
     CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, Query, QueryValue,
     RegexValue, SourceInfo, Template,
@@ -396,8 +395,12 @@ fn eval_query_certificate(
             CertificateAttributeName::Issuer => {
                 certificate.issuer().map(|it| Value::String(it.clone()))
             }
-            CertificateAttributeName::StartDate => certificate.start_date().map(Value::Date),
-            CertificateAttributeName::ExpireDate => certificate.expire_date().map(Value::Date),
+            CertificateAttributeName::StartDate => certificate
+                .start_date()
+                .map(|it| Value::Date(it.with_timezone(&chrono_tz::UTC))),
+            CertificateAttributeName::ExpireDate => certificate
+                .expire_date()
+                .map(|it| Value::Date(it.with_timezone(&chrono_tz::UTC))),
             CertificateAttributeName::SerialNumber => certificate
                 .serial_number()
                 .map(|it| Value::String(it.clone())),
@@ -444,7 +447,7 @@ fn eval_cookie_attribute_name(
         CookieAttributeName::Expires(_) => {
             if let Some(s) = cookie.expires() {
                 if let Ok(v) = chrono::DateTime::parse_from_rfc2822(s.as_str()) {
-                    Ok(Some(Value::Date(v.with_timezone(&chrono::Utc))))
+                    Ok(Some(Value::Date(v.with_timezone(&chrono_tz::UTC))))
 
                 // support format with dash such as Wed, 13-Jan-2021 22:23:01 GMT
                 // TODO: search for for other possible date format used in the wild
@@ -452,7 +455,9 @@ fn eval_cookie_attribute_name(
                     s.as_str(),
                     "%a, %d-%b-%Y %H:%M:%S%.3f GMT",
                 ) {
-                    Ok(Some(Value::Date(v.and_local_timezone(Utc).unwrap())))
+                    Ok(Some(Value::Date(
+                        v.and_local_timezone(chrono_tz::UTC).unwrap(),
+                    )))
                 } else {
                     Err(RunnerError::new(
                         query_source_info,
@@ -1039,7 +1044,7 @@ pub mod tests {
             Value::Date(
                 chrono::DateTime::parse_from_rfc2822("Wed, 13 Jan 2021 22:23:01 GMT")
                     .unwrap()
-                    .with_timezone(&chrono::Utc)
+                    .with_timezone(&chrono_tz::UTC)
             ),
         );
         assert_eq!(
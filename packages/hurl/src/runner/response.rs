@@ -15,8 +15,6 @@
  * limitations under the License.
  *
  */
-use std::path::PathBuf;
-
 use hurl_core::ast::{Base64, Body, Bytes, Hex, Response, SourceInfo, StatusValue};
 
 use crate::http;
@@ -27,6 +25,7 @@ use super::body;
 use super::cache::BodyCache;
 use super::capture;
 use super::error::{RunnerError, RunnerErrorKind};
+use super::filter::FilterContext;
 use super::json;
 use super::multiline;
 use super::result::{AssertResult, CaptureResult};
@@ -73,7 +72,7 @@ pub fn eval_asserts(
     http_responses: &[&http::Response],
     cache: &mut BodyCache,
     context_dir: &ContextDir,
-    jsfilter_path: &Option<PathBuf>,
+    filter_context: &FilterContext,
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
     let last_response = http_responses.last().unwrap();
@@ -160,7 +159,7 @@ pub fn eval_asserts(
     // Then, checks all the explicit asserts.
     for assert in response.asserts() {
         let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_responses, cache, context_dir, jsfilter_path);
+            assert::eval_explicit_assert(assert, variables, http_responses, cache, context_dir, filter_context);
         asserts.push(assert_result);
     }
     asserts
@@ -365,11 +364,11 @@ pub fn eval_captures(
     http_responses: &[&http::Response],
     cache: &mut BodyCache,
     variables: &mut VariableSet,
-    jsfilter_path: &Option<PathBuf>,
+    filter_context: &FilterContext,
 ) -> Result<Vec<CaptureResult>, RunnerError> {
     let mut captures = vec![];
     for capture in response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_responses, cache, jsfilter_path)?;
+        let capture_result = capture::eval_capture(capture, variables, http_responses, cache, filter_context)?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         let name = capture_result.name.clone();
@@ -465,7 +464,7 @@ mod tests {
                 &[&http::xml_two_users_http_response()],
                 &mut cache,
                 &context_dir,
-                &None,
+                &FilterContext::default(),
             ),
             vec![AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(2)))),
@@ -513,7 +512,7 @@ mod tests {
                 &[&http::xml_two_users_http_response()],
                 &mut cache,
                 &mut variables,
-                &None,
+                &FilterContext::default(),
             )
             .unwrap(),
             vec![CaptureResult {
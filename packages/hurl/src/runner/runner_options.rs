@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::time::Duration;
 
@@ -23,7 +24,9 @@ use hurl_core::types::{BytesPerSec, Count};
 use crate::http::{IpResolve, RequestedHttpVersion};
 use crate::util::path::ContextDir;
 
+use super::js::{ArgsMode, DEFAULT_FILTER_PREFIX};
 use super::output::Output;
+use super::value::Value;
 
 /// Build a [`RunnerOptions`] instance.
 pub struct RunnerOptionsBuilder {
@@ -48,7 +51,15 @@ pub struct RunnerOptionsBuilder {
     ignore_asserts: bool,
     insecure: bool,
     ip_resolve: IpResolve,
+    jsfilter_args_mode: ArgsMode,
+    jsfilter_config: HashMap<String, Value>,
+    jsfilter_date_objects: bool,
     jsfilter_path: Option<PathBuf>,
+    jsfilter_prefix: String,
+    jsfilter_shared_global: bool,
+    jsfilter_strict: bool,
+    jsfilter_tuple_results: bool,
+    jsfilter_undefined_unchanged: bool,
     max_filesize: Option<u64>,
     max_recv_speed: Option<BytesPerSec>,
     max_redirect: Count,
@@ -67,8 +78,10 @@ pub struct RunnerOptionsBuilder {
     resolves: Vec<String>,
     retry: Option<Count>,
     retry_interval: Duration,
+    seed: Option<u64>,
     skip: bool,
     ssl_no_revoke: bool,
+    strict_arithmetic: bool,
     timeout: Duration,
     to_entry: Option<usize>,
     unix_socket: Option<String>,
@@ -101,7 +114,15 @@ impl Default for RunnerOptionsBuilder {
             ignore_asserts: false,
             insecure: false,
             ip_resolve: IpResolve::default(),
+            jsfilter_args_mode: ArgsMode::default(),
+            jsfilter_config: HashMap::new(),
+            jsfilter_date_objects: false,
             jsfilter_path: None,
+            jsfilter_prefix: DEFAULT_FILTER_PREFIX.to_string(),
+            jsfilter_shared_global: false,
+            jsfilter_strict: false,
+            jsfilter_tuple_results: false,
+            jsfilter_undefined_unchanged: false,
             max_filesize: None,
             max_recv_speed: None,
             max_redirect: Count::Finite(50),
@@ -120,8 +141,10 @@ impl Default for RunnerOptionsBuilder {
             resolves: vec![],
             retry: None,
             retry_interval: Duration::from_millis(1000),
+            seed: None,
             skip: false,
             ssl_no_revoke: false,
+            strict_arithmetic: false,
             timeout: Duration::from_secs(300),
             to_entry: None,
             unix_socket: None,
@@ -290,12 +313,69 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the calling convention of the JavaScript filter functions.
+    pub fn jsfilter_args_mode(&mut self, args_mode: ArgsMode) -> &mut Self {
+        self.jsfilter_args_mode = args_mode;
+        self
+    }
+
+    /// Sets the values of the `config` object of JavaScript filters.
+    ///
+    /// This object is read-only and holds static configuration (API base URLs, feature flags
+    /// etc...) for the filters.
+    pub fn jsfilter_config(&mut self, config: HashMap<String, Value>) -> &mut Self {
+        self.jsfilter_config = config;
+        self
+    }
+
+    /// Passes dates to JavaScript filters as `Date` objects, instead of ISO 8601 strings.
+    pub fn jsfilter_date_objects(&mut self, jsfilter_date_objects: bool) -> &mut Self {
+        self.jsfilter_date_objects = jsfilter_date_objects;
+        self
+    }
+
     /// Sets the JavaScript filter file path for custom filters.
     pub fn jsfilter_path(&mut self, path: Option<PathBuf>) -> &mut Self {
         self.jsfilter_path = path;
         self
     }
 
+    /// Sets the prefix of the names of the JavaScript filter functions, `filter_` by default.
+    pub fn jsfilter_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.jsfilter_prefix = prefix.to_string();
+        self
+    }
+
+    /// Shares the `client.global` object of JavaScript filters between threads.
+    ///
+    /// Data stored in `client.global` (counters for instance) is then accumulated across parallel
+    /// workers, but JavaScript filters are run one at a time.
+    pub fn jsfilter_shared_global(&mut self, jsfilter_shared_global: bool) -> &mut Self {
+        self.jsfilter_shared_global = jsfilter_shared_global;
+        self
+    }
+
+    /// Rejects the values returned by JavaScript filters that have no Hurl counterpart (functions,
+    /// symbols), instead of converting them to an object or a string.
+    pub fn jsfilter_strict(&mut self, jsfilter_strict: bool) -> &mut Self {
+        self.jsfilter_strict = jsfilter_strict;
+        self
+    }
+
+    /// Follows the `[result, error]` convention for the values returned by JavaScript filters: a
+    /// two-element array whose second element is a string is an error.
+    pub fn jsfilter_tuple_results(&mut self, jsfilter_tuple_results: bool) -> &mut Self {
+        self.jsfilter_tuple_results = jsfilter_tuple_results;
+        self
+    }
+
+    /// Makes JavaScript filters returning `undefined` leave their input unchanged, instead of
+    /// returning `null`.
+    pub fn jsfilter_undefined_unchanged(&mut self, undefined_unchanged: bool) -> &mut Self {
+        self.jsfilter_undefined_unchanged = undefined_unchanged;
+        self
+    }
+
     /// Set the file size limit
     pub fn max_filesize(&mut self, max_filesize: Option<u64>) -> &mut Self {
         self.max_filesize = max_filesize;
@@ -416,6 +496,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the seed of the random generator used by filters like `sample`.
+    ///
+    /// Default is `None`, the generator is seeded from the operating system.
+    pub fn seed(&mut self, seed: Option<u64>) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
     /// Skip the run without executing any request.
     pub fn skip(&mut self, skip: bool) -> &mut Self {
         self.skip = skip;
@@ -428,6 +516,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Makes the arithmetic filters (like `add`) fail on integer overflow.
+    ///
+    /// Default is false, an integer overflow silently wraps around.
+    pub fn strict_arithmetic(&mut self, strict_arithmetic: bool) -> &mut Self {
+        self.strict_arithmetic = strict_arithmetic;
+        self
+    }
+
     /// Sets maximum time allowed for the transfer.
     ///
     /// Default 300 seconds.
@@ -488,7 +584,15 @@ impl RunnerOptionsBuilder {
             ignore_asserts: self.ignore_asserts,
             insecure: self.insecure,
             ip_resolve: self.ip_resolve,
+            jsfilter_args_mode: self.jsfilter_args_mode,
+            jsfilter_config: self.jsfilter_config.clone(),
+            jsfilter_date_objects: self.jsfilter_date_objects,
             jsfilter_path: self.jsfilter_path.clone(),
+            jsfilter_prefix: self.jsfilter_prefix.clone(),
+            jsfilter_shared_global: self.jsfilter_shared_global,
+            jsfilter_strict: self.jsfilter_strict,
+            jsfilter_tuple_results: self.jsfilter_tuple_results,
+            jsfilter_undefined_unchanged: self.jsfilter_undefined_unchanged,
             max_filesize: self.max_filesize,
             max_recv_speed: self.max_recv_speed,
             max_redirect: self.max_redirect,
@@ -507,8 +611,10 @@ impl RunnerOptionsBuilder {
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
+            seed: self.seed,
             skip: self.skip,
             ssl_no_revoke: self.ssl_no_revoke,
+            strict_arithmetic: self.strict_arithmetic,
             timeout: self.timeout,
             to_entry: self.to_entry,
             unix_socket: self.unix_socket.clone(),
@@ -568,8 +674,24 @@ pub struct RunnerOptions {
     pub(crate) ip_resolve: IpResolve,
     /// Allows Hurl to perform "insecure" SSL connections and transfers.
     pub(crate) insecure: bool,
+    /// Calling convention of the JavaScript filter functions.
+    pub(crate) jsfilter_args_mode: ArgsMode,
+    /// Values of the `config` object of JavaScript filters.
+    pub(crate) jsfilter_config: HashMap<String, Value>,
+    /// Passes dates to JavaScript filters as `Date` objects.
+    pub(crate) jsfilter_date_objects: bool,
     /// JavaScript filter file path for custom filters.
     pub(crate) jsfilter_path: Option<PathBuf>,
+    /// Prefix of the names of the JavaScript filter functions.
+    pub(crate) jsfilter_prefix: String,
+    /// Shares the `client.global` object of JavaScript filters between threads.
+    pub(crate) jsfilter_shared_global: bool,
+    /// Rejects the values returned by JavaScript filters that have no Hurl counterpart.
+    pub(crate) jsfilter_strict: bool,
+    /// Follows the `[result, error]` convention for the values returned by JavaScript filters.
+    pub(crate) jsfilter_tuple_results: bool,
+    /// Makes JavaScript filters returning `undefined` leave their input unchanged.
+    pub(crate) jsfilter_undefined_unchanged: bool,
     /// Set the file size limit.
     pub(crate) max_filesize: Option<u64>,
     /// Set the maximum download speed.
@@ -605,10 +727,14 @@ pub struct RunnerOptions {
     pub(crate) retry: Option<Count>,
     /// Sets duration between each retry.
     pub(crate) retry_interval: Duration,
+    /// Sets the seed of the random generator used by filters.
+    pub(crate) seed: Option<u64>,
     /// Skip the run without executing any request.
     pub(crate) skip: bool,
     /// Disables certificate revocation checks for SSL backends where such behavior is present.
     pub(crate) ssl_no_revoke: bool,
+    /// Makes the arithmetic filters fail on integer overflow instead of wrapping around.
+    pub(crate) strict_arithmetic: bool,
     /// Sets maximum time allowed for the transfer.
     pub(crate) timeout: Duration,
     /// Executes Hurl file to to_entry (starting at 1), ignores the remaining of the file.
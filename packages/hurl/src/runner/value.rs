@@ -32,7 +32,7 @@ pub enum Value {
     /// A buffer of bytes.
     Bytes(Vec<u8>),
     /// A date.
-    Date(chrono::DateTime<chrono::Utc>),
+    Date(chrono::DateTime<chrono_tz::Tz>),
     /// A structure to represent an HTTP response.
     HttpResponse(HttpResponse),
     /// A list of [`Value`].
@@ -165,7 +165,7 @@ impl Value {
     pub fn render(&self) -> Option<String> {
         match self {
             Value::Bool(v) => Some(v.to_string()),
-            Value::Date(d) => Some(d.format(FORMAT_ISO).to_string()),
+            Value::Date(d) => Some(d.to_utc().format(FORMAT_ISO).to_string()),
             Value::Null => Some("null".to_string()),
             Value::Number(v) => Some(v.to_string()),
             Value::String(s) => Some(s.clone()),
@@ -216,7 +216,7 @@ mod tests {
             .unwrap();
         let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(datetime_naive, Utc);
         assert_eq!(
-            Value::Date(datetime_utc).repr(),
+            Value::Date(datetime_utc.with_timezone(&chrono_tz::UTC)).repr(),
             "date <2000-01-01 12:00:00.123 UTC>".to_string()
         );
         assert_eq!(Value::List(vec![]).repr(), "list <[]>".to_string());
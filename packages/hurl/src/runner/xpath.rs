@@ -77,6 +77,16 @@ impl Document {
         };
         libxml_eval_xpath(&self.inner, expr, support_ns)
     }
+
+    /// Evaluates a XPath 1.0 expression `expr` against a document and returns the string content
+    /// of each matched node. The expression must evaluate to a node-set.
+    pub fn eval_xpath_nodes(&self, expr: &str) -> Result<Vec<String>, XPathError> {
+        let support_ns = match self.format {
+            Format::Html => false,
+            Format::Xml => true,
+        };
+        libxml_eval_xpath_nodes(&self.inner, expr, support_ns)
+    }
 }
 
 /// FIXME: Here are some patched functions of libxml crate.
@@ -159,12 +169,13 @@ fn register_namespaces(doc: &libxml::tree::Document, context: &libxml::xpath::Co
     }
 }
 
-/// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using namespace.
-fn libxml_eval_xpath(
+/// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using
+/// namespace, and returns the raw libxml2 result.
+fn libxml_eval_xpath_object(
     doc: &libxml::tree::Document,
     expr: &str,
     support_ns: bool,
-) -> Result<Value, XPathError> {
+) -> Result<libxml::xpath::Object, XPathError> {
     let context = libxml::xpath::Context::new(doc).expect("error setting context in xpath module");
 
     // libxml2 prints to stdout warning and errors, so we mut it.
@@ -176,10 +187,16 @@ fn libxml_eval_xpath(
         register_namespaces(doc, &context);
     }
 
-    let result = match context.evaluate(expr) {
-        Ok(object) => object,
-        Err(_) => return Err(XPathError::Eval),
-    };
+    context.evaluate(expr).map_err(|_| XPathError::Eval)
+}
+
+/// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using namespace.
+fn libxml_eval_xpath(
+    doc: &libxml::tree::Document,
+    expr: &str,
+    support_ns: bool,
+) -> Result<Value, XPathError> {
+    let result = libxml_eval_xpath_object(doc, expr, support_ns)?;
 
     match unsafe { *result.ptr }.type_ {
         libxml::bindings::xmlXPathObjectType_XPATH_NUMBER => {
@@ -204,6 +221,25 @@ fn libxml_eval_xpath(
     }
 }
 
+/// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using
+/// namespace, and returns the string content of the matched nodes.
+fn libxml_eval_xpath_nodes(
+    doc: &libxml::tree::Document,
+    expr: &str,
+    support_ns: bool,
+) -> Result<Vec<String>, XPathError> {
+    let result = libxml_eval_xpath_object(doc, expr, support_ns)?;
+    if unsafe { *result.ptr }.type_ != libxml::bindings::xmlXPathObjectType_XPATH_NODESET {
+        return Err(XPathError::Eval);
+    }
+    let contents = result
+        .get_nodes_as_vec()
+        .iter()
+        .map(|node| node.get_content())
+        .collect();
+    Ok(contents)
+}
+
 /// A XML namespace
 #[derive(Debug, PartialEq, Eq)]
 struct Namespace {
@@ -291,6 +327,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_xml_nodes() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<food>
+  <fruit>banana</fruit>
+  <fruit>apple</fruit>
+  <meat>beef</meat>
+</food>
+"#;
+        let doc = Document::parse(xml, Format::Xml).unwrap();
+
+        assert_eq!(
+            doc.eval_xpath_nodes("//fruit").unwrap(),
+            vec!["banana".to_string(), "apple".to_string()]
+        );
+        assert_eq!(
+            doc.eval_xpath_nodes("//meat").unwrap(),
+            vec!["beef".to_string()]
+        );
+        assert!(doc.eval_xpath_nodes("//fish").unwrap().is_empty());
+        assert_eq!(
+            doc.eval_xpath_nodes("count(//fruit)").unwrap_err(),
+            XPathError::Eval
+        );
+    }
+
     #[test]
     fn test_error_eval() {
         let xml = "<a/>";
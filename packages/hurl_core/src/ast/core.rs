@@ -281,15 +281,73 @@ pub enum FilterValue {
     Base64Decode,
     Base64Encode,
     Base64UrlSafeDecode,
-    Base64UrlSafeEncode,
+    Base64UrlSafeEncode {
+        pad: Option<Whitespace>,
+    },
+    Between {
+        space0: Whitespace,
+        min: Box<NumberValue>,
+        space1: Whitespace,
+        max: Box<NumberValue>,
+        exclusive: Option<Whitespace>,
+    },
+    CamelCase,
+    Canonicalize,
+    ClampLength {
+        space0: Whitespace,
+        max: IntegerValue,
+    },
+    Coalesce {
+        args: Vec<(Whitespace, Template)>,
+    },
     Count,
     DaysAfterNow,
     DaysBeforeNow,
+    Debug {
+        label: Option<(Whitespace, Template)>,
+    },
     Decode {
         space0: Whitespace,
         encoding: Template,
     },
-    First,
+    DeepEqual {
+        space0: Whitespace,
+        expected: Placeholder,
+    },
+    DropWhile {
+        space0: Whitespace,
+        name: Template,
+    },
+    DurationFormat {
+        iso: Option<Whitespace>,
+    },
+    EndOf {
+        space0: Whitespace,
+        unit: Template,
+    },
+    EnsurePrefix {
+        space0: Whitespace,
+        prefix: Template,
+    },
+    EnsureSuffix {
+        space0: Whitespace,
+        suffix: Template,
+    },
+    Entropy,
+    EscapeJson,
+    Expect {
+        space0: Whitespace,
+        name: Template,
+        space1: Whitespace,
+        message: Template,
+    },
+    Filter {
+        space0: Whitespace,
+        name: Template,
+    },
+    First {
+        n: Option<(Whitespace, IntegerValue)>,
+    },
     Format {
         space0: Whitespace,
         fmt: Template,
@@ -298,23 +356,99 @@ pub enum FilterValue {
         space0: Whitespace,
         fmt: Template,
     },
+    FormatBytes {
+        decimal: Option<Whitespace>,
+    },
+    FormDecode,
+    FormEncode,
+    FromYaml,
+    GroupBy {
+        space0: Whitespace,
+        name: Template,
+    },
+    HexDump,
     HtmlEscape,
     HtmlUnescape,
+    IsLeapYear,
+    JsEval {
+        space0: Whitespace,
+        expr: Template,
+    },
+    JsonPatch {
+        space0: Whitespace,
+        ops: Placeholder,
+    },
     JsonPath {
         space0: Whitespace,
         expr: Template,
+        default: Option<(Whitespace, Whitespace, Template)>,
     },
     JsFilter {
         space0: Whitespace,
         name: Template,
         args: Vec<(Whitespace, Template)>,
     },
-    Last,
+    JsonPathKeys {
+        space0: Whitespace,
+        expr: Template,
+    },
+    JwtDecode {
+        header: Option<Whitespace>,
+    },
+    KebabCase,
+    Last {
+        n: Option<(Whitespace, IntegerValue)>,
+    },
+    Leaves,
     Location,
+    Map {
+        space0: Whitespace,
+        name: Template,
+    },
+    Mask {
+        space0: Whitespace,
+        start: Box<IntegerValue>,
+        space1: Whitespace,
+        end: Box<IntegerValue>,
+        mask: Option<(Whitespace, Template)>,
+    },
+    Merge {
+        space0: Whitespace,
+        other: Placeholder,
+    },
+    MergeDeep {
+        space0: Whitespace,
+        other: Placeholder,
+    },
+    NonEmpty,
     Nth {
         space0: Whitespace,
         n: IntegerValue,
     },
+    NthFromEnd {
+        space0: Whitespace,
+        n: IntegerValue,
+    },
+    NumberFormat {
+        space0: Whitespace,
+        fmt: Template,
+    },
+    ParseDuration,
+    Paths,
+    Percent {
+        space0: Whitespace,
+        total: NumberValue,
+    },
+    Ratio {
+        space0: Whitespace,
+        total: NumberValue,
+    },
+    Reduce {
+        space0: Whitespace,
+        name: Template,
+        space1: Whitespace,
+        init: Template,
+    },
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -325,16 +459,51 @@ pub enum FilterValue {
         space1: Whitespace,
         new_value: Template,
     },
+    ReplaceAll {
+        space0: Whitespace,
+        old_value: Template,
+        space1: Whitespace,
+        new_value: Template,
+    },
     ReplaceRegex {
         space0: Whitespace,
         pattern: RegexValue,
         space1: Whitespace,
         new_value: Template,
     },
+    RoundTo {
+        space0: Whitespace,
+        step: NumberValue,
+    },
+    Sample {
+        n: Option<(Whitespace, IntegerValue)>,
+    },
+    Shuffle,
+    Sign,
+    SnakeCase,
+    SortBy {
+        space0: Whitespace,
+        name: Template,
+    },
     Split {
         space0: Whitespace,
         sep: Template,
     },
+    SplitRegex {
+        space0: Whitespace,
+        pattern: RegexValue,
+        trim: Option<Whitespace>,
+    },
+    Sqrt,
+    StartOf {
+        space0: Whitespace,
+        unit: Template,
+    },
+    TakeWhile {
+        space0: Whitespace,
+        name: Template,
+    },
+    ToBigInt,
     ToDate {
         space0: Whitespace,
         fmt: Template,
@@ -342,7 +511,22 @@ pub enum FilterValue {
     ToFloat,
     ToHex,
     ToInt,
-    ToString,
+    ToString {
+        fmt: Option<(Whitespace, Template)>,
+    },
+    ToTimezone {
+        space0: Whitespace,
+        tz: Template,
+    },
+    ToUtc,
+    ToYaml,
+    Truncate {
+        space0: Whitespace,
+        len: Box<IntegerValue>,
+        suffix: Option<(Whitespace, Template)>,
+    },
+    TypeOf,
+    UnescapeJson,
     UrlDecode,
     UrlEncode,
     UrlQueryParam {
@@ -351,10 +535,30 @@ pub enum FilterValue {
     },
     Utf8Decode,
     Utf8Encode,
+    Weekday {
+        name: Option<Whitespace>,
+    },
+    Wrap {
+        space0: Whitespace,
+        left: Template,
+        right: Option<(Whitespace, Template)>,
+    },
     XPath {
         space0: Whitespace,
         expr: Template,
     },
+    XPathAll {
+        space0: Whitespace,
+        expr: Template,
+    },
+    XPathBoolean {
+        space0: Whitespace,
+        expr: Template,
+    },
+    XPathNumber {
+        space0: Whitespace,
+        expr: Template,
+    },
 }
 
 impl FilterValue {
@@ -365,36 +569,100 @@ impl FilterValue {
             FilterValue::Base64Decode => "base64Decode",
             FilterValue::Base64Encode => "base64Encode",
             FilterValue::Base64UrlSafeDecode => "base64UrlSafeDecode",
-            FilterValue::Base64UrlSafeEncode => "base64UrlSafeEncode",
+            FilterValue::Base64UrlSafeEncode { .. } => "base64UrlSafeEncode",
+            FilterValue::Between { .. } => "between",
+            FilterValue::CamelCase => "camelCase",
+            FilterValue::Canonicalize => "canonicalize",
+            FilterValue::ClampLength { .. } => "clampLength",
+            FilterValue::Coalesce { .. } => "coalesce",
             FilterValue::Count => "count",
             FilterValue::DaysAfterNow => "daysAfterNow",
             FilterValue::DaysBeforeNow => "daysBeforeNow",
+            FilterValue::Debug { .. } => "debug",
             FilterValue::Decode { .. } => "decode",
-            FilterValue::First => "first",
+            FilterValue::DeepEqual { .. } => "deepEqual",
+            FilterValue::DropWhile { .. } => "dropWhile",
+            FilterValue::DurationFormat { .. } => "durationFormat",
+            FilterValue::EndOf { .. } => "endOf",
+            FilterValue::EnsurePrefix { .. } => "ensurePrefix",
+            FilterValue::EnsureSuffix { .. } => "ensureSuffix",
+            FilterValue::Entropy => "entropy",
+            FilterValue::EscapeJson => "escapeJson",
+            FilterValue::Expect { .. } => "expect",
+            FilterValue::Filter { .. } => "filter",
+            FilterValue::First { .. } => "first",
             FilterValue::Format { .. } => "format",
             FilterValue::DateFormat { .. } => "dateFormat",
+            FilterValue::FormatBytes { .. } => "formatBytes",
+            FilterValue::FormDecode => "formDecode",
+            FilterValue::FormEncode => "formEncode",
+            FilterValue::FromYaml => "fromYaml",
+            FilterValue::GroupBy { .. } => "groupBy",
+            FilterValue::HexDump => "hexDump",
             FilterValue::HtmlEscape => "htmlEscape",
             FilterValue::HtmlUnescape => "htmlUnescape",
+            FilterValue::IsLeapYear => "isLeapYear",
+            FilterValue::JsEval { .. } => "jseval",
+            FilterValue::JsonPatch { .. } => "jsonPatch",
             FilterValue::JsonPath { .. } => "jsonpath",
             FilterValue::JsFilter { .. } => "jsfilter",
-            FilterValue::Last => "last",
+            FilterValue::JsonPathKeys { .. } => "jsonpathKeys",
+            FilterValue::JwtDecode { .. } => "jwtDecode",
+            FilterValue::KebabCase => "kebabCase",
+            FilterValue::Last { .. } => "last",
+            FilterValue::Leaves => "leaves",
             FilterValue::Location => "location",
+            FilterValue::Map { .. } => "map",
+            FilterValue::Mask { .. } => "mask",
+            FilterValue::Merge { .. } => "merge",
+            FilterValue::MergeDeep { .. } => "mergeDeep",
+            FilterValue::NonEmpty => "nonEmpty",
             FilterValue::Nth { .. } => "nth",
+            FilterValue::NthFromEnd { .. } => "nthFromEnd",
+            FilterValue::NumberFormat { .. } => "numberFormat",
+            FilterValue::ParseDuration => "parseDuration",
+            FilterValue::Paths => "paths",
+            FilterValue::Percent { .. } => "percent",
+            FilterValue::Ratio { .. } => "ratio",
+            FilterValue::Reduce { .. } => "reduce",
             FilterValue::Regex { .. } => "regex",
             FilterValue::Replace { .. } => "replace",
+            FilterValue::ReplaceAll { .. } => "replaceAll",
             FilterValue::ReplaceRegex { .. } => "replaceRegex",
+            FilterValue::RoundTo { .. } => "roundTo",
+            FilterValue::Sample { .. } => "sample",
+            FilterValue::Shuffle => "shuffle",
+            FilterValue::Sign => "sign",
+            FilterValue::SnakeCase => "snakeCase",
+            FilterValue::SortBy { .. } => "sortBy",
             FilterValue::Split { .. } => "split",
+            FilterValue::SplitRegex { .. } => "splitRegex",
+            FilterValue::Sqrt => "sqrt",
+            FilterValue::StartOf { .. } => "startOf",
+            FilterValue::TakeWhile { .. } => "takeWhile",
+            FilterValue::ToBigInt => "toBigInt",
             FilterValue::ToDate { .. } => "toDate",
             FilterValue::ToFloat => "toFloat",
             FilterValue::ToHex => "toHex",
             FilterValue::ToInt => "toInt",
-            FilterValue::ToString => "toString",
+            FilterValue::ToString { .. } => "toString",
+            FilterValue::ToTimezone { .. } => "toTimezone",
+            FilterValue::ToUtc => "toUtc",
+            FilterValue::ToYaml => "toYaml",
+            FilterValue::Truncate { .. } => "truncate",
+            FilterValue::TypeOf => "typeOf",
+            FilterValue::UnescapeJson => "unescapeJson",
             FilterValue::UrlDecode => "urlDecode",
             FilterValue::UrlEncode => "urlEncode",
             FilterValue::UrlQueryParam { .. } => "urlQueryParam",
             FilterValue::Utf8Decode => "utf8Decode",
             FilterValue::Utf8Encode => "utf8Encode",
+            FilterValue::Weekday { .. } => "weekday",
+            FilterValue::Wrap { .. } => "wrap",
             FilterValue::XPath { .. } => "xpath",
+            FilterValue::XPathAll { .. } => "xpathAll",
+            FilterValue::XPathBoolean { .. } => "xpathBoolean",
+            FilterValue::XPathNumber { .. } => "xpathNumber",
         }
     }
 }
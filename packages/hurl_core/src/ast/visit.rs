@@ -428,15 +428,102 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
         FilterValue::Base64Decode => {}
         FilterValue::Base64Encode => {}
         FilterValue::Base64UrlSafeDecode => {}
-        FilterValue::Base64UrlSafeEncode => {}
+        FilterValue::Base64UrlSafeEncode { pad } => {
+            if let Some(space0) = pad {
+                visitor.visit_whitespace(space0);
+                visitor.visit_literal("pad");
+            }
+        }
+        FilterValue::Between {
+            space0,
+            min,
+            space1,
+            max,
+            exclusive,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_number_value(min);
+            visitor.visit_whitespace(space1);
+            visitor.visit_number_value(max);
+            if let Some(space2) = exclusive {
+                visitor.visit_whitespace(space2);
+                visitor.visit_literal("exclusive");
+            }
+        }
+        FilterValue::CamelCase => {}
+        FilterValue::Canonicalize => {}
+        FilterValue::ClampLength { space0, max } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_integer_value(max);
+        }
+        FilterValue::Coalesce { args } => {
+            for (space, arg) in args {
+                visitor.visit_whitespace(space);
+                visitor.visit_template(arg);
+            }
+        }
         FilterValue::Count => {}
         FilterValue::DaysAfterNow => {}
         FilterValue::DaysBeforeNow => {}
+        FilterValue::Debug { label } => {
+            if let Some((space0, label)) = label {
+                visitor.visit_whitespace(space0);
+                visitor.visit_template(label);
+            }
+        }
         FilterValue::Decode { space0, encoding } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(encoding);
         }
-        FilterValue::First => {}
+        FilterValue::DeepEqual { space0, expected } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_placeholder(expected);
+        }
+        FilterValue::DropWhile { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
+        FilterValue::DurationFormat { iso } => {
+            if let Some(space0) = iso {
+                visitor.visit_whitespace(space0);
+                visitor.visit_literal("iso");
+            }
+        }
+        FilterValue::EndOf { space0, unit } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(unit);
+        }
+        FilterValue::EnsurePrefix { space0, prefix } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(prefix);
+        }
+        FilterValue::EnsureSuffix { space0, suffix } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(suffix);
+        }
+        FilterValue::Entropy => {}
+        FilterValue::EscapeJson => {}
+        FilterValue::Expect {
+            space0,
+            name,
+            space1,
+            message,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+            visitor.visit_whitespace(space1);
+            visitor.visit_template(message);
+        }
+        FilterValue::Filter { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
+        FilterValue::First { n } => {
+            if let Some((space0, n)) = n {
+                visitor.visit_whitespace(space0);
+                visitor.visit_integer_value(n);
+            }
+        }
         FilterValue::Format { space0, fmt } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(fmt);
@@ -445,12 +532,45 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
             visitor.visit_whitespace(space0);
             visitor.visit_template(fmt);
         }
+        FilterValue::FormatBytes { decimal } => {
+            if let Some(space0) = decimal {
+                visitor.visit_whitespace(space0);
+                visitor.visit_literal("decimal");
+            }
+        }
+        FilterValue::FormDecode => {}
+        FilterValue::FormEncode => {}
+        FilterValue::FromYaml => {}
+        FilterValue::GroupBy { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
+        FilterValue::HexDump => {}
         FilterValue::HtmlEscape => {}
         FilterValue::HtmlUnescape => {}
-        FilterValue::JsonPath { space0, expr } => {
+        FilterValue::IsLeapYear => {}
+        FilterValue::JsEval { space0, expr } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(expr);
         }
+        FilterValue::JsonPatch { space0, ops } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_placeholder(ops);
+        }
+        FilterValue::JsonPath {
+            space0,
+            expr,
+            default,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+            if let Some((space1, space2, default)) = default {
+                visitor.visit_whitespace(space1);
+                visitor.visit_literal("default");
+                visitor.visit_whitespace(space2);
+                visitor.visit_template(default);
+            }
+        }
         FilterValue::JsFilter { space0, name, args } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(name);
@@ -459,12 +579,87 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
                 visitor.visit_template(arg);
             }
         }
-        FilterValue::Last => {}
+        FilterValue::JsonPathKeys { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
+        FilterValue::JwtDecode { header } => {
+            if let Some(space0) = header {
+                visitor.visit_whitespace(space0);
+                visitor.visit_literal("header");
+            }
+        }
+        FilterValue::KebabCase => {}
+        FilterValue::Last { n } => {
+            if let Some((space0, n)) = n {
+                visitor.visit_whitespace(space0);
+                visitor.visit_integer_value(n);
+            }
+        }
+        FilterValue::Leaves => {}
         FilterValue::Location => {}
+        FilterValue::Map { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
+        FilterValue::Mask {
+            space0,
+            start,
+            space1,
+            end,
+            mask,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_integer_value(start);
+            visitor.visit_whitespace(space1);
+            visitor.visit_integer_value(end);
+            if let Some((space2, mask)) = mask {
+                visitor.visit_whitespace(space2);
+                visitor.visit_template(mask);
+            }
+        }
+        FilterValue::Merge { space0, other } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_placeholder(other);
+        }
+        FilterValue::MergeDeep { space0, other } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_placeholder(other);
+        }
+        FilterValue::NonEmpty => {}
         FilterValue::Nth { space0, n } => {
             visitor.visit_whitespace(space0);
             visitor.visit_integer_value(n);
         }
+        FilterValue::NthFromEnd { space0, n } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_integer_value(n);
+        }
+        FilterValue::NumberFormat { space0, fmt } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(fmt);
+        }
+        FilterValue::ParseDuration => {}
+        FilterValue::Paths => {}
+        FilterValue::Percent { space0, total } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_number_value(total);
+        }
+        FilterValue::Ratio { space0, total } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_number_value(total);
+        }
+        FilterValue::Reduce {
+            space0,
+            name,
+            space1,
+            init,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+            visitor.visit_whitespace(space1);
+            visitor.visit_template(init);
+        }
         FilterValue::Regex { space0, value } => {
             visitor.visit_whitespace(space0);
             match value {
@@ -483,6 +678,17 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
             visitor.visit_whitespace(space1);
             visitor.visit_template(new_value);
         }
+        FilterValue::ReplaceAll {
+            space0,
+            old_value,
+            space1,
+            new_value,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(old_value);
+            visitor.visit_whitespace(space1);
+            visitor.visit_template(new_value);
+        }
         FilterValue::ReplaceRegex {
             space0,
             pattern,
@@ -497,10 +703,52 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
             visitor.visit_whitespace(space1);
             visitor.visit_template(new_value);
         }
+        FilterValue::RoundTo { space0, step } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_number_value(step);
+        }
+        FilterValue::Sample { n } => {
+            if let Some((space0, n)) = n {
+                visitor.visit_whitespace(space0);
+                visitor.visit_integer_value(n);
+            }
+        }
+        FilterValue::Shuffle => {}
+        FilterValue::Sign => {}
+        FilterValue::SnakeCase => {}
+        FilterValue::SortBy { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
         FilterValue::Split { space0, sep } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(sep);
         }
+        FilterValue::SplitRegex {
+            space0,
+            pattern,
+            trim,
+        } => {
+            visitor.visit_whitespace(space0);
+            match pattern {
+                RegexValue::Template(value) => visitor.visit_template(value),
+                RegexValue::Regex(regex) => visitor.visit_regex(regex),
+            }
+            if let Some(space1) = trim {
+                visitor.visit_whitespace(space1);
+                visitor.visit_literal("trim");
+            }
+        }
+        FilterValue::Sqrt => {}
+        FilterValue::StartOf { space0, unit } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(unit);
+        }
+        FilterValue::TakeWhile { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
+        FilterValue::ToBigInt => {}
         FilterValue::ToDate { space0, fmt } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(fmt);
@@ -508,7 +756,32 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
         FilterValue::ToFloat => {}
         FilterValue::ToHex => {}
         FilterValue::ToInt => {}
-        FilterValue::ToString => {}
+        FilterValue::ToString { fmt } => {
+            if let Some((space0, fmt)) = fmt {
+                visitor.visit_whitespace(space0);
+                visitor.visit_template(fmt);
+            }
+        }
+        FilterValue::ToTimezone { space0, tz } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(tz);
+        }
+        FilterValue::ToUtc => {}
+        FilterValue::ToYaml => {}
+        FilterValue::Truncate {
+            space0,
+            len,
+            suffix,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_integer_value(len);
+            if let Some((space1, suffix)) = suffix {
+                visitor.visit_whitespace(space1);
+                visitor.visit_template(suffix);
+            }
+        }
+        FilterValue::TypeOf => {}
+        FilterValue::UnescapeJson => {}
         FilterValue::UrlDecode => {}
         FilterValue::UrlEncode => {}
         FilterValue::UrlQueryParam { space0, param } => {
@@ -517,10 +790,40 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
         }
         FilterValue::Utf8Decode => {}
         FilterValue::Utf8Encode => {}
+        FilterValue::Weekday { name } => {
+            if let Some(space0) = name {
+                visitor.visit_whitespace(space0);
+                visitor.visit_literal("name");
+            }
+        }
+        FilterValue::Wrap {
+            space0,
+            left,
+            right,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(left);
+            if let Some((space1, right)) = right {
+                visitor.visit_whitespace(space1);
+                visitor.visit_template(right);
+            }
+        }
         FilterValue::XPath { space0, expr } => {
             visitor.visit_whitespace(space0);
             visitor.visit_template(expr);
         }
+        FilterValue::XPathAll { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
+        FilterValue::XPathBoolean { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
+        FilterValue::XPathNumber { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
     }
 }
 
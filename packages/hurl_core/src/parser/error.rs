@@ -20,6 +20,7 @@ use std::cmp;
 use crate::ast::SourceInfo;
 use crate::error;
 use crate::error::DisplaySourceError;
+use crate::parser::filter::FILTER_NAMES;
 use crate::reader::Pos;
 use crate::text::{Style, StyledString};
 
@@ -38,6 +39,7 @@ pub enum ParseErrorKind {
     Expecting { value: String },
     FileContentType,
     Filename,
+    Filter { name: String },
     GraphQlVariables,
     HexDigit,
     InvalidCookieAttribute,
@@ -101,6 +103,7 @@ impl DisplaySourceError for ParseError {
             ParseErrorKind::Expecting { .. } => "Parsing literal".to_string(),
             ParseErrorKind::FileContentType => "Parsing file content type".to_string(),
             ParseErrorKind::Filename => "Parsing filename".to_string(),
+            ParseErrorKind::Filter { .. } => "Parsing filter".to_string(),
             ParseErrorKind::GraphQlVariables => "Parsing GraphQL variables".to_string(),
             ParseErrorKind::HexDigit => "Parsing hexadecimal number".to_string(),
             ParseErrorKind::InvalidCookieAttribute => "Parsing cookie attribute".to_string(),
@@ -141,6 +144,12 @@ impl DisplaySourceError for ParseError {
             ParseErrorKind::Expecting { value } => format!("expecting '{value}'"),
             ParseErrorKind::FileContentType => "expecting a content type".to_string(),
             ParseErrorKind::Filename => "expecting a filename".to_string(),
+            ParseErrorKind::Filter { name } => match filter_suggestion(name) {
+                Some(suggest) => {
+                    format!("the filter <{name}> is not valid. Did you mean {suggest}?")
+                }
+                None => format!("the filter <{name}> is not valid"),
+            },
             ParseErrorKind::GraphQlVariables => {
                 "GraphQL variables is not a valid JSON object".to_string()
             }
@@ -310,6 +319,26 @@ fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
     None
 }
 
+/// Returns the filter name closest to `name` (with an edit distance of at most 2), or `None`
+/// if `name` is already a valid filter name or if there is no close filter name.
+pub(crate) fn filter_suggestion(name: &str) -> Option<String> {
+    if FILTER_NAMES.contains(&name) {
+        return None;
+    }
+    // Candidates are compared case-insensitively, ties are broken with the case-sensitive distance
+    // (so `jsonpatth` suggests `jsonpath` rather than `jsonPatch`).
+    let lowercase_name = name.to_lowercase();
+    FILTER_NAMES
+        .iter()
+        .map(|f| {
+            let distance = levenshtein_distance(&f.to_lowercase(), &lowercase_name);
+            (distance, levenshtein_distance(f, name), f)
+        })
+        .filter(|(distance, _, _)| *distance <= 2)
+        .min_by_key(|(distance, case_distance, _)| (*distance, *case_distance))
+        .map(|(_, _, f)| f.to_string())
+}
+
 // From https://en.wikibooks.org/wiki/Algorithm_Implementation/Strings/Levenshtein_distance#Rust
 fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     let v1: Vec<char> = s1.chars().collect();
@@ -368,6 +397,16 @@ mod tests {
         assert_eq!(suggestion(&valid_values, "asser"), None);
     }
 
+    #[test]
+    fn test_filter_suggestion() {
+        assert_eq!(filter_suggestion("jsonpatth"), Some("jsonpath".to_string()));
+        assert_eq!(filter_suggestion("jsonPatc"), Some("jsonPatch".to_string()));
+        assert_eq!(filter_suggestion("toint"), Some("toInt".to_string()));
+        assert_eq!(filter_suggestion("cont"), Some("count".to_string()));
+        assert_eq!(filter_suggestion("count"), None);
+        assert_eq!(filter_suggestion("foobar"), None);
+    }
+
     #[test]
     fn test_parsing_error() {
         let content = "GET abc";
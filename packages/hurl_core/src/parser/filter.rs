@@ -17,14 +17,120 @@
  */
 use crate::ast::{Filter, FilterValue, IntegerValue, NumberValue, SourceInfo, Template, TemplateElement, Whitespace};
 use crate::types::ToSource;
-use crate::combinator::{choice, ParseError as ParseErrorTrait};
+use crate::combinator::{choice, optional, recover, ParseError as ParseErrorTrait};
+use crate::parser::error::filter_suggestion;
 use crate::parser::number::{integer, number};
-use crate::parser::primitives::{one_or_more_spaces, try_literal, zero_or_more_spaces};
+use crate::parser::predicate::predicate;
+use crate::parser::primitives::{keyword, one_or_more_spaces, zero_or_more_spaces};
 use crate::parser::query::regex_value;
 use crate::parser::string::quoted_template;
 use crate::parser::{placeholder, ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
 
+/// Names of the filters, used to suggest a filter when parsing an unknown filter name.
+///
+/// This list must be kept in sync with the filters parsed by [`filter`].
+pub(crate) const FILTER_NAMES: &[&str] = &[
+    "add",
+    "base64Decode",
+    "base64Encode",
+    "base64UrlSafeDecode",
+    "base64UrlSafeEncode",
+    "between",
+    "camelCase",
+    "canonicalize",
+    "clampLength",
+    "coalesce",
+    "count",
+    "dateFormat",
+    "daysAfterNow",
+    "daysBeforeNow",
+    "debug",
+    "decode",
+    "deepEqual",
+    "dropWhile",
+    "durationFormat",
+    "endOf",
+    "ensurePrefix",
+    "ensureSuffix",
+    "entropy",
+    "escapeJson",
+    "expect",
+    "filter",
+    "first",
+    "format",
+    "formatBytes",
+    "formDecode",
+    "formEncode",
+    "fromYaml",
+    "groupBy",
+    "hexDump",
+    "htmlEscape",
+    "htmlUnescape",
+    "isLeapYear",
+    "jseval",
+    "jsfilter",
+    "jsonPatch",
+    "jsonpath",
+    "jsonpathKeys",
+    "jwtDecode",
+    "kebabCase",
+    "last",
+    "leaves",
+    "location",
+    "map",
+    "mask",
+    "merge",
+    "mergeDeep",
+    "nonEmpty",
+    "nth",
+    "nthFromEnd",
+    "numberFormat",
+    "parseDuration",
+    "paths",
+    "percent",
+    "ratio",
+    "reduce",
+    "regex",
+    "replace",
+    "replaceAll",
+    "replaceRegex",
+    "roundTo",
+    "sample",
+    "shuffle",
+    "sign",
+    "snakeCase",
+    "sortBy",
+    "split",
+    "splitRegex",
+    "sqrt",
+    "startOf",
+    "takeWhile",
+    "toBigInt",
+    "toDate",
+    "toFloat",
+    "toHex",
+    "toInt",
+    "toString",
+    "toTimezone",
+    "toUtc",
+    "toYaml",
+    "truncate",
+    "typeOf",
+    "unescapeJson",
+    "urlDecode",
+    "urlEncode",
+    "urlQueryParam",
+    "utf8Decode",
+    "utf8Encode",
+    "weekday",
+    "wrap",
+    "xpath",
+    "xpathAll",
+    "xpathBoolean",
+    "xpathNumber",
+];
+
 pub fn filters(reader: &mut Reader) -> ParseResult<Vec<(Whitespace, Filter)>> {
     let mut filters = vec![];
     loop {
@@ -40,6 +146,15 @@ pub fn filters(reader: &mut Reader) -> ParseResult<Vec<(Whitespace, Filter)>> {
             Err(e) => {
                 if e.recoverable {
                     reader.seek(save);
+                    // An unknown filter name close to a valid one is most likely a typo, unless
+                    // it's a predicate (like `not` or `isDate`) or the `redact` keyword of a
+                    // capture following the filters.
+                    if matches!(e.kind, ParseErrorKind::Filter { .. })
+                        && !is_predicate(reader)
+                        && !is_redact(reader)
+                    {
+                        return Err(e.to_non_recoverable());
+                    }
                     break;
                 } else {
                     return Err(e);
@@ -59,44 +174,114 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
             base64_encode_filter,
             base64_url_safe_decode_filter,
             base64_url_safe_encode_filter,
+            between_filter,
+            camel_case_filter,
+            canonicalize_filter,
+            clamp_length_filter,
+            coalesce_filter,
             count_filter,
             days_after_now_filter,
             days_before_now_filter,
+            debug_filter,
             decode_filter,
+            deep_equal_filter,
+            drop_while_filter,
+            duration_format_filter,
+            end_of_filter,
+            ensure_prefix_filter,
+            ensure_suffix_filter,
+            entropy_filter,
+            escape_json_filter,
+            expect_filter,
+            filter_filter,
             first_filter,
+            form_decode_filter,
+            form_encode_filter,
+            format_bytes_filter,
             format_filter,
             date_format_filter,
+            from_yaml_filter,
+            group_by_filter,
+            hex_dump_filter,
             html_decode_filter,
             html_encode_filter,
+            is_leap_year_filter,
+            json_patch_filter,
+            jsonpath_keys_filter,
             jsonpath_filter,
             jsfilter_filter,
+            jseval_filter,
+            jwt_decode_filter,
+            kebab_case_filter,
             last_filter,
+            leaves_filter,
             location_filter,
+            map_filter,
+            mask_filter,
+            merge_deep_filter,
+            merge_filter,
+            non_empty_filter,
+            nth_from_end_filter,
             nth_filter,
+            number_format_filter,
+            parse_duration_filter,
+            paths_filter,
+            percent_filter,
+            ratio_filter,
+            reduce_filter,
             regex_filter,
             replace_regex_filter,
+            replace_all_filter,
             replace_filter,
+            round_to_filter,
+            sample_filter,
+            shuffle_filter,
+            sign_filter,
+            snake_case_filter,
+            sort_by_filter,
             split_filter,
+            split_regex_filter,
+            sqrt_filter,
+            start_of_filter,
+            take_while_filter,
+            to_big_int_filter,
             to_date_filter,
             to_float_filter,
             to_hex_filter,
             to_int_filter,
             to_string_filter,
+            to_timezone_filter,
+            to_utc_filter,
+            to_yaml_filter,
+            truncate_filter,
+            type_of_filter,
+            unescape_json_filter,
             url_decode_filter,
             url_encode_filter,
             url_query_param_filter,
             utf8_decode_filter,
             utf8_encode_filter,
+            weekday_filter,
+            wrap_filter,
+            xpath_all_filter,
+            xpath_boolean_filter,
+            xpath_number_filter,
             xpath_filter,
         ],
         reader,
     )
     .map_err(|e| {
         if e.recoverable {
-            let kind = ParseErrorKind::Expecting {
-                value: "filter".to_string(),
+            reader.seek(start);
+            let name = reader.read_while(|c| c.is_ascii_alphanumeric() || c == '_');
+            let kind = if filter_suggestion(&name).is_some() {
+                ParseErrorKind::Filter { name }
+            } else {
+                ParseErrorKind::Expecting {
+                    value: "filter".to_string(),
+                }
             };
-            ParseError::new(e.pos, true, kind)
+            ParseError::new(start.pos, true, kind)
         } else {
             e
         }
@@ -109,10 +294,28 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     Ok(Filter { source_info, value })
 }
 
+/// Returns `true` if a predicate can be parsed, after optional spaces, from the current position
+/// of `reader`. The reader position is left unchanged.
+fn is_predicate(reader: &mut Reader) -> bool {
+    let save = reader.cursor();
+    let ret = zero_or_more_spaces(reader).is_ok() && predicate(reader).is_ok();
+    reader.seek(save);
+    ret
+}
+
+/// Returns `true` if the `redact` keyword of a capture follows, after optional spaces, the current
+/// position of `reader`. The reader position is left unchanged.
+fn is_redact(reader: &mut Reader) -> bool {
+    let save = reader.cursor();
+    let ret = zero_or_more_spaces(reader).is_ok() && keyword("redact", reader).is_ok();
+    reader.seek(save);
+    ret
+}
+
 fn add_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("add", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let value = number_value(reader)?;
+    keyword("add", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let value = number_value(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::Add { space0, value })
 }
 
@@ -134,96 +337,345 @@ fn number_value(reader: &mut Reader) -> ParseResult<NumberValue> {
 }
 
 fn base64_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("base64Decode", reader)?;
+    keyword("base64Decode", reader)?;
     Ok(FilterValue::Base64Decode)
 }
 
 fn base64_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("base64Encode", reader)?;
+    keyword("base64Encode", reader)?;
     Ok(FilterValue::Base64Encode)
 }
 
 fn base64_url_safe_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("base64UrlSafeDecode", reader)?;
+    keyword("base64UrlSafeDecode", reader)?;
     Ok(FilterValue::Base64UrlSafeDecode)
 }
 
 fn base64_url_safe_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("base64UrlSafeEncode", reader)?;
-    Ok(FilterValue::Base64UrlSafeEncode)
+    keyword("base64UrlSafeEncode", reader)?;
+    let pad = optional(pad_flag, reader)?;
+    Ok(FilterValue::Base64UrlSafeEncode { pad })
+}
+
+/// Parses the `pad` flag of the `base64UrlSafeEncode` filter, returning the whitespace before
+/// the flag.
+fn pad_flag(reader: &mut Reader) -> ParseResult<Whitespace> {
+    let space = recover(one_or_more_spaces, reader)?;
+    keyword("pad", reader)?;
+    Ok(space)
+}
+
+fn between_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("between", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let min = number_value(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let max = number_value(reader).map_err(|e| e.to_non_recoverable())?;
+    let exclusive = optional(exclusive_flag, reader)?;
+    Ok(FilterValue::Between {
+        space0,
+        min: Box::new(min),
+        space1,
+        max: Box::new(max),
+        exclusive,
+    })
+}
+
+/// Parses the `exclusive` flag of the `between` filter, returning the whitespace before the flag.
+fn exclusive_flag(reader: &mut Reader) -> ParseResult<Whitespace> {
+    let space = recover(one_or_more_spaces, reader)?;
+    keyword("exclusive", reader)?;
+    Ok(space)
+}
+
+fn camel_case_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("camelCase", reader)?;
+    Ok(FilterValue::CamelCase)
+}
+
+fn canonicalize_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("canonicalize", reader)?;
+    Ok(FilterValue::Canonicalize)
+}
+
+fn clamp_length_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("clampLength", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let max = integer_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::ClampLength { space0, max })
+}
+
+fn coalesce_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("coalesce", reader)?;
+    let start = reader.cursor();
+    let args = filter_args(reader)?;
+    if args.is_empty() {
+        let kind = ParseErrorKind::Expecting {
+            value: "argument".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    Ok(FilterValue::Coalesce { args })
 }
 
 fn count_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("count", reader)?;
+    keyword("count", reader)?;
     Ok(FilterValue::Count)
 }
 
 fn days_after_now_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("daysAfterNow", reader)?;
+    keyword("daysAfterNow", reader)?;
     Ok(FilterValue::DaysAfterNow)
 }
 
 fn days_before_now_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("daysBeforeNow", reader)?;
+    keyword("daysBeforeNow", reader)?;
     Ok(FilterValue::DaysBeforeNow)
 }
 
+fn debug_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("debug", reader)?;
+    let label = optional(optional_template_arg, reader)?;
+    Ok(FilterValue::Debug { label })
+}
+
 fn decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("decode", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let encoding = quoted_template(reader)?;
+    keyword("decode", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let encoding = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::Decode { space0, encoding })
 }
 
+fn deep_equal_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("deepEqual", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let expected = placeholder::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::DeepEqual { space0, expected })
+}
+
+fn drop_while_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("dropWhile", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::DropWhile { space0, name })
+}
+
+fn duration_format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("durationFormat", reader)?;
+    let iso = optional(iso_flag, reader)?;
+    Ok(FilterValue::DurationFormat { iso })
+}
+
+/// Parses the `iso` flag of the `durationFormat` filter, returning the whitespace before the flag.
+fn iso_flag(reader: &mut Reader) -> ParseResult<Whitespace> {
+    let space = recover(one_or_more_spaces, reader)?;
+    keyword("iso", reader)?;
+    Ok(space)
+}
+
+fn end_of_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("endOf", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let unit = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::EndOf { space0, unit })
+}
+
+fn ensure_prefix_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("ensurePrefix", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let prefix = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::EnsurePrefix { space0, prefix })
+}
+
+fn ensure_suffix_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("ensureSuffix", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let suffix = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::EnsureSuffix { space0, suffix })
+}
+
+fn entropy_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("entropy", reader)?;
+    Ok(FilterValue::Entropy)
+}
+
+fn escape_json_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("escapeJson", reader)?;
+    Ok(FilterValue::EscapeJson)
+}
+
+fn expect_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("expect", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let message = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Expect {
+        space0,
+        name,
+        space1,
+        message,
+    })
+}
+
+fn filter_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("filter", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Filter { space0, name })
+}
+
 fn first_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("first", reader)?;
-    Ok(FilterValue::First)
+    keyword("first", reader)?;
+    let n = optional(optional_integer_arg, reader)?;
+    Ok(FilterValue::First { n })
+}
+
+fn form_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("formDecode", reader)?;
+    Ok(FilterValue::FormDecode)
+}
+
+fn form_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("formEncode", reader)?;
+    Ok(FilterValue::FormEncode)
+}
+
+fn format_bytes_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("formatBytes", reader)?;
+    let decimal = optional(decimal_flag, reader)?;
+    Ok(FilterValue::FormatBytes { decimal })
+}
+
+/// Parses the `decimal` flag of the `formatBytes` filter, returning the whitespace before the
+/// flag.
+fn decimal_flag(reader: &mut Reader) -> ParseResult<Whitespace> {
+    let space = recover(one_or_more_spaces, reader)?;
+    keyword("decimal", reader)?;
+    Ok(space)
 }
 
 fn format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("format", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let fmt = quoted_template(reader)?;
+    keyword("format", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let fmt = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::Format { space0, fmt })
 }
 
 fn date_format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("dateFormat", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let fmt = quoted_template(reader)?;
+    keyword("dateFormat", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let fmt = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::DateFormat { space0, fmt })
 }
 
+fn from_yaml_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("fromYaml", reader)?;
+    Ok(FilterValue::FromYaml)
+}
+
+fn group_by_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("groupBy", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::GroupBy { space0, name })
+}
+
+fn hex_dump_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("hexDump", reader)?;
+    Ok(FilterValue::HexDump)
+}
+
 fn html_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("htmlEscape", reader)?;
+    keyword("htmlEscape", reader)?;
     Ok(FilterValue::HtmlEscape)
 }
 
+fn is_leap_year_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("isLeapYear", reader)?;
+    Ok(FilterValue::IsLeapYear)
+}
+
 fn html_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("htmlUnescape", reader)?;
+    keyword("htmlUnescape", reader)?;
     Ok(FilterValue::HtmlUnescape)
 }
 
+fn json_patch_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("jsonPatch", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let ops = placeholder::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::JsonPatch { space0, ops })
+}
+
+fn jsonpath_keys_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("jsonpathKeys", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::JsonPathKeys { space0, expr })
+}
+
 fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("jsonpath", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
+    keyword("jsonpath", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
-    Ok(FilterValue::JsonPath { space0, expr })
+    let default = optional(jsonpath_default, reader)?;
+    Ok(FilterValue::JsonPath {
+        space0,
+        expr,
+        default,
+    })
+}
+
+/// Parses the optional `default <value>` clause of a `jsonpath` filter, the value being a
+/// placeholder, a quoted string or a number.
+fn jsonpath_default(reader: &mut Reader) -> ParseResult<(Whitespace, Whitespace, Template)> {
+    let space0 = recover(one_or_more_spaces, reader)?;
+    keyword("default", reader)?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let value = filter_arg(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok((space0, space1, value))
 }
 
 fn jsfilter_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("jsfilter", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
+    keyword("jsfilter", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     // Parse the filter function name as unquoted template
     let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
-    // Parse optional arguments (templates/placeholders)
+    let args = filter_args(reader)?;
+    Ok(FilterValue::JsFilter { space0, name, args })
+}
+
+fn jseval_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("jseval", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::JsEval { space0, expr })
+}
+
+fn jwt_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("jwtDecode", reader)?;
+    let header = optional(header_flag, reader)?;
+    Ok(FilterValue::JwtDecode { header })
+}
+
+/// Parses the `header` flag of the `jwtDecode` filter, returning the whitespace before the flag.
+fn header_flag(reader: &mut Reader) -> ParseResult<Whitespace> {
+    let space = recover(one_or_more_spaces, reader)?;
+    keyword("header", reader)?;
+    Ok(space)
+}
+
+/// Parses optional filter arguments (placeholders, quoted templates or numbers), each one preceded
+/// by spaces.
+fn filter_args(reader: &mut Reader) -> ParseResult<Vec<(Whitespace, Template)>> {
     let mut args = Vec::new();
     loop {
         let save = reader.cursor();
         match one_or_more_spaces(reader) {
             Ok(space) => {
                 // Try to parse an argument (placeholder or quoted template)
-                match jsfilter_arg(reader) {
+                match filter_arg(reader) {
                     Ok(arg) => args.push((space, arg)),
                     Err(e) => {
                         if e.recoverable {
@@ -235,10 +687,18 @@ fn jsfilter_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
                     }
                 }
             }
-            Err(_) => break,
+            Err(_) => {
+                reader.seek(save);
+                break;
+            }
         }
     }
-    Ok(FilterValue::JsFilter { space0, name, args })
+    Ok(args)
+}
+
+fn kebab_case_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("kebabCase", reader)?;
+    Ok(FilterValue::KebabCase)
 }
 
 /// Parses an unquoted filter name (identifier: alphanumeric and underscore).
@@ -290,8 +750,8 @@ fn jsfilter_name(reader: &mut Reader) -> ParseResult<Template> {
     })
 }
 
-/// Parses a jsfilter argument: either a placeholder {{var}} or a quoted string.
-fn jsfilter_arg(reader: &mut Reader) -> ParseResult<Template> {
+/// Parses a filter argument: either a placeholder {{var}}, a quoted string or a number.
+fn filter_arg(reader: &mut Reader) -> ParseResult<Template> {
     let start = reader.cursor();
     // Try placeholder first
     if let Some('{') = reader.peek() {
@@ -364,19 +824,74 @@ fn jsfilter_arg(reader: &mut Reader) -> ParseResult<Template> {
 }
 
 fn last_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("last", reader)?;
-    Ok(FilterValue::Last)
+    keyword("last", reader)?;
+    let n = optional(optional_integer_arg, reader)?;
+    Ok(FilterValue::Last { n })
+}
+
+fn leaves_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("leaves", reader)?;
+    Ok(FilterValue::Leaves)
 }
 
 fn location_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("location", reader)?;
+    keyword("location", reader)?;
     Ok(FilterValue::Location)
 }
 
+fn map_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("map", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Map { space0, name })
+}
+
+fn mask_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("mask", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let start = integer_value(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let end = integer_value(reader).map_err(|e| e.to_non_recoverable())?;
+    let mask = optional(optional_template_arg, reader)?;
+    Ok(FilterValue::Mask {
+        space0,
+        start: Box::new(start),
+        space1,
+        end: Box::new(end),
+        mask,
+    })
+}
+
+fn merge_deep_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("mergeDeep", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let other = placeholder::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::MergeDeep { space0, other })
+}
+
+fn merge_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("merge", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let other = placeholder::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Merge { space0, other })
+}
+
+fn non_empty_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("nonEmpty", reader)?;
+    Ok(FilterValue::NonEmpty)
+}
+
+fn nth_from_end_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("nthFromEnd", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let n = integer_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::NthFromEnd { space0, n })
+}
+
 fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("nth", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let n = integer_value(reader)?;
+    keyword("nth", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let n = integer_value(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::Nth { space0, n })
 }
 
@@ -397,18 +912,77 @@ fn integer_value(reader: &mut Reader) -> ParseResult<IntegerValue> {
     }
 }
 
+/// Parses an optional integer argument, preceded by spaces.
+fn optional_integer_arg(reader: &mut Reader) -> ParseResult<(Whitespace, IntegerValue)> {
+    let space = recover(one_or_more_spaces, reader)?;
+    let value = recover(integer_value, reader)?;
+    Ok((space, value))
+}
+
+/// Parses an optional quoted template argument, preceded by spaces.
+fn optional_template_arg(reader: &mut Reader) -> ParseResult<(Whitespace, Template)> {
+    let space = recover(one_or_more_spaces, reader)?;
+    let value = quoted_template(reader)?;
+    Ok((space, value))
+}
+
+fn number_format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("numberFormat", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let fmt = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::NumberFormat { space0, fmt })
+}
+
+fn parse_duration_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("parseDuration", reader)?;
+    Ok(FilterValue::ParseDuration)
+}
+
+fn paths_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("paths", reader)?;
+    Ok(FilterValue::Paths)
+}
+
+fn percent_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("percent", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let total = number_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Percent { space0, total })
+}
+
+fn ratio_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("ratio", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let total = number_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Ratio { space0, total })
+}
+
+fn reduce_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("reduce", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let init = filter_arg(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Reduce {
+        space0,
+        name,
+        space1,
+        init,
+    })
+}
+
 fn regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("regex", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let value = regex_value(reader)?;
+    keyword("regex", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let value = regex_value(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::Regex { space0, value })
 }
 
 fn replace_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("replace", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
+    keyword("replace", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     let old_value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
-    let space1 = one_or_more_spaces(reader)?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     let new_value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::Replace {
         space0,
@@ -418,11 +992,29 @@ fn replace_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     })
 }
 
+fn round_to_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("roundTo", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let step = number_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::RoundTo { space0, step })
+}
+
+fn sample_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("sample", reader)?;
+    let n = optional(optional_integer_arg, reader)?;
+    Ok(FilterValue::Sample { n })
+}
+
+fn shuffle_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("shuffle", reader)?;
+    Ok(FilterValue::Shuffle)
+}
+
 fn replace_regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("replaceRegex", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let pattern = regex_value(reader)?;
-    let space1 = one_or_more_spaces(reader)?;
+    keyword("replaceRegex", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let pattern = regex_value(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     let new_value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::ReplaceRegex {
         space0,
@@ -432,79 +1024,239 @@ fn replace_regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     })
 }
 
+fn replace_all_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("replaceAll", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let old_value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let new_value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::ReplaceAll {
+        space0,
+        old_value,
+        space1,
+        new_value,
+    })
+}
+
+fn sign_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("sign", reader)?;
+    Ok(FilterValue::Sign)
+}
+
+fn snake_case_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("snakeCase", reader)?;
+    Ok(FilterValue::SnakeCase)
+}
+
+fn sort_by_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("sortBy", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::SortBy { space0, name })
+}
+
 fn split_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("split", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
+    keyword("split", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     let sep = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::Split { space0, sep })
 }
 
+fn split_regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("splitRegex", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let pattern = regex_value(reader).map_err(|e| e.to_non_recoverable())?;
+    let trim = optional(trim_flag, reader)?;
+    Ok(FilterValue::SplitRegex {
+        space0,
+        pattern,
+        trim,
+    })
+}
+
+/// Parses the `trim` flag of the `splitRegex` filter, returning the whitespace before the flag.
+fn trim_flag(reader: &mut Reader) -> ParseResult<Whitespace> {
+    let space = recover(one_or_more_spaces, reader)?;
+    keyword("trim", reader)?;
+    Ok(space)
+}
+
+fn sqrt_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("sqrt", reader)?;
+    Ok(FilterValue::Sqrt)
+}
+
+fn start_of_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("startOf", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let unit = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::StartOf { space0, unit })
+}
+
+fn take_while_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("takeWhile", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let name = jsfilter_name(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::TakeWhile { space0, name })
+}
+
+fn to_big_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("toBigInt", reader)?;
+    Ok(FilterValue::ToBigInt)
+}
+
 fn to_date_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("toDate", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let fmt = quoted_template(reader)?;
+    keyword("toDate", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let fmt = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::ToDate { space0, fmt })
 }
 
 fn to_float_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("toFloat", reader)?;
+    keyword("toFloat", reader)?;
     Ok(FilterValue::ToFloat)
 }
 
 fn to_hex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("toHex", reader)?;
+    keyword("toHex", reader)?;
     Ok(FilterValue::ToHex)
 }
 
 fn to_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("toInt", reader)?;
+    keyword("toInt", reader)?;
     Ok(FilterValue::ToInt)
 }
 
 fn to_string_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("toString", reader)?;
-    Ok(FilterValue::ToString)
+    keyword("toString", reader)?;
+    let fmt = optional(optional_template_arg, reader)?;
+    Ok(FilterValue::ToString { fmt })
+}
+
+fn to_timezone_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("toTimezone", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let tz = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::ToTimezone { space0, tz })
+}
+
+fn to_utc_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("toUtc", reader)?;
+    Ok(FilterValue::ToUtc)
+}
+
+fn to_yaml_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("toYaml", reader)?;
+    Ok(FilterValue::ToYaml)
+}
+
+fn truncate_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("truncate", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let len = integer_value(reader).map_err(|e| e.to_non_recoverable())?;
+    let suffix = optional(optional_template_arg, reader)?;
+    Ok(FilterValue::Truncate {
+        space0,
+        len: Box::new(len),
+        suffix,
+    })
+}
+
+fn type_of_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("typeOf", reader)?;
+    Ok(FilterValue::TypeOf)
+}
+
+fn unescape_json_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("unescapeJson", reader)?;
+    Ok(FilterValue::UnescapeJson)
 }
 
 fn url_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("urlEncode", reader)?;
+    keyword("urlEncode", reader)?;
     Ok(FilterValue::UrlEncode)
 }
 
 fn url_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("urlDecode", reader)?;
+    keyword("urlDecode", reader)?;
     Ok(FilterValue::UrlDecode)
 }
 
 fn url_query_param_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("urlQueryParam", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
+    keyword("urlQueryParam", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     let param = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::UrlQueryParam { space0, param })
 }
 
 fn utf8_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("utf8Decode", reader)?;
+    keyword("utf8Decode", reader)?;
     Ok(FilterValue::Utf8Decode)
 }
 
 fn utf8_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("utf8Encode", reader)?;
+    keyword("utf8Encode", reader)?;
     Ok(FilterValue::Utf8Encode)
 }
 
+fn weekday_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("weekday", reader)?;
+    let name = optional(name_flag, reader)?;
+    Ok(FilterValue::Weekday { name })
+}
+
+/// Parses the `name` flag of the `weekday` filter, returning the whitespace before the flag.
+fn name_flag(reader: &mut Reader) -> ParseResult<Whitespace> {
+    let space = recover(one_or_more_spaces, reader)?;
+    keyword("name", reader)?;
+    Ok(space)
+}
+
+fn wrap_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("wrap", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let left = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    let right = optional(optional_template_arg, reader)?;
+    Ok(FilterValue::Wrap {
+        space0,
+        left,
+        right,
+    })
+}
+
+fn xpath_all_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("xpathAll", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::XPathAll { space0, expr })
+}
+
+fn xpath_boolean_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("xpathBoolean", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::XPathBoolean { space0, expr })
+}
+
 fn xpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
-    try_literal("xpath", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
+    keyword("xpath", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
     let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
     Ok(FilterValue::XPath { space0, expr })
 }
 
+fn xpath_number_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    keyword("xpathNumber", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::XPathNumber { space0, expr })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::parser::ParseErrorKind;
-    use crate::reader::Pos;
+    use crate::reader::{CharPos, Pos};
 
     #[test]
     fn test_count() {
@@ -518,14 +1270,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_filter_names() {
+        let sources = [
+            ("add 1", "add"),
+            ("base64Decode", "base64Decode"),
+            ("base64Encode", "base64Encode"),
+            ("base64UrlSafeDecode", "base64UrlSafeDecode"),
+            ("base64UrlSafeEncode", "base64UrlSafeEncode"),
+            ("base64UrlSafeEncode pad", "base64UrlSafeEncode"),
+            ("between 1 10", "between"),
+            ("between 0.5 1 exclusive", "between"),
+            ("camelCase", "camelCase"),
+            ("canonicalize", "canonicalize"),
+            ("clampLength 10", "clampLength"),
+            ("coalesce {{a}} \"b\" 1", "coalesce"),
+            ("count", "count"),
+            ("dateFormat \"%Y\"", "dateFormat"),
+            ("daysAfterNow", "daysAfterNow"),
+            ("daysBeforeNow", "daysBeforeNow"),
+            ("debug", "debug"),
+            ("debug \"books\"", "debug"),
+            ("decode \"utf-8\"", "decode"),
+            ("deepEqual {{expected}}", "deepEqual"),
+            ("dropWhile isSmall", "dropWhile"),
+            ("durationFormat", "durationFormat"),
+            ("durationFormat iso", "durationFormat"),
+            ("endOf \"month\"", "endOf"),
+            ("ensurePrefix \"https://\"", "ensurePrefix"),
+            ("ensureSuffix \"/\"", "ensureSuffix"),
+            ("entropy", "entropy"),
+            ("escapeJson", "escapeJson"),
+            ("expect isAdmin \"must be an admin\"", "expect"),
+            ("filter isEven", "filter"),
+            ("first", "first"),
+            ("first 2", "first"),
+            ("format \"%Y\"", "format"),
+            ("formatBytes", "formatBytes"),
+            ("formatBytes decimal", "formatBytes"),
+            ("formDecode", "formDecode"),
+            ("formEncode", "formEncode"),
+            ("fromYaml", "fromYaml"),
+            ("groupBy parity", "groupBy"),
+            ("hexDump", "hexDump"),
+            ("htmlEscape", "htmlEscape"),
+            ("htmlUnescape", "htmlUnescape"),
+            ("isLeapYear", "isLeapYear"),
+            ("jseval \"input * 2\"", "jseval"),
+            ("jsfilter add", "jsfilter"),
+            ("jsonPatch {{ops}}", "jsonPatch"),
+            ("jsonpath \"$.id\"", "jsonpath"),
+            ("jsonpath \"$.id\" default {{fallback}}", "jsonpath"),
+            ("jsonpathKeys \"$.config\"", "jsonpathKeys"),
+            ("jwtDecode header", "jwtDecode"),
+            ("kebabCase", "kebabCase"),
+            ("last", "last"),
+            ("last 2", "last"),
+            ("leaves", "leaves"),
+            ("location", "location"),
+            ("map double", "map"),
+            ("mask 4 4", "mask"),
+            ("mask 4 4 \"#\"", "mask"),
+            ("merge {{defaults}}", "merge"),
+            ("mergeDeep {{patch}}", "mergeDeep"),
+            ("nonEmpty", "nonEmpty"),
+            ("nth 0", "nth"),
+            ("nthFromEnd 0", "nthFromEnd"),
+            ("numberFormat \"#,##0\"", "numberFormat"),
+            ("parseDuration", "parseDuration"),
+            ("paths", "paths"),
+            ("percent 10", "percent"),
+            ("ratio {{total}}", "ratio"),
+            ("reduce sum 0", "reduce"),
+            ("regex \"a\"", "regex"),
+            ("replace \"a\" \"b\"", "replace"),
+            ("replaceAll \"a\" \"b\"", "replaceAll"),
+            ("replaceRegex \"a\" \"b\"", "replaceRegex"),
+            ("roundTo 50", "roundTo"),
+            ("roundTo 0.25", "roundTo"),
+            ("sample", "sample"),
+            ("sample 2", "sample"),
+            ("shuffle", "shuffle"),
+            ("sign", "sign"),
+            ("snakeCase", "snakeCase"),
+            ("sortBy age", "sortBy"),
+            ("split \",\"", "split"),
+            ("splitRegex /\\s+/ trim", "splitRegex"),
+            ("sqrt", "sqrt"),
+            ("startOf \"day\"", "startOf"),
+            ("takeWhile isSmall", "takeWhile"),
+            ("toBigInt", "toBigInt"),
+            ("toDate \"%Y\"", "toDate"),
+            ("toFloat", "toFloat"),
+            ("toHex", "toHex"),
+            ("toInt", "toInt"),
+            ("toString", "toString"),
+            ("toString \"%.2f\"", "toString"),
+            ("toTimezone \"Europe/Paris\"", "toTimezone"),
+            ("toUtc", "toUtc"),
+            ("toYaml", "toYaml"),
+            ("truncate 3", "truncate"),
+            ("truncate 3 \"...\"", "truncate"),
+            ("typeOf", "typeOf"),
+            ("unescapeJson", "unescapeJson"),
+            ("urlDecode", "urlDecode"),
+            ("urlEncode", "urlEncode"),
+            ("urlQueryParam \"a\"", "urlQueryParam"),
+            ("utf8Decode", "utf8Decode"),
+            ("utf8Encode", "utf8Encode"),
+            ("weekday", "weekday"),
+            ("weekday name", "weekday"),
+            ("wrap \"\\\"\"", "wrap"),
+            ("wrap \"[\" \"]\"", "wrap"),
+            ("xpath \"//a\"", "xpath"),
+            ("xpathAll \"//a\"", "xpathAll"),
+            ("xpathBoolean \"//a\"", "xpathBoolean"),
+            ("xpathNumber \"//a\"", "xpathNumber"),
+        ];
+        for (source, identifier) in sources {
+            let mut reader = Reader::new(source);
+            let filter = filter(&mut reader).unwrap();
+            assert_eq!(filter.value.identifier(), identifier, "{source}");
+            assert!(reader.is_eof(), "{source}");
+            assert!(FILTER_NAMES.contains(&identifier), "{identifier}");
+        }
+    }
+
+    #[test]
+    fn test_filter_names_are_parsed() {
+        // Each suggested name is a filter name: it's parsed, or it fails on a missing argument.
+        for name in FILTER_NAMES {
+            let mut reader = Reader::new(name);
+            match filter(&mut reader) {
+                Ok(filter) => assert_eq!(filter.value.identifier(), *name),
+                Err(err) => assert!(!err.recoverable, "{name}"),
+            }
+        }
+    }
+
     #[test]
     fn test_error() {
         let mut reader = Reader::new("xcount");
         let err = filter(&mut reader).err().unwrap();
         assert_eq!(
             err.kind,
-            ParseErrorKind::Expecting {
-                value: "filter".to_string()
+            ParseErrorKind::Filter {
+                name: "xcount".to_string()
             }
         );
         assert_eq!(err.pos, Pos { line: 1, column: 1 });
@@ -542,4 +1432,198 @@ mod tests {
         assert_eq!(err.pos, Pos { line: 1, column: 7 });
         assert!(!err.recoverable);
     }
+
+    #[test]
+    fn test_jsfilter_end_of_line() {
+        let mut reader = Reader::new("jsfilter upper\nb: body");
+        let filter = filter(&mut reader).unwrap();
+        assert!(matches!(filter.value, FilterValue::JsFilter { args, .. } if args.is_empty()));
+        assert_eq!(reader.cursor().index, CharPos(14));
+    }
+
+    #[test]
+    fn test_first_last_count() {
+        let mut reader = Reader::new("first 2");
+        let value = filter(&mut reader).unwrap().value;
+        let FilterValue::First { n: Some((_, n)) } = value else {
+            panic!("unexpected filter {value:?}");
+        };
+        assert_eq!(n.to_string(), "2");
+
+        let mut reader = Reader::new(" last count");
+        let filters = filters(&mut reader).unwrap();
+        assert_eq!(filters.len(), 2);
+        assert_eq!(filters[0].1.value, FilterValue::Last { n: None });
+        assert_eq!(filters[1].1.value, FilterValue::Count);
+    }
+
+    #[test]
+    fn test_jsonpath_default() {
+        let mut reader = Reader::new("jsonpath \"$.maybe\" default \"none\" count");
+        let value = filter(&mut reader).unwrap().value;
+        let FilterValue::JsonPath { default, .. } = value else {
+            panic!("unexpected filter {value:?}");
+        };
+        assert_eq!(default.unwrap().2.to_string(), "none");
+        assert_eq!(reader.cursor().index, CharPos(33));
+
+        let mut reader = Reader::new("jsonpath \"$.id\" count");
+        let value = filter(&mut reader).unwrap().value;
+        assert!(matches!(value, FilterValue::JsonPath { default: None, .. }));
+        assert_eq!(reader.cursor().index, CharPos(15));
+
+        // A word only starting with `default` is not a default clause.
+        let mut reader = Reader::new("jsonpath \"$.id\" defaultValue");
+        let value = filter(&mut reader).unwrap().value;
+        assert!(matches!(value, FilterValue::JsonPath { default: None, .. }));
+        assert_eq!(reader.cursor().index, CharPos(15));
+
+        let mut reader = Reader::new("jsonpath \"$.id\" default");
+        let err = filter(&mut reader).err().unwrap();
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_missing_argument() {
+        for src in [
+            "decode",
+            "format",
+            "toDate",
+            "nth ",
+            "between 1",
+            "replace \"a\"",
+            "splitRegex",
+            "splitRegex 1",
+        ] {
+            let mut reader = Reader::new(src);
+            let err = filter(&mut reader).err().unwrap();
+            assert!(!err.recoverable, "{src}");
+        }
+
+        // A missing argument is reported instead of ending the filters chain.
+        let mut reader = Reader::new(" count decode");
+        let err = filters(&mut reader).err().unwrap();
+        assert_eq!(
+            err.pos,
+            Pos {
+                line: 1,
+                column: 14
+            }
+        );
+        assert!(!err.recoverable);
+
+        let mut reader = Reader::new(" format\n");
+        let err = filters(&mut reader).err().unwrap();
+        assert_eq!(err.pos, Pos { line: 1, column: 8 });
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_replace_filters() {
+        let mut reader = Reader::new("replace \"a\" \"b\"");
+        let value = filter(&mut reader).unwrap().value;
+        assert!(matches!(value, FilterValue::Replace { .. }));
+
+        let mut reader = Reader::new("replaceAll \"a\" \"b\"");
+        let value = filter(&mut reader).unwrap().value;
+        assert!(matches!(value, FilterValue::ReplaceAll { .. }));
+
+        let mut reader = Reader::new("replaceRegex /a+/ \"b\"");
+        let value = filter(&mut reader).unwrap().value;
+        assert!(matches!(value, FilterValue::ReplaceRegex { .. }));
+
+        // `replaceAll` has literal semantics, a regex is not a valid argument.
+        let mut reader = Reader::new("replaceAll /a+/ \"b\"");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.pos,
+            Pos {
+                line: 1,
+                column: 12
+            }
+        );
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_keyword_filter_boundary() {
+        let mut reader = Reader::new("countx");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Filter {
+                name: "countx".to_string()
+            }
+        );
+        assert_eq!(err.pos, Pos { line: 1, column: 1 });
+        assert!(err.recoverable);
+
+        let mut reader = Reader::new("firstname");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Expecting {
+                value: "filter".to_string()
+            }
+        );
+        assert_eq!(err.pos, Pos { line: 1, column: 1 });
+        assert!(err.recoverable);
+    }
+
+    #[test]
+    fn test_unknown_filter() {
+        let mut reader = Reader::new("jsonpatth \"$.id\"");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Filter {
+                name: "jsonpatth".to_string()
+            }
+        );
+        assert_eq!(err.pos, Pos { line: 1, column: 1 });
+        assert!(err.recoverable);
+
+        let mut reader = Reader::new("foobar");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Expecting {
+                value: "filter".to_string()
+            }
+        );
+        assert!(err.recoverable);
+    }
+
+    #[test]
+    fn test_filters_unknown_filter() {
+        // A near-miss filter name is reported as an error...
+        let mut reader = Reader::new(" count jsonpatth \"$.id\"");
+        let err = filters(&mut reader).err().unwrap();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Filter {
+                name: "jsonpatth".to_string()
+            }
+        );
+        assert_eq!(err.pos, Pos { line: 1, column: 8 });
+        assert!(!err.recoverable);
+
+        // ... unless it's a predicate...
+        let mut reader = Reader::new(" count not == 2");
+        let ret = filters(&mut reader).unwrap();
+        assert_eq!(ret.len(), 1);
+        assert_eq!(reader.cursor().index, CharPos(6));
+
+        // ... or the `redact` keyword of a capture.
+        let mut reader = Reader::new(" count redact");
+        let ret = filters(&mut reader).unwrap();
+        assert_eq!(ret.len(), 1);
+        assert_eq!(reader.cursor().index, CharPos(6));
+
+        // An unrelated token just ends the filters.
+        let mut reader = Reader::new(" count foobar");
+        let ret = filters(&mut reader).unwrap();
+        assert_eq!(ret.len(), 1);
+        assert_eq!(reader.cursor().index, CharPos(6));
+    }
 }
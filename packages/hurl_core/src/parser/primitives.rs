@@ -168,6 +168,24 @@ pub fn try_literal(s: &str, reader: &mut Reader) -> ParseResult<()> {
     }
 }
 
+/// Recoverable parser of the keyword `s`: the literal `s` must not be followed by an alphanumeric
+/// char or an underscore (`count` is not matched in `counting`).
+pub fn keyword(s: &str, reader: &mut Reader) -> ParseResult<()> {
+    let save_state = reader.cursor();
+    try_literal(s, reader)?;
+    if reader
+        .peek()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    {
+        reader.seek(save_state);
+        let kind = ParseErrorKind::Expecting {
+            value: s.to_string(),
+        };
+        return Err(ParseError::new(save_state.pos, true, kind));
+    }
+    Ok(())
+}
+
 pub fn newline(reader: &mut Reader) -> ParseResult<Whitespace> {
     let start = reader.cursor();
     match try_literal("\r\n", reader) {
@@ -532,6 +550,31 @@ mod tests {
         assert!(error.recoverable);
     }
 
+    #[test]
+    fn test_keyword() {
+        let mut reader = Reader::new("count");
+        assert_eq!(keyword("count", &mut reader), Ok(()));
+        assert_eq!(reader.cursor().index, CharPos(5));
+
+        let mut reader = Reader::new("count == 1");
+        assert_eq!(keyword("count", &mut reader), Ok(()));
+        assert_eq!(reader.cursor().index, CharPos(5));
+
+        for source in ["counting", "count_", "count2"] {
+            let mut reader = Reader::new(source);
+            let error = keyword("count", &mut reader).err().unwrap();
+            assert_eq!(error.pos, Pos { line: 1, column: 1 });
+            assert_eq!(
+                error.kind,
+                ParseErrorKind::Expecting {
+                    value: String::from("count")
+                }
+            );
+            assert!(error.recoverable);
+            assert_eq!(reader.cursor().index, CharPos(0));
+        }
+    }
+
     #[test]
     fn test_literal() {
         let mut reader = Reader::new("hello");
@@ -657,21 +657,137 @@ impl ToJson for FilterValue {
         attributes.push((att_name, att_value));
 
         match self {
+            FilterValue::Base64UrlSafeEncode { pad: Some(_) } => {
+                attributes.push(("pad".to_string(), JValue::Boolean(true)));
+            }
+            FilterValue::Between {
+                min,
+                max,
+                exclusive,
+                ..
+            } => {
+                attributes.push(("min".to_string(), JValue::Number(min.to_string())));
+                attributes.push(("max".to_string(), JValue::Number(max.to_string())));
+                if exclusive.is_some() {
+                    attributes.push(("exclusive".to_string(), JValue::Boolean(true)));
+                }
+            }
+            FilterValue::ClampLength { max, .. } => {
+                attributes.push(("max".to_string(), JValue::Number(max.to_string())));
+            }
+            FilterValue::Coalesce { args } => {
+                let args = args
+                    .iter()
+                    .map(|(_, arg)| JValue::String(arg.to_string()))
+                    .collect();
+                attributes.push(("args".to_string(), JValue::List(args)));
+            }
+            FilterValue::Debug {
+                label: Some((_, label)),
+            } => {
+                attributes.push(("label".to_string(), JValue::String(label.to_string())));
+            }
             FilterValue::Decode { encoding, .. } => {
                 attributes.push(("encoding".to_string(), JValue::String(encoding.to_string())));
             }
+            FilterValue::DeepEqual { expected, .. } => {
+                attributes.push(("expected".to_string(), expected.to_json()));
+            }
+            FilterValue::DropWhile { name, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
+            FilterValue::DurationFormat { iso: Some(_) } => {
+                attributes.push(("iso".to_string(), JValue::Boolean(true)));
+            }
+            FilterValue::EndOf { unit, .. } => {
+                attributes.push(("unit".to_string(), JValue::String(unit.to_string())));
+            }
+            FilterValue::EnsurePrefix { prefix, .. } => {
+                attributes.push(("prefix".to_string(), JValue::String(prefix.to_string())));
+            }
+            FilterValue::EnsureSuffix { suffix, .. } => {
+                attributes.push(("suffix".to_string(), JValue::String(suffix.to_string())));
+            }
+            FilterValue::Expect { name, message, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+                attributes.push(("message".to_string(), JValue::String(message.to_string())));
+            }
+            FilterValue::Filter { name, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
+            FilterValue::First { n: Some((_, n)) } => {
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+            }
             FilterValue::Format { fmt, .. } => {
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
             FilterValue::DateFormat { fmt, .. } => {
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
-            FilterValue::JsonPath { expr, .. } => {
+            FilterValue::FormatBytes { decimal: Some(_) } => {
+                attributes.push(("decimal".to_string(), JValue::Boolean(true)));
+            }
+            FilterValue::GroupBy { name, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
+            FilterValue::JsEval { expr, .. } => {
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::JsonPatch { ops, .. } => {
+                attributes.push(("ops".to_string(), ops.to_json()));
+            }
+            FilterValue::JsonPath { expr, default, .. } => {
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+                if let Some((_, _, default)) = default {
+                    attributes.push(("default".to_string(), JValue::String(default.to_string())));
+                }
+            }
+            FilterValue::JsonPathKeys { expr, .. } => {
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::JwtDecode { header: Some(_) } => {
+                attributes.push(("header".to_string(), JValue::Boolean(true)));
+            }
+            FilterValue::Last { n: Some((_, n)) } => {
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+            }
+            FilterValue::Map { name, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
+            FilterValue::Mask {
+                start, end, mask, ..
+            } => {
+                attributes.push(("start".to_string(), JValue::Number(start.to_string())));
+                attributes.push(("end".to_string(), JValue::Number(end.to_string())));
+                if let Some((_, mask)) = mask {
+                    attributes.push(("mask".to_string(), JValue::String(mask.to_string())));
+                }
+            }
+            FilterValue::Merge { other, .. } => {
+                attributes.push(("other".to_string(), other.to_json()));
+            }
+            FilterValue::MergeDeep { other, .. } => {
+                attributes.push(("other".to_string(), other.to_json()));
             }
             FilterValue::Nth { n, .. } => {
                 attributes.push(("n".to_string(), JValue::Number(n.to_string())));
             }
+            FilterValue::NthFromEnd { n, .. } => {
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+            }
+            FilterValue::NumberFormat { fmt, .. } => {
+                attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
+            }
+            FilterValue::Percent { total, .. } => {
+                attributes.push(("total".to_string(), JValue::Number(total.to_string())));
+            }
+            FilterValue::Ratio { total, .. } => {
+                attributes.push(("total".to_string(), JValue::Number(total.to_string())));
+            }
+            FilterValue::Reduce { name, init, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+                attributes.push(("init".to_string(), JValue::String(init.to_string())));
+            }
             FilterValue::Regex { value, .. } => {
                 attributes.push(("expr".to_string(), value.to_json()));
             }
@@ -689,6 +805,20 @@ impl ToJson for FilterValue {
                     JValue::String(new_value.to_string()),
                 ));
             }
+            FilterValue::ReplaceAll {
+                old_value,
+                new_value,
+                ..
+            } => {
+                attributes.push((
+                    "old_value".to_string(),
+                    JValue::String(old_value.to_string()),
+                ));
+                attributes.push((
+                    "new_value".to_string(),
+                    JValue::String(new_value.to_string()),
+                ));
+            }
             FilterValue::ReplaceRegex {
                 pattern, new_value, ..
             } => {
@@ -698,18 +828,71 @@ impl ToJson for FilterValue {
                     JValue::String(new_value.to_string()),
                 ));
             }
+            FilterValue::RoundTo { step, .. } => {
+                attributes.push(("step".to_string(), JValue::Number(step.to_string())));
+            }
+            FilterValue::Sample { n: Some((_, n)) } => {
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+            }
+            FilterValue::SortBy { name, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
             FilterValue::Split { sep, .. } => {
                 attributes.push(("sep".to_string(), JValue::String(sep.to_string())));
             }
+            FilterValue::SplitRegex { pattern, trim, .. } => {
+                attributes.push(("pattern".to_string(), pattern.to_json()));
+                if trim.is_some() {
+                    attributes.push(("trim".to_string(), JValue::Boolean(true)));
+                }
+            }
+            FilterValue::StartOf { unit, .. } => {
+                attributes.push(("unit".to_string(), JValue::String(unit.to_string())));
+            }
+            FilterValue::TakeWhile { name, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
             FilterValue::ToDate { fmt, .. } => {
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
+            FilterValue::ToString {
+                fmt: Some((_, fmt)),
+            } => {
+                attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
+            }
+            FilterValue::ToTimezone { tz, .. } => {
+                attributes.push(("tz".to_string(), JValue::String(tz.to_string())));
+            }
+            FilterValue::Truncate { len, suffix, .. } => {
+                attributes.push(("len".to_string(), JValue::Number(len.to_string())));
+                if let Some((_, suffix)) = suffix {
+                    attributes.push(("suffix".to_string(), JValue::String(suffix.to_string())));
+                }
+            }
             FilterValue::UrlQueryParam { param, .. } => {
                 attributes.push(("param".to_string(), JValue::String(param.to_string())));
             }
+            FilterValue::Weekday { name: Some(_) } => {
+                attributes.push(("name".to_string(), JValue::Boolean(true)));
+            }
+            FilterValue::Wrap { left, right, .. } => {
+                attributes.push(("left".to_string(), JValue::String(left.to_string())));
+                if let Some((_, right)) = right {
+                    attributes.push(("right".to_string(), JValue::String(right.to_string())));
+                }
+            }
             FilterValue::XPath { expr, .. } => {
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
+            FilterValue::XPathAll { expr, .. } => {
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::XPathBoolean { expr, .. } => {
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::XPathNumber { expr, .. } => {
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
             _ => {}
         }
         JValue::Object(attributes)
@@ -242,4 +242,26 @@ HTTP 200
 "#
         );
     }
+
+    #[test]
+    fn format_filters_round_trip() {
+        let src = r#"
+GET https://foo.com
+HTTP 200
+[Captures]
+id: jsonpath "$.id" jsfilter pad_left   007 "x"  {{width}}
+ratio: jsonpath "$.errors" ratio  {{total}} roundTo 0.50 toString "%.2f"
+[Asserts]
+jsonpath "$.items" jsfilter sum -1.50 2 count == 3
+jsonpath "$.user" jsonpath "$.id"  default   {{fallback}} == 1
+jsonpath "$.items" map double reduce add 0 between 1 10 exclusive == true
+jsonpath "$.name" truncate 10 "..." debug "name" == "toto"
+jsonpath "$.list" nth 0 add 1.50 clampLength 2 == 1
+jsonpath "$.date" toDate "%Y-%m-%d" toString "%d/%m/%Y" == "01/01/2026"
+"#;
+        let file = parse_hurl_file(src).unwrap();
+        let mut fmt = TextFormatter::new();
+        let dst = fmt.format(&file, Format::Plain);
+        assert_eq!(src, dst);
+    }
 }
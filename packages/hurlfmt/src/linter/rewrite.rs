@@ -253,10 +253,86 @@ impl Lint for FilterValue {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            FilterValue::Base64UrlSafeEncode { pad } => {
+                if pad.is_some() {
+                    s.push_str(" pad");
+                }
+            }
+            FilterValue::Between {
+                min,
+                max,
+                exclusive,
+                ..
+            } => {
+                s.push(' ');
+                s.push_str(&min.lint());
+                s.push(' ');
+                s.push_str(&max.lint());
+                if exclusive.is_some() {
+                    s.push_str(" exclusive");
+                }
+            }
+            FilterValue::ClampLength { max, .. } => {
+                s.push(' ');
+                s.push_str(&max.lint());
+            }
+            FilterValue::Coalesce { args } => {
+                for (_, arg) in args {
+                    s.push(' ');
+                    s.push_str(&arg.lint());
+                }
+            }
+            FilterValue::Debug { label } => {
+                if let Some((_, label)) = label {
+                    s.push(' ');
+                    s.push_str(&label.lint());
+                }
+            }
             FilterValue::Decode { encoding, .. } => {
                 s.push(' ');
                 s.push_str(&encoding.lint());
             }
+            FilterValue::DeepEqual { expected, .. } => {
+                s.push(' ');
+                s.push_str(&expected.lint());
+            }
+            FilterValue::DropWhile { name, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+            }
+            FilterValue::DurationFormat { iso } => {
+                if iso.is_some() {
+                    s.push_str(" iso");
+                }
+            }
+            FilterValue::EndOf { unit, .. } => {
+                s.push(' ');
+                s.push_str(&unit.lint());
+            }
+            FilterValue::EnsurePrefix { prefix, .. } => {
+                s.push(' ');
+                s.push_str(&prefix.lint());
+            }
+            FilterValue::EnsureSuffix { suffix, .. } => {
+                s.push(' ');
+                s.push_str(&suffix.lint());
+            }
+            FilterValue::Expect { name, message, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+                s.push(' ');
+                s.push_str(&message.lint());
+            }
+            FilterValue::Filter { name, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+            }
+            FilterValue::First { n } => {
+                if let Some((_, n)) = n {
+                    s.push(' ');
+                    s.push_str(&n.lint());
+                }
+            }
             FilterValue::Format { fmt, .. } => {
                 s.push(' ');
                 s.push_str(&fmt.lint());
@@ -265,14 +341,96 @@ impl Lint for FilterValue {
                 s.push(' ');
                 s.push_str(&fmt.lint());
             }
-            FilterValue::JsonPath { expr, .. } => {
+            FilterValue::FormatBytes { decimal } => {
+                if decimal.is_some() {
+                    s.push_str(" decimal");
+                }
+            }
+            FilterValue::GroupBy { name, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+            }
+            FilterValue::JsEval { expr, .. } => {
+                s.push(' ');
+                s.push_str(&expr.lint());
+            }
+            FilterValue::JsonPatch { ops, .. } => {
+                s.push(' ');
+                s.push_str(&ops.lint());
+            }
+            FilterValue::JsonPath { expr, default, .. } => {
+                s.push(' ');
+                s.push_str(&expr.lint());
+                if let Some((_, _, default)) = default {
+                    s.push_str(" default ");
+                    s.push_str(&default.lint());
+                }
+            }
+            FilterValue::JsonPathKeys { expr, .. } => {
                 s.push(' ');
                 s.push_str(&expr.lint());
             }
+            FilterValue::JwtDecode { header } => {
+                if header.is_some() {
+                    s.push_str(" header");
+                }
+            }
+            FilterValue::Last { n } => {
+                if let Some((_, n)) = n {
+                    s.push(' ');
+                    s.push_str(&n.lint());
+                }
+            }
+            FilterValue::Map { name, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+            }
+            FilterValue::Mask {
+                start, end, mask, ..
+            } => {
+                s.push(' ');
+                s.push_str(&start.lint());
+                s.push(' ');
+                s.push_str(&end.lint());
+                if let Some((_, mask)) = mask {
+                    s.push(' ');
+                    s.push_str(&mask.lint());
+                }
+            }
+            FilterValue::Merge { other, .. } => {
+                s.push(' ');
+                s.push_str(&other.lint());
+            }
+            FilterValue::MergeDeep { other, .. } => {
+                s.push(' ');
+                s.push_str(&other.lint());
+            }
             FilterValue::Nth { n, .. } => {
                 s.push(' ');
                 s.push_str(&n.lint());
             }
+            FilterValue::NthFromEnd { n, .. } => {
+                s.push(' ');
+                s.push_str(&n.lint());
+            }
+            FilterValue::NumberFormat { fmt, .. } => {
+                s.push(' ');
+                s.push_str(&fmt.lint());
+            }
+            FilterValue::Percent { total, .. } => {
+                s.push(' ');
+                s.push_str(&total.lint());
+            }
+            FilterValue::Ratio { total, .. } => {
+                s.push(' ');
+                s.push_str(&total.lint());
+            }
+            FilterValue::Reduce { name, init, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+                s.push(' ');
+                s.push_str(&init.lint());
+            }
             FilterValue::Regex { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
@@ -287,6 +445,30 @@ impl Lint for FilterValue {
                 s.push(' ');
                 s.push_str(&new_value.lint());
             }
+            FilterValue::ReplaceAll {
+                old_value,
+                new_value,
+                ..
+            } => {
+                s.push(' ');
+                s.push_str(&old_value.lint());
+                s.push(' ');
+                s.push_str(&new_value.lint());
+            }
+            FilterValue::RoundTo { step, .. } => {
+                s.push(' ');
+                s.push_str(&step.lint());
+            }
+            FilterValue::Sample { n } => {
+                if let Some((_, n)) = n {
+                    s.push(' ');
+                    s.push_str(&n.lint());
+                }
+            }
+            FilterValue::SortBy { name, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+            }
             FilterValue::Split { sep, .. } => {
                 s.push(' ');
                 s.push_str(&sep.lint());
@@ -299,18 +481,76 @@ impl Lint for FilterValue {
                 s.push(' ');
                 s.push_str(&new_value.lint());
             }
+            FilterValue::SplitRegex { pattern, trim, .. } => {
+                s.push(' ');
+                s.push_str(&pattern.lint());
+                if trim.is_some() {
+                    s.push_str(" trim");
+                }
+            }
+            FilterValue::StartOf { unit, .. } => {
+                s.push(' ');
+                s.push_str(&unit.lint());
+            }
+            FilterValue::TakeWhile { name, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+            }
             FilterValue::ToDate { fmt, .. } => {
                 s.push(' ');
                 s.push_str(&fmt.lint());
             }
+            FilterValue::ToString { fmt } => {
+                if let Some((_, fmt)) = fmt {
+                    s.push(' ');
+                    s.push_str(&fmt.lint());
+                }
+            }
+            FilterValue::ToTimezone { tz, .. } => {
+                s.push(' ');
+                s.push_str(&tz.lint());
+            }
+            FilterValue::Truncate { len, suffix, .. } => {
+                s.push(' ');
+                s.push_str(&len.lint());
+                if let Some((_, suffix)) = suffix {
+                    s.push(' ');
+                    s.push_str(&suffix.lint());
+                }
+            }
             FilterValue::UrlQueryParam { param, .. } => {
                 s.push(' ');
                 s.push_str(&param.lint());
             }
+            FilterValue::Weekday { name } => {
+                if name.is_some() {
+                    s.push_str(" name");
+                }
+            }
+            FilterValue::Wrap { left, right, .. } => {
+                s.push(' ');
+                s.push_str(&left.lint());
+                if let Some((_, right)) = right {
+                    s.push(' ');
+                    s.push_str(&right.lint());
+                }
+            }
             FilterValue::XPath { expr, .. } => {
                 s.push(' ');
                 s.push_str(&expr.lint());
             }
+            FilterValue::XPathAll { expr, .. } => {
+                s.push(' ');
+                s.push_str(&expr.lint());
+            }
+            FilterValue::XPathBoolean { expr, .. } => {
+                s.push(' ');
+                s.push_str(&expr.lint());
+            }
+            FilterValue::XPathNumber { expr, .. } => {
+                s.push(' ');
+                s.push_str(&expr.lint());
+            }
             FilterValue::JsFilter { name, args, .. } => {
                 s.push(' ');
                 s.push_str(&name.lint());
@@ -322,19 +562,38 @@ impl Lint for FilterValue {
             FilterValue::Base64Decode
             | FilterValue::Base64Encode
             | FilterValue::Base64UrlSafeDecode
-            | FilterValue::Base64UrlSafeEncode
+            | FilterValue::CamelCase
+            | FilterValue::Canonicalize
             | FilterValue::Count
             | FilterValue::DaysAfterNow
             | FilterValue::DaysBeforeNow
-            | FilterValue::First
+            | FilterValue::Entropy
+            | FilterValue::EscapeJson
+            | FilterValue::FormDecode
+            | FilterValue::FormEncode
+            | FilterValue::FromYaml
+            | FilterValue::HexDump
             | FilterValue::HtmlEscape
             | FilterValue::HtmlUnescape
-            | FilterValue::Last
+            | FilterValue::IsLeapYear
+            | FilterValue::KebabCase
+            | FilterValue::Leaves
             | FilterValue::Location
+            | FilterValue::NonEmpty
+            | FilterValue::ParseDuration
+            | FilterValue::Paths
+            | FilterValue::Shuffle
+            | FilterValue::Sign
+            | FilterValue::SnakeCase
+            | FilterValue::Sqrt
+            | FilterValue::ToBigInt
             | FilterValue::ToFloat
             | FilterValue::ToHex
             | FilterValue::ToInt
-            | FilterValue::ToString
+            | FilterValue::ToUtc
+            | FilterValue::ToYaml
+            | FilterValue::TypeOf
+            | FilterValue::UnescapeJson
             | FilterValue::UrlDecode
             | FilterValue::UrlEncode
             | FilterValue::Utf8Decode
@@ -975,4 +1234,33 @@ HTTP 200
 "#
         );
     }
+
+    #[test]
+    fn test_lint_filters() {
+        let src = r#"GET https://foo.com
+HTTP 200
+[Captures]
+id: jsonpath "$.id"   jsfilter pad_left   007 "x"  {{width}}
+ratio: jsonpath "$.errors" ratio  {{total}}   roundTo 0.50 toString  "%.2f"
+[Asserts]
+jsonpath "$.items" jsfilter sum -1.50 2 count == 3
+jsonpath "$.items" map double  reduce add 0   between 1 10   exclusive == true
+"#;
+        let expected = r#"GET https://foo.com
+HTTP 200
+[Captures]
+id: jsonpath "$.id" jsfilter pad_left 007 "x" {{width}}
+ratio: jsonpath "$.errors" ratio {{total}} roundTo 0.50 toString "%.2f"
+[Asserts]
+jsonpath "$.items" jsfilter sum -1.50 2 count == 3
+jsonpath "$.items" map double reduce add 0 between 1 10 exclusive == true
+"#;
+        let file = parser::parse_hurl_file(src).unwrap();
+        let linted = lint_hurl_file(&file);
+        assert_eq!(linted, expected);
+
+        // Linting is idempotent.
+        let file = parser::parse_hurl_file(&linted).unwrap();
+        assert_eq!(lint_hurl_file(&file), expected);
+    }
 }